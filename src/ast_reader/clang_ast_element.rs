@@ -1,5 +1,5 @@
 use std::collections::VecDeque;
-use std::rc::Rc;
+use std::sync::Arc;
 
 use super::super::location::range::Range;
 use super::clang_ast_element_type::ClangAstElementType;
@@ -9,9 +9,14 @@ pub struct ClangAstElement {
     pub element_id: usize,
     pub parent_element_id: usize,
     pub prev_element_id: usize,
-    pub file: Rc<String>,
+    pub file: Arc<String>,
     pub range: Range,
     pub inner: VecDeque<ClangAstElement>,
+    // Pairs of included and including file seen in the locations of this element. Only the
+    // JSON dump contains them and only where the file changes.
+    pub include_edges: Vec<(Arc<String>, Arc<String>)>,
+    // Mangled name of functions, only the JSON dump contains it.
+    pub mangled_name: Option<String>,
     pub attributes: String,
 }
 
@@ -21,7 +26,7 @@ impl ClangAstElement {
         element_id: usize,
         parent_element_id: usize,
         prev_element_id: usize,
-        file: Rc<String>,
+        file: Arc<String>,
         range: Range,
         attributes: String,
     ) -> ClangAstElement {
@@ -33,6 +38,8 @@ impl ClangAstElement {
             file,
             range,
             inner: VecDeque::new(),
+            include_edges: Vec::new(),
+            mangled_name: None,
             attributes,
         }
     }
@@ -6,14 +6,25 @@ pub enum ClangAstElementType {
     ClassTemplateDecl,
     ClassTemplateSpecializationDecl,
     CompoundStmt,
+    CXXBindTemporaryExpr,
+    CXXConstructExpr,
+    CXXConstructorDecl,
+    CXXConversionDecl,
+    CXXDeleteExpr,
+    CXXDependentScopeMemberExpr,
+    CXXDestructorDecl,
     CXXMemberCallExpr,
     CXXMethodDecl,
+    CXXOperatorCallExpr,
     CXXRecordDecl,
+    CXXTemporaryObjectExpr,
     DeclRefExpr,
     FunctionDecl,
     FunctionTemplateDecl,
+    LambdaExpr,
     MemberExpr,
     NamespaceDecl,
+    NonTypeTemplateParmDecl,
     Overrides,
     #[allow(non_camel_case_types)]
     private,
@@ -22,7 +33,12 @@ pub enum ClangAstElementType {
     #[allow(non_camel_case_types)]
     public,
     TemplateArgument,
+    TemplateTemplateParmDecl,
+    TemplateTypeParmDecl,
     TypedefDecl,
+    UnresolvedLookupExpr,
+    UnresolvedMemberExpr,
+    VarDecl,
 }
 
 impl FromStr for ClangAstElementType {
@@ -36,20 +52,36 @@ impl FromStr for ClangAstElementType {
                 Ok(ClangAstElementType::ClassTemplateSpecializationDecl)
             }
             "CompoundStmt" => Ok(ClangAstElementType::CompoundStmt),
+            "CXXBindTemporaryExpr" => Ok(ClangAstElementType::CXXBindTemporaryExpr),
+            "CXXConstructExpr" => Ok(ClangAstElementType::CXXConstructExpr),
+            "CXXConstructorDecl" => Ok(ClangAstElementType::CXXConstructorDecl),
+            "CXXConversionDecl" => Ok(ClangAstElementType::CXXConversionDecl),
+            "CXXDeleteExpr" => Ok(ClangAstElementType::CXXDeleteExpr),
+            "CXXDependentScopeMemberExpr" => Ok(ClangAstElementType::CXXDependentScopeMemberExpr),
+            "CXXDestructorDecl" => Ok(ClangAstElementType::CXXDestructorDecl),
             "CXXMemberCallExpr" => Ok(ClangAstElementType::CXXMemberCallExpr),
             "CXXMethodDecl" => Ok(ClangAstElementType::CXXMethodDecl),
+            "CXXOperatorCallExpr" => Ok(ClangAstElementType::CXXOperatorCallExpr),
             "CXXRecordDecl" => Ok(ClangAstElementType::CXXRecordDecl),
+            "CXXTemporaryObjectExpr" => Ok(ClangAstElementType::CXXTemporaryObjectExpr),
             "DeclRefExpr" => Ok(ClangAstElementType::DeclRefExpr),
             "FunctionDecl" => Ok(ClangAstElementType::FunctionDecl),
             "FunctionTemplateDecl" => Ok(ClangAstElementType::FunctionTemplateDecl),
+            "LambdaExpr" => Ok(ClangAstElementType::LambdaExpr),
             "MemberExpr" => Ok(ClangAstElementType::MemberExpr),
             "NamespaceDecl" => Ok(ClangAstElementType::NamespaceDecl),
+            "NonTypeTemplateParmDecl" => Ok(ClangAstElementType::NonTypeTemplateParmDecl),
             "Overrides" => Ok(ClangAstElementType::Overrides),
             "private" => Ok(ClangAstElementType::private),
             "protected" => Ok(ClangAstElementType::protected),
             "public" => Ok(ClangAstElementType::public),
             "TemplateArgument" => Ok(ClangAstElementType::TemplateArgument),
+            "TemplateTemplateParmDecl" => Ok(ClangAstElementType::TemplateTemplateParmDecl),
+            "TemplateTypeParmDecl" => Ok(ClangAstElementType::TemplateTypeParmDecl),
             "TypedefDecl" => Ok(ClangAstElementType::TypedefDecl),
+            "UnresolvedLookupExpr" => Ok(ClangAstElementType::UnresolvedLookupExpr),
+            "UnresolvedMemberExpr" => Ok(ClangAstElementType::UnresolvedMemberExpr),
+            "VarDecl" => Ok(ClangAstElementType::VarDecl),
             _ => Err(()),
         }
     }
@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClangAstFormat {
+    // The default "-Xclang -ast-dump" output.
+    Text,
+    // The "-Xclang -ast-dump=json" output.
+    Json,
+}
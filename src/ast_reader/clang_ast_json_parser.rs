@@ -0,0 +1,786 @@
+use std::collections::VecDeque;
+use std::io::Read;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use serde::Deserialize;
+
+use super::super::location::position::Position;
+use super::super::location::range::Range;
+use super::super::process::Process;
+use super::clang_ast_element::ClangAstElement;
+use super::clang_ast_element_type::ClangAstElementType;
+use super::clang_ast_parser::ClangAstParser;
+
+// Parses the output of "-Xclang -ast-dump=json" into the same element tree as the
+// ClangAstParserImpl. The attributes are recreated in the layout of the text dump, so the
+// AST walker doesn't need to know which dump format was used.
+pub struct ClangAstJsonParserImpl {
+    process: Box<dyn Process>,
+    last_seen_file: Arc<String>,
+    last_seen_line: usize,
+    include_edges: Vec<(Arc<String>, Arc<String>)>,
+}
+
+// Only the members used to create the elements are read, everything else of the dump is
+// skipped while deserializing.
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct JsonAstNode {
+    id: String,
+    kind: String,
+    loc: Option<JsonLocation>,
+    range: Option<JsonRange>,
+    parent_decl_context_id: String,
+    previous_decl: String,
+    name: String,
+    mangled_name: Option<String>,
+    #[serde(rename = "type")]
+    qual_type: Option<JsonType>,
+    storage_class: String,
+    inline: bool,
+    #[serde(rename = "virtual")]
+    is_virtual: bool,
+    pure: bool,
+    is_implicit: bool,
+    is_used: bool,
+    is_referenced: bool,
+    tag_used: String,
+    complete_definition: bool,
+    value_category: String,
+    referenced_decl: Option<Box<JsonAstNode>>,
+    is_arrow: bool,
+    referenced_member_decl: String,
+    ctor_type: Option<JsonType>,
+    elidable: bool,
+    init: String,
+    depth: u64,
+    index: u64,
+    is_parameter_pack: bool,
+    #[serde(rename = "usesADL")]
+    uses_adl: bool,
+    lookups: Vec<JsonAstNode>,
+    member: String,
+    bases: Vec<JsonBase>,
+    overridden_methods: Vec<JsonAstNode>,
+    inner: Vec<JsonAstNode>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct JsonType {
+    qual_type: String,
+    desugared_qual_type: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct JsonBase {
+    access: String,
+    #[serde(rename = "type")]
+    qual_type: Option<JsonType>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct JsonRange {
+    begin: JsonLocation,
+    end: JsonLocation,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct JsonLocation {
+    line: Option<usize>,
+    col: Option<usize>,
+    file: Option<String>,
+    included_from: Option<JsonIncludedFrom>,
+    spelling_loc: Option<Box<JsonLocation>>,
+    expansion_loc: Option<Box<JsonLocation>>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct JsonIncludedFrom {
+    file: String,
+}
+
+// Hands the output lines of the process to the deserializer, so the text of the dump is never
+// held in memory as a whole. The deserialized tree of nodes still is, until it is converted.
+struct ProcessReader<'a> {
+    process: &'a mut dyn Process,
+    line: Vec<u8>,
+    position: usize,
+}
+
+impl Read for ProcessReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.position == self.line.len() {
+            if !self.process.has_next_line() {
+                return Ok(0);
+            }
+            self.line = self.process.get_next_line().into_bytes();
+            self.line.push(b'\n');
+            self.position = 0;
+        }
+
+        let count = buf.len().min(self.line.len() - self.position);
+        buf[..count].copy_from_slice(&self.line[self.position..self.position + count]);
+        self.position += count;
+        Ok(count)
+    }
+}
+
+impl ClangAstJsonParserImpl {
+    pub fn new(process: Box<dyn Process>) -> Self {
+        ClangAstJsonParserImpl {
+            process,
+            last_seen_file: Arc::new("".to_string()),
+            last_seen_line: 0,
+            include_edges: Vec::new(),
+        }
+    }
+
+    pub fn is_process_successful(&mut self) -> bool {
+        self.process.is_successful()
+    }
+}
+
+impl ClangAstParser for ClangAstJsonParserImpl {
+    fn parse_ast(&mut self) -> Option<VecDeque<ClangAstElement>> {
+        let reader = ProcessReader {
+            process: self.process.as_mut(),
+            line: Vec::new(),
+            position: 0,
+        };
+        let root: JsonAstNode = match serde_json::from_reader(reader) {
+            Ok(root) => root,
+            Err(err) => {
+                println!("Failed to parse JSON AST: {}", err);
+                return None;
+            }
+        };
+
+        if root.kind != "TranslationUnitDecl" {
+            return None;
+        }
+
+        self.last_seen_file = Arc::new("".to_string());
+        self.last_seen_line = 0;
+        self.include_edges.clear();
+
+        let mut ast: VecDeque<ClangAstElement> = VecDeque::new();
+        self.parse_inner(root.inner, &mut ast);
+
+        Some(ast)
+    }
+}
+
+impl ClangAstJsonParserImpl {
+    // The nodes are consumed, so the deserialized tree is released while the elements are
+    // created.
+    fn parse_inner(&mut self, inner: Vec<JsonAstNode>, parent_vec: &mut VecDeque<ClangAstElement>) {
+        for inner_node in inner {
+            self.parse_node(inner_node, parent_vec);
+        }
+    }
+
+    fn parse_node(&mut self, mut node: JsonAstNode, parent_vec: &mut VecDeque<ClangAstElement>) {
+        let (file, range) = self.get_range(&node);
+
+        let element_type = match ClangAstElementType::from_str(&node.kind) {
+            Ok(element_type) => element_type,
+            Err(_) => {
+                // Like in the text dump, children of unknown elements are moved to the parent.
+                self.parse_inner(node.inner, parent_vec);
+                return;
+            }
+        };
+
+        let mut ast_element = ClangAstElement::new(
+            element_type,
+            parse_hex_id(&node.id),
+            parse_hex_id(&node.parent_decl_context_id),
+            parse_hex_id(&node.previous_decl),
+            file,
+            range,
+            create_attributes(&node),
+        );
+        ast_element.include_edges = std::mem::take(&mut self.include_edges);
+        ast_element.mangled_name = node.mangled_name.take();
+
+        // The text dump lists overrides and base classes as children, so they are added here.
+        if !node.overridden_methods.is_empty() {
+            let overridden_methods: Vec<String> = node
+                .overridden_methods
+                .iter()
+                .map(create_bare_decl_ref)
+                .collect();
+            ast_element.inner.push_back(self.create_child_element(
+                ClangAstElementType::Overrides,
+                overridden_methods.join(" "),
+            ));
+        }
+
+        for base in &node.bases {
+            let element_type = match ClangAstElementType::from_str(&base.access) {
+                Ok(element_type) => element_type,
+                Err(_) => continue,
+            };
+            let attributes = create_qual_type(&base.qual_type);
+            ast_element
+                .inner
+                .push_back(self.create_child_element(element_type, attributes));
+        }
+
+        self.parse_inner(std::mem::take(&mut node.inner), &mut ast_element.inner);
+
+        parent_vec.push_back(ast_element);
+    }
+
+    fn create_child_element(
+        &self,
+        element_type: ClangAstElementType,
+        attributes: String,
+    ) -> ClangAstElement {
+        ClangAstElement::new(
+            element_type,
+            0,
+            0,
+            0,
+            Arc::clone(&self.last_seen_file),
+            Range::create(0, 0, 0, 0),
+            attributes,
+        )
+    }
+
+    fn get_range(&mut self, node: &JsonAstNode) -> (Arc<String>, Range) {
+        // The JSON dump only writes file and line if they changed since the last written
+        // location, so all locations need to be read in the order they were written.
+        if let Some(loc) = &node.loc {
+            self.read_location(loc);
+        }
+
+        let range = match &node.range {
+            Some(range) => range,
+            None => return (Arc::clone(&self.last_seen_file), Range::create(0, 0, 0, 0)),
+        };
+
+        let start = self.read_location(&range.begin);
+        let file = Arc::clone(&self.last_seen_file);
+        let end = self.read_location(&range.end);
+
+        match (start, end) {
+            (Some(start), Some(end)) => (
+                file,
+                Range::create(start.line, start.column, end.line, end.column + 1),
+            ),
+            _ => (file, Range::create(0, 0, 0, 0)),
+        }
+    }
+
+    fn read_location(&mut self, loc: &JsonLocation) -> Option<Position> {
+        // Locations inside of macros contain the spelling and the expansion location. Both
+        // update the last seen file and line, but like the text dump the expansion is used.
+        if let Some(spelling_loc) = &loc.spelling_loc {
+            self.read_bare_location(spelling_loc);
+            return match &loc.expansion_loc {
+                Some(expansion_loc) => self.read_bare_location(expansion_loc),
+                None => None,
+            };
+        }
+
+        self.read_bare_location(loc)
+    }
+
+    fn read_bare_location(&mut self, loc: &JsonLocation) -> Option<Position> {
+        let col = loc.col?;
+
+        if let Some(file) = &loc.file {
+            if *self.last_seen_file != *file {
+                self.last_seen_file = Arc::new(file.to_string());
+            }
+            if let Some(included_from) = &loc.included_from {
+                self.include_edges.push((
+                    Arc::clone(&self.last_seen_file),
+                    Arc::new(included_from.file.to_string()),
+                ));
+            }
+        }
+        if let Some(line) = loc.line {
+            self.last_seen_line = line;
+        }
+
+        Some(Position::new(self.last_seen_line, col))
+    }
+}
+
+fn parse_hex_id(id: &str) -> usize {
+    match id.strip_prefix("0x") {
+        Some(hex_str) => usize::from_str_radix(hex_str, 16).unwrap_or(0),
+        None => 0,
+    }
+}
+
+fn create_qual_type(qual_type: &Option<JsonType>) -> String {
+    let qual_type = match qual_type {
+        Some(qual_type) => qual_type,
+        None => return "".to_string(),
+    };
+
+    match &qual_type.desugared_qual_type {
+        Some(desugared_type_str) => {
+            format!("'{}':'{}'", qual_type.qual_type, desugared_type_str)
+        }
+        None => format!("'{}'", qual_type.qual_type),
+    }
+}
+
+fn get_bare_qual_type(qual_type: &Option<JsonType>) -> &str {
+    match qual_type {
+        Some(qual_type) => &qual_type.qual_type,
+        None => "",
+    }
+}
+
+fn create_bare_decl_ref(decl: &JsonAstNode) -> String {
+    format!(
+        "{} {} '{}'",
+        decl.id,
+        decl.name,
+        get_bare_qual_type(&decl.qual_type)
+    )
+}
+
+fn push_flags(parts: &mut Vec<String>, node: &JsonAstNode) {
+    if node.is_implicit {
+        parts.push("implicit".to_string());
+    }
+    if node.is_used {
+        parts.push("used".to_string());
+    } else if node.is_referenced {
+        parts.push("referenced".to_string());
+    }
+}
+
+fn push_value_category(parts: &mut Vec<String>, node: &JsonAstNode) {
+    // The text dump doesn't mention prvalues.
+    if !node.value_category.is_empty() && node.value_category != "prvalue" {
+        parts.push(node.value_category.to_string());
+    }
+}
+
+fn create_attributes(node: &JsonAstNode) -> String {
+    let mut parts: Vec<String> = Vec::new();
+
+    match node.kind.as_str() {
+        "FunctionDecl" | "CXXMethodDecl" | "CXXConstructorDecl" | "CXXConversionDecl"
+        | "CXXDestructorDecl" => {
+            push_flags(&mut parts, node);
+            parts.push(node.name.to_string());
+            parts.push(create_qual_type(&node.qual_type));
+            if !node.storage_class.is_empty() {
+                parts.push(node.storage_class.to_string());
+            }
+            if node.inline {
+                parts.push("inline".to_string());
+            }
+            if node.is_virtual {
+                parts.push("virtual".to_string());
+            }
+            if node.pure {
+                parts.push("pure".to_string());
+            }
+        }
+        "CXXRecordDecl" | "ClassTemplateSpecializationDecl" => {
+            push_flags(&mut parts, node);
+            parts.push(node.tag_used.to_string());
+            if !node.name.is_empty() {
+                parts.push(node.name.to_string());
+            }
+            if node.complete_definition {
+                parts.push("definition".to_string());
+            }
+        }
+        "NamespaceDecl" | "ClassTemplateDecl" | "FunctionTemplateDecl" | "TypedefDecl" => {
+            push_flags(&mut parts, node);
+            parts.push(node.name.to_string());
+        }
+        "DeclRefExpr" => {
+            parts.push(create_qual_type(&node.qual_type));
+            push_value_category(&mut parts, node);
+            if let Some(referenced_decl) = &node.referenced_decl {
+                let decl_kind = referenced_decl.kind.as_str();
+                parts.push(
+                    decl_kind
+                        .strip_suffix("Decl")
+                        .unwrap_or(decl_kind)
+                        .to_string(),
+                );
+                parts.push(referenced_decl.id.to_string());
+                parts.push(format!("'{}'", referenced_decl.name));
+                parts.push(create_qual_type(&referenced_decl.qual_type));
+            }
+        }
+        "MemberExpr" => {
+            parts.push(create_qual_type(&node.qual_type));
+            push_value_category(&mut parts, node);
+            let member_access = if node.is_arrow { "->" } else { "." };
+            parts.push(format!("{}{}", member_access, node.name));
+            parts.push(node.referenced_member_decl.to_string());
+        }
+        "TemplateArgument" if node.qual_type.is_some() => {
+            parts.push("type".to_string());
+            parts.push(create_qual_type(&node.qual_type));
+        }
+        "CXXConstructExpr" | "CXXTemporaryObjectExpr" => {
+            parts.push(create_qual_type(&node.qual_type));
+            push_value_category(&mut parts, node);
+            if node.ctor_type.is_some() {
+                parts.push(format!("'{}'", get_bare_qual_type(&node.ctor_type)));
+            }
+            if node.elidable {
+                parts.push("elidable".to_string());
+            }
+        }
+        "VarDecl" => {
+            push_flags(&mut parts, node);
+            parts.push(node.name.to_string());
+            parts.push(create_qual_type(&node.qual_type));
+            if !node.storage_class.is_empty() {
+                parts.push(node.storage_class.to_string());
+            }
+            if !node.init.is_empty() {
+                parts.push(format!("{}init", node.init));
+            }
+        }
+        "CallExpr" | "CXXMemberCallExpr" | "CXXOperatorCallExpr" | "CXXBindTemporaryExpr"
+        | "CXXDeleteExpr" => {
+            parts.push(create_qual_type(&node.qual_type));
+            push_value_category(&mut parts, node);
+        }
+        "TemplateTypeParmDecl" | "NonTypeTemplateParmDecl" | "TemplateTemplateParmDecl" => {
+            push_flags(&mut parts, node);
+            if !node.tag_used.is_empty() {
+                parts.push(node.tag_used.to_string());
+            }
+            if node.qual_type.is_some() {
+                parts.push(create_qual_type(&node.qual_type));
+            }
+            parts.push(format!("depth {} index {}", node.depth, node.index));
+            if node.is_parameter_pack {
+                parts.push("...".to_string());
+            }
+            if !node.name.is_empty() {
+                parts.push(node.name.to_string());
+            }
+        }
+        "UnresolvedLookupExpr" => {
+            parts.push(create_qual_type(&node.qual_type));
+            push_value_category(&mut parts, node);
+            let adl = if node.uses_adl { "(ADL)" } else { "(no ADL)" };
+            parts.push(format!("{} = '{}'", adl, node.name));
+            for lookup in &node.lookups {
+                parts.push(lookup.id.to_string());
+            }
+        }
+        "CXXDependentScopeMemberExpr" | "UnresolvedMemberExpr" => {
+            parts.push(create_qual_type(&node.qual_type));
+            push_value_category(&mut parts, node);
+            let member = if node.member.is_empty() {
+                &node.name
+            } else {
+                &node.member
+            };
+            if !member.is_empty() {
+                let member_access = if node.is_arrow { "->" } else { "." };
+                parts.push(format!("{}{}", member_access, member));
+            }
+        }
+        _ => {}
+    }
+
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast_reader::clang_ast_parser::ClangAstParserImpl;
+    use crate::process::dummy_process::DummyProcess;
+
+    fn create_json_parser(json_str: &str) -> ClangAstJsonParserImpl {
+        let mut process = DummyProcess::new();
+        for line in json_str.lines() {
+            process.add_line(line.to_string());
+        }
+        ClangAstJsonParserImpl::new(Box::new(process))
+    }
+
+    fn create_text_parser(text_str: &str) -> ClangAstParserImpl {
+        let mut process = DummyProcess::new();
+        for line in text_str.lines() {
+            process.add_line(line.to_string());
+        }
+        ClangAstParserImpl::new(Box::new(process))
+    }
+
+    fn assert_same_elements(
+        json_elements: &VecDeque<ClangAstElement>,
+        text_elements: &VecDeque<ClangAstElement>,
+    ) {
+        assert_eq!(json_elements.len(), text_elements.len());
+        for (json_element, text_element) in json_elements.iter().zip(text_elements.iter()) {
+            assert_eq!(json_element.element_type, text_element.element_type);
+            assert_eq!(json_element.element_id, text_element.element_id);
+            assert_eq!(
+                json_element.parent_element_id,
+                text_element.parent_element_id
+            );
+            assert_eq!(json_element.prev_element_id, text_element.prev_element_id);
+            assert_eq!(json_element.file, text_element.file);
+            assert_eq!(json_element.range, text_element.range);
+            // The JSON dump doesn't contain the qualified name of overridden methods.
+            if json_element.element_type != ClangAstElementType::Overrides {
+                assert_eq!(json_element.attributes, text_element.attributes);
+            }
+            assert_same_elements(&json_element.inner, &text_element.inner);
+        }
+    }
+
+    #[test]
+    fn parse_ast_invalid_json() {
+        let mut parser = create_json_parser("{ \"kind\": \"TranslationUnitDecl\", ");
+        assert!(parser.parse_ast().is_none());
+
+        let mut parser = create_json_parser("{ \"kind\": \"FunctionDecl\" }");
+        assert!(parser.parse_ast().is_none());
+    }
+
+    #[test]
+    fn parse_ast_unknown_elements_are_skipped() {
+        let mut parser = create_json_parser(
+            r#"{
+                "id": "0x1", "kind": "TranslationUnitDecl", "loc": {}, "range": {"begin": {}, "end": {}},
+                "inner": [
+                    {
+                        "id": "0x2", "kind": "LinkageSpecDecl",
+                        "loc": {"offset": 0, "file": "file.cpp", "line": 1, "col": 1, "tokLen": 6},
+                        "range": {"begin": {"offset": 0, "col": 1, "tokLen": 6}, "end": {"offset": 40, "line": 3, "col": 1, "tokLen": 1}},
+                        "inner": [
+                            {
+                                "id": "0x3", "kind": "FunctionDecl",
+                                "loc": {"offset": 20, "line": 2, "col": 6, "tokLen": 3},
+                                "range": {"begin": {"offset": 15, "col": 1, "tokLen": 4}, "end": {"offset": 30, "col": 16, "tokLen": 1}},
+                                "name": "foo", "type": {"qualType": "void ()"}
+                            }
+                        ]
+                    }
+                ]
+            }"#,
+        );
+
+        let ast = parser.parse_ast().unwrap();
+        assert_eq!(ast.len(), 1);
+        assert_eq!(ast[0].element_type, ClangAstElementType::FunctionDecl);
+        assert_eq!(ast[0].element_id, 3);
+        assert_eq!(*ast[0].file, "file.cpp");
+        assert_eq!(ast[0].range, Range::create(2, 1, 2, 17));
+        assert_eq!(ast[0].attributes, "foo 'void ()'");
+    }
+
+    #[test]
+    fn parse_ast_include_edges() {
+        let mut parser = create_json_parser(
+            r#"{
+                "id": "0x1", "kind": "TranslationUnitDecl",
+                "inner": [
+                    {
+                        "id": "0x2", "kind": "FunctionDecl",
+                        "loc": {"offset": 5, "file": "inner.h", "line": 1, "col": 6, "tokLen": 3, "includedFrom": {"file": "outer.h"}},
+                        "range": {"begin": {"offset": 0, "col": 1, "tokLen": 4}, "end": {"offset": 10, "col": 11, "tokLen": 1}},
+                        "name": "foo", "type": {"qualType": "void ()"}
+                    },
+                    {
+                        "id": "0x3", "kind": "FunctionDecl",
+                        "loc": {"offset": 5, "col": 6, "tokLen": 3},
+                        "range": {"begin": {"offset": 0, "col": 1, "tokLen": 4}, "end": {"offset": 10, "col": 11, "tokLen": 1}},
+                        "name": "bar", "type": {"qualType": "void ()"}
+                    }
+                ]
+            }"#,
+        );
+
+        let ast = parser.parse_ast().unwrap();
+        assert_eq!(ast.len(), 2);
+        assert_eq!(ast[0].include_edges.len(), 1);
+        assert_eq!(*ast[0].include_edges[0].0, "inner.h");
+        assert_eq!(*ast[0].include_edges[0].1, "outer.h");
+        assert!(ast[1].include_edges.is_empty());
+    }
+
+    #[test]
+    fn parse_ast_mangled_name() {
+        let mut parser = create_json_parser(
+            r#"{
+                "id": "0x1", "kind": "TranslationUnitDecl",
+                "inner": [
+                    {
+                        "id": "0x2", "kind": "FunctionDecl",
+                        "loc": {"offset": 5, "file": "file.cpp", "line": 1, "col": 6, "tokLen": 3},
+                        "range": {"begin": {"offset": 0, "col": 1, "tokLen": 4}, "end": {"offset": 10, "col": 11, "tokLen": 1}},
+                        "name": "foo", "mangledName": "_Z3foov", "type": {"qualType": "void ()"}
+                    },
+                    {
+                        "id": "0x3", "kind": "NamespaceDecl",
+                        "loc": {"offset": 20, "line": 2, "col": 11, "tokLen": 2},
+                        "range": {"begin": {"offset": 10, "col": 1, "tokLen": 9}, "end": {"offset": 25, "col": 16, "tokLen": 1}},
+                        "name": "ns"
+                    }
+                ]
+            }"#,
+        );
+
+        let ast = parser.parse_ast().unwrap();
+        assert_eq!(ast.len(), 2);
+        assert_eq!(ast[0].mangled_name, Some("_Z3foov".to_string()));
+        assert_eq!(ast[1].mangled_name, None);
+    }
+
+    #[test]
+    fn parse_ast_macro_locations() {
+        let mut parser = create_json_parser(
+            r#"{
+                "id": "0x1", "kind": "TranslationUnitDecl",
+                "inner": [
+                    {
+                        "id": "0x2", "kind": "FunctionDecl",
+                        "loc": {
+                            "spellingLoc": {"offset": 20, "file": "macro.h", "line": 2, "col": 6, "tokLen": 3},
+                            "expansionLoc": {"offset": 50, "file": "file.cpp", "line": 5, "col": 1, "tokLen": 6}
+                        },
+                        "range": {
+                            "begin": {"spellingLoc": {"offset": 15, "file": "macro.h", "line": 2, "col": 1, "tokLen": 4}, "expansionLoc": {"offset": 50, "file": "file.cpp", "line": 5, "col": 1, "tokLen": 6}},
+                            "end": {"spellingLoc": {"offset": 30, "file": "macro.h", "line": 2, "col": 16, "tokLen": 1}, "expansionLoc": {"offset": 50, "file": "file.cpp", "line": 5, "col": 1, "tokLen": 6}}
+                        },
+                        "name": "foo", "type": {"qualType": "void ()"}
+                    },
+                    {
+                        "id": "0x3", "kind": "FunctionDecl",
+                        "loc": {"offset": 70, "line": 7, "col": 6, "tokLen": 3},
+                        "range": {"begin": {"offset": 65, "col": 1, "tokLen": 4}, "end": {"offset": 80, "col": 16, "tokLen": 1}},
+                        "name": "bar", "type": {"qualType": "void ()"}
+                    }
+                ]
+            }"#,
+        );
+
+        let ast = parser.parse_ast().unwrap();
+        assert_eq!(ast.len(), 2);
+        assert_eq!(*ast[0].file, "file.cpp");
+        assert_eq!(ast[0].range, Range::create(5, 1, 5, 2));
+        // The expansion location was written last, so the following location is relative to it.
+        assert_eq!(*ast[1].file, "file.cpp");
+        assert_eq!(ast[1].range, Range::create(7, 1, 7, 17));
+    }
+
+    #[test]
+    fn parse_ast_same_as_text_dump() {
+        let text_dump = r#"TranslationUnitDecl 0x100 <<invalid sloc>> <invalid sloc>
+|-CXXRecordDecl 0x200 <file.cpp:1:1, line:4:1> line:1:7 referenced class Base definition
+| `-CXXMethodDecl 0x210 <line:3:5, col:40> col:17 used add 'int (int, int)' virtual
+|-CXXRecordDecl 0x300 <line:6:1, line:10:1> line:6:7 class Child definition
+| |-public 'Base':'Base'
+| `-CXXMethodDecl 0x310 <line:8:5, line:9:5> line:8:9 used add 'int (int, int)'
+|   |-Overrides: [ 0x210 Base::add 'int (int, int)' ]
+|   `-CompoundStmt 0x320 <col:30, line:9:5>
+`-FunctionDecl 0x400 <line:12:1, line:15:1> line:12:5 main 'int ()'
+  `-CompoundStmt 0x410 <col:12, line:15:1>
+    `-CXXMemberCallExpr 0x420 <line:14:12, col:24> 'int'
+      `-MemberExpr 0x430 <col:12, col:14> '<bound member function type>' .add 0x310
+        `-DeclRefExpr 0x440 <col:12> 'Child' lvalue Var 0x450 'child' 'Child'"#;
+
+        let json_dump = r#"{
+  "id": "0x100", "kind": "TranslationUnitDecl", "loc": {}, "range": {"begin": {}, "end": {}},
+  "inner": [
+    {
+      "id": "0x200", "kind": "CXXRecordDecl",
+      "loc": {"offset": 6, "file": "file.cpp", "line": 1, "col": 7, "tokLen": 4},
+      "range": {"begin": {"offset": 0, "col": 1, "tokLen": 5}, "end": {"offset": 60, "line": 4, "col": 1, "tokLen": 1}},
+      "isReferenced": true, "name": "Base", "tagUsed": "class", "completeDefinition": true,
+      "inner": [
+        {
+          "id": "0x210", "kind": "CXXMethodDecl",
+          "loc": {"offset": 30, "line": 3, "col": 17, "tokLen": 3},
+          "range": {"begin": {"offset": 18, "col": 5, "tokLen": 7}, "end": {"offset": 53, "col": 40, "tokLen": 1}},
+          "isUsed": true, "name": "add", "type": {"qualType": "int (int, int)"}, "virtual": true
+        }
+      ]
+    },
+    {
+      "id": "0x300", "kind": "CXXRecordDecl",
+      "loc": {"offset": 70, "line": 6, "col": 7, "tokLen": 5},
+      "range": {"begin": {"offset": 64, "col": 1, "tokLen": 5}, "end": {"offset": 140, "line": 10, "col": 1, "tokLen": 1}},
+      "name": "Child", "tagUsed": "class", "completeDefinition": true,
+      "bases": [{"access": "public", "type": {"desugaredQualType": "Base", "qualType": "Base"}, "writtenAccess": "public"}],
+      "inner": [
+        {
+          "id": "0x310", "kind": "CXXMethodDecl",
+          "loc": {"offset": 100, "line": 8, "col": 9, "tokLen": 3},
+          "range": {"begin": {"offset": 96, "col": 5, "tokLen": 3}, "end": {"offset": 130, "line": 9, "col": 5, "tokLen": 1}},
+          "isUsed": true, "name": "add", "type": {"qualType": "int (int, int)"},
+          "overriddenMethods": [{"id": "0x210", "kind": "CXXMethodDecl", "name": "add", "type": {"qualType": "int (int, int)"}}],
+          "inner": [
+            {
+              "id": "0x320", "kind": "CompoundStmt",
+              "range": {"begin": {"offset": 121, "line": 8, "col": 30, "tokLen": 1}, "end": {"offset": 130, "line": 9, "col": 5, "tokLen": 1}}
+            }
+          ]
+        }
+      ]
+    },
+    {
+      "id": "0x400", "kind": "FunctionDecl",
+      "loc": {"offset": 150, "line": 12, "col": 5, "tokLen": 4},
+      "range": {"begin": {"offset": 146, "col": 1, "tokLen": 3}, "end": {"offset": 200, "line": 15, "col": 1, "tokLen": 1}},
+      "name": "main", "type": {"qualType": "int ()"},
+      "inner": [
+        {
+          "id": "0x410", "kind": "CompoundStmt",
+          "range": {"begin": {"offset": 157, "line": 12, "col": 12, "tokLen": 1}, "end": {"offset": 200, "line": 15, "col": 1, "tokLen": 1}},
+          "inner": [
+            {
+              "id": "0x420", "kind": "CXXMemberCallExpr",
+              "range": {"begin": {"offset": 180, "line": 14, "col": 12, "tokLen": 5}, "end": {"offset": 192, "col": 24, "tokLen": 1}},
+              "type": {"qualType": "int"}, "valueCategory": "prvalue",
+              "inner": [
+                {
+                  "id": "0x430", "kind": "MemberExpr",
+                  "range": {"begin": {"offset": 180, "col": 12, "tokLen": 5}, "end": {"offset": 182, "col": 14, "tokLen": 3}},
+                  "type": {"qualType": "<bound member function type>"}, "valueCategory": "prvalue",
+                  "name": "add", "isArrow": false, "referencedMemberDecl": "0x310",
+                  "inner": [
+                    {
+                      "id": "0x440", "kind": "DeclRefExpr",
+                      "range": {"begin": {"offset": 180, "col": 12, "tokLen": 5}, "end": {"offset": 180, "col": 12, "tokLen": 5}},
+                      "type": {"qualType": "Child"}, "valueCategory": "lvalue",
+                      "referencedDecl": {"id": "0x450", "kind": "VarDecl", "name": "child", "type": {"qualType": "Child"}}
+                    }
+                  ]
+                }
+              ]
+            }
+          ]
+        }
+      ]
+    }
+  ]
+}"#;
+
+        let text_ast = create_text_parser(text_dump).parse_ast().unwrap();
+        let json_ast = create_json_parser(json_dump).parse_ast().unwrap();
+
+        assert_eq!(json_ast.len(), 3);
+        assert_same_elements(&json_ast, &text_ast);
+    }
+}
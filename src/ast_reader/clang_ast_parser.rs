@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
-use std::rc::Rc;
 use std::str::FromStr;
+use std::sync::Arc;
 
 use super::super::location::position::Position;
 use super::super::location::range::Range;
@@ -14,7 +14,7 @@ pub trait ClangAstParser {
 
 pub struct ClangAstParserImpl {
     process: Box<dyn Process>,
-    files: Vec<Rc<String>>,
+    files: Vec<Arc<String>>,
     last_seen_line: usize,
 }
 
@@ -26,10 +26,14 @@ impl ClangAstParserImpl {
             last_seen_line: 0,
         };
 
-        parser_impl.files.push(Rc::new("".to_string()));
+        parser_impl.files.push(Arc::new("".to_string()));
 
         parser_impl
     }
+
+    pub fn is_process_successful(&mut self) -> bool {
+        self.process.is_successful()
+    }
 }
 
 impl ClangAstParser for ClangAstParserImpl {
@@ -48,7 +52,7 @@ impl ClangAstParser for ClangAstParserImpl {
 
         self.last_seen_line = 0;
         self.files.clear();
-        self.files.push(Rc::new("".to_string()));
+        self.files.push(Arc::new("".to_string()));
 
         if self.process.has_next_line() {
             self.parse_ast_line(1, &mut ast);
@@ -119,7 +123,7 @@ impl ClangAstParserImpl {
                         0,
                         0,
                         0,
-                        Rc::clone(&file),
+                        Arc::clone(file),
                         Range::create(0, 0, 0, 0),
                         "".to_string(),
                     ));
@@ -149,7 +153,7 @@ impl ClangAstParserImpl {
                         0,
                         0,
                         0,
-                        Rc::clone(&file),
+                        Arc::clone(file),
                         Range::create(0, 0, 0, 0),
                         remaining_parts,
                     ));
@@ -209,7 +213,7 @@ impl ClangAstParserImpl {
                     id,
                     parent_element_id,
                     prev_element_id,
-                    Rc::clone(&file),
+                    Arc::clone(file),
                     range,
                     remaining_parts,
                 ));
@@ -300,7 +304,7 @@ impl ClangAstParserImpl {
         if element.starts_with("<") && element.ends_with(",") {
             let parts: Vec<&str> = element[1..element.len() - 1].split(':').collect();
             if parts.len() == 3 {
-                self.files.push(Rc::new(parts[0].to_string()));
+                self.files.push(Arc::new(parts[0].to_string()));
 
                 if let Ok(line) = parts[1].parse::<usize>() {
                     if let Ok(col) = parts[2].parse::<usize>() {
@@ -310,7 +314,7 @@ impl ClangAstParserImpl {
                 }
             }
             if parts.len() == 4 {
-                self.files.push(Rc::new([parts[0], parts[1]].join(":")));
+                self.files.push(Arc::new([parts[0], parts[1]].join(":")));
 
                 if let Ok(line) = parts[2].parse::<usize>() {
                     if let Ok(col) = parts[3].parse::<usize>() {
@@ -3,25 +3,73 @@ use serde::Deserialize;
 #[derive(Debug, Deserialize)]
 pub struct CompileCommand {
     pub directory: String,
-    pub command: String,
+    pub command: Option<String>,
+    pub arguments: Option<Vec<String>>,
     pub file: String,
     pub output: Option<String>,
 }
 
-fn read_compile_commands_json(file: &std::fs::File) -> Option<Vec<CompileCommand>> {
-    let reader = std::io::BufReader::new(file);
-    let entries: Result<Vec<CompileCommand>, serde_json::Error> = serde_json::from_reader(reader);
-    match entries {
-        Ok(entries) => Some(entries),
-        Err(_) => None,
+fn get_entry_description(index: usize, entry: &serde_json::Value) -> String {
+    match entry.get("file").and_then(|file| file.as_str()) {
+        Some(file) => format!("#{} (file: {})", index, file),
+        None => format!("#{}", index),
     }
 }
 
-pub fn read_compile_commands_json_file(file: &std::path::PathBuf) -> Option<Vec<CompileCommand>> {
-    let file = std::fs::File::open(file).ok();
-    match file {
-        Some(file) => read_compile_commands_json(&file),
-        None => None,
+// Returns the descriptions of all failures if the file or any of its entries is invalid.
+fn read_compile_commands_json<R: std::io::Read>(
+    reader: R,
+) -> Result<Vec<CompileCommand>, Vec<String>> {
+    let reader = std::io::BufReader::new(reader);
+    let raw_entries: Vec<serde_json::Value> = match serde_json::from_reader(reader) {
+        Ok(raw_entries) => raw_entries,
+        Err(err) => {
+            return Err(vec![format!(
+                "Failed to read compile_commands.json: {}",
+                err
+            )]);
+        }
+    };
+
+    let mut entries = Vec::new();
+    let mut failures = Vec::new();
+    for (index, raw_entry) in raw_entries.into_iter().enumerate() {
+        let entry_description = get_entry_description(index, &raw_entry);
+        let entry: CompileCommand = match serde_json::from_value(raw_entry) {
+            Ok(entry) => entry,
+            Err(err) => {
+                failures.push(format!(
+                    "Failed to deserialize compile_commands.json entry {}: {}",
+                    entry_description, err
+                ));
+                continue;
+            }
+        };
+
+        if entry.command.is_none() && entry.arguments.is_none() {
+            failures.push(format!(
+                "compile_commands.json entry {} has neither a \"command\" nor an \"arguments\" field",
+                entry_description
+            ));
+            continue;
+        }
+
+        entries.push(entry);
+    }
+
+    if failures.is_empty() {
+        Ok(entries)
+    } else {
+        Err(failures)
+    }
+}
+
+pub fn read_compile_commands_json_file(
+    file: &std::path::PathBuf,
+) -> Result<Vec<CompileCommand>, Vec<String>> {
+    match std::fs::File::open(file) {
+        Ok(file) => read_compile_commands_json(&file),
+        Err(err) => Err(vec![format!("Failed to open {}: {}", file.display(), err)]),
     }
 }
 
@@ -35,7 +83,7 @@ mod tests {
     fn read_compile_commands_json_file_test() {
         let compile_commands_json = PathBuf::from("tests/playground/compile_commands.json");
         let compile_commands = read_compile_commands_json_file(&compile_commands_json);
-        assert!(compile_commands.is_some());
+        assert!(compile_commands.is_ok());
         let compile_commands = compile_commands.unwrap();
         assert_ne!(compile_commands.len(), 0);
     }
@@ -58,8 +106,8 @@ mod tests {
         let compile_command = &compile_commands[0];
         assert_eq!(compile_command.directory, "/home/user/project");
         assert_eq!(
-            compile_command.command,
-            "/usr/bin/clang++ -Iinclude src/main.cpp -o build/main.o"
+            compile_command.command.as_deref(),
+            Some("/usr/bin/clang++ -Iinclude src/main.cpp -o build/main.o")
         );
         assert_eq!(compile_command.file, "src/main.cpp");
         match &compile_command.output {
@@ -85,8 +133,8 @@ mod tests {
         let compile_command = &compile_commands[0];
         assert_eq!(compile_command.directory, "/home/user/project");
         assert_eq!(
-            compile_command.command,
-            "/usr/bin/clang++ -Iinclude src/main.cpp -o build/main.o"
+            compile_command.command.as_deref(),
+            Some("/usr/bin/clang++ -Iinclude src/main.cpp -o build/main.o")
         );
         assert_eq!(compile_command.file, "src/main.cpp");
         assert!(compile_command.output.is_none());
@@ -110,8 +158,8 @@ mod tests {
         let compile_command = &compile_commands[0];
         assert_eq!(compile_command.directory, "C:\\home\\user\\project");
         assert_eq!(
-            compile_command.command,
-            "C:\\usr\\bin\\clang++ -Iinclude src\\main.cpp -o build\\main.o"
+            compile_command.command.as_deref(),
+            Some("C:\\usr\\bin\\clang++ -Iinclude src\\main.cpp -o build\\main.o")
         );
         assert_eq!(compile_command.file, "src\\main.cpp");
         match &compile_command.output {
@@ -144,8 +192,8 @@ mod tests {
         let compile_command = &compile_commands[0];
         assert_eq!(compile_command.directory, "/home/user/project");
         assert_eq!(
-            compile_command.command,
-            "/usr/bin/clang++ -Iinclude src/main.cpp -o build/main.o"
+            compile_command.command.as_deref(),
+            Some("/usr/bin/clang++ -Iinclude src/main.cpp -o build/main.o")
         );
         assert_eq!(compile_command.file, "src/main.cpp");
         match &compile_command.output {
@@ -156,8 +204,8 @@ mod tests {
         let compile_command = &compile_commands[1];
         assert_eq!(compile_command.directory, "/home/user/project");
         assert_eq!(
-            compile_command.command,
-            "/usr/bin/clang++ -Iinclude src/helper.cpp -o build/helper.o"
+            compile_command.command.as_deref(),
+            Some("/usr/bin/clang++ -Iinclude src/helper.cpp -o build/helper.o")
         );
         assert_eq!(compile_command.file, "src/helper.cpp");
         match &compile_command.output {
@@ -285,4 +333,105 @@ mod tests {
             serde_json::from_str(virtual_file);
         assert!(compile_commands.is_err());
     }
+
+    #[test]
+    fn read_compile_commands_json_arguments_test() {
+        let virtual_file = r#"
+        [
+            {
+                "directory": "/home/user/project",
+                "arguments": ["/usr/bin/clang++", "-Iinclude dir", "-c", "src/main.cpp"],
+                "file": "src/main.cpp"
+            }
+        ]
+        "#;
+
+        let compile_commands = read_compile_commands_json(virtual_file.as_bytes()).unwrap();
+        assert_eq!(compile_commands.len(), 1);
+        let compile_command = &compile_commands[0];
+        assert!(compile_command.command.is_none());
+        assert_eq!(
+            compile_command.arguments,
+            Some(vec![
+                "/usr/bin/clang++".to_string(),
+                "-Iinclude dir".to_string(),
+                "-c".to_string(),
+                "src/main.cpp".to_string()
+            ])
+        );
+        assert_eq!(compile_command.file, "src/main.cpp");
+    }
+
+    #[test]
+    fn read_compile_commands_json_command_and_arguments_test() {
+        let virtual_file = r#"
+        [
+            {
+                "directory": "/home/user/project",
+                "command": "/usr/bin/clang++ -c src/main.cpp",
+                "arguments": ["/usr/bin/clang++", "-c", "src/main.cpp"],
+                "file": "src/main.cpp"
+            }
+        ]
+        "#;
+
+        let compile_commands = read_compile_commands_json(virtual_file.as_bytes()).unwrap();
+        assert_eq!(compile_commands.len(), 1);
+        assert!(compile_commands[0].command.is_some());
+        assert!(compile_commands[0].arguments.is_some());
+    }
+
+    #[test]
+    fn read_compile_commands_json_no_command_or_arguments_test() {
+        let virtual_file = r#"
+        [
+            {
+                "directory": "/home/user/project",
+                "command": "/usr/bin/clang++ -c src/main.cpp",
+                "file": "src/main.cpp"
+            },
+            {
+                "directory": "/home/user/project",
+                "file": "src/helper.cpp"
+            }
+        ]
+        "#;
+
+        let failures = read_compile_commands_json(virtual_file.as_bytes()).unwrap_err();
+        assert_eq!(
+            failures,
+            vec![
+                "compile_commands.json entry #1 (file: src/helper.cpp) has neither a \"command\" nor an \"arguments\" field"
+            ]
+        );
+    }
+
+    #[test]
+    fn read_compile_commands_json_invalid_member_reported_test() {
+        let virtual_file = r#"
+        [
+            {
+                "directory": "/home/user/project",
+                "command": "/usr/bin/clang++ -c src/main.cpp",
+                "file": "src/main.cpp"
+            },
+            {
+                "directory": "/home/user/project",
+                "arguments": "/usr/bin/clang++ -c src/helper.cpp",
+                "file": "src/helper.cpp"
+            },
+            {
+                "directory": "/home/user/project",
+                "command": "/usr/bin/clang++ -c src/other.cpp"
+            }
+        ]
+        "#;
+
+        let failures = read_compile_commands_json(virtual_file.as_bytes()).unwrap_err();
+        assert_eq!(failures.len(), 2);
+        assert!(failures[0].starts_with(
+            "Failed to deserialize compile_commands.json entry #1 (file: src/helper.cpp):"
+        ));
+        assert!(failures[1].starts_with("Failed to deserialize compile_commands.json entry #2:"));
+    }
 }
@@ -1,4 +1,6 @@
 pub mod clang_ast_element;
 pub mod clang_ast_element_type;
+pub mod clang_ast_format;
+pub mod clang_ast_json_parser;
 pub mod clang_ast_parser;
 pub mod compile_commands_reader;
@@ -1,7 +1,8 @@
 use std::{
     cell::RefCell,
-    collections::{HashMap, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     rc::Rc,
+    sync::Arc,
 };
 
 use crate::{
@@ -17,6 +18,8 @@ use crate::{
     location::range::Range,
 };
 
+type OpenFuncConnections = HashMap<usize, Vec<(Range, Rc<RefCell<FuncStructure>>)>>;
+
 struct ClangAstWalkerInternal {
     pub db: Rc<RefCell<DatabaseSqlite>>,
     pub file_path: String,
@@ -24,9 +27,27 @@ struct ClangAstWalkerInternal {
     pub known_func_decls_and_impls: HashMap<usize, Rc<RefCell<FuncStructure>>>,
     pub known_classes: HashMap<String, Rc<RefCell<CppClass>>>,
     pub current_class_stack: Vec<Rc<RefCell<CppClass>>>,
-    pub open_func_call_connections: HashMap<usize, Vec<(Range, Rc<RefCell<FuncStructure>>)>>,
+    pub open_func_call_connections: OpenFuncConnections,
+    pub open_func_ref_connections: OpenFuncConnections,
     pub current_func_impl_ast_id: usize,
+    // The first inner element of a call expression is the called function, any other mention
+    // of a function only references it.
+    pub current_callee_ast_id: usize,
     pub ignored_namespaces: Vec<String>,
+    // Constructors are stored by qualified name and destructors by class name, as their
+    // calls don't reference the declaration id.
+    pub known_constructors: HashMap<String, Rc<RefCell<FuncStructure>>>,
+    pub known_destructors: HashMap<String, Rc<RefCell<FuncStructure>>>,
+    pub open_constructor_calls: Vec<(String, Range, Rc<RefCell<FuncStructure>>)>,
+    pub open_destructor_calls: Vec<(String, Range, Rc<RefCell<FuncStructure>>)>,
+    pub implicit_destructor_calls: bool,
+    pub current_scope_range: Range,
+    // Template patterns and their instantiations share the position of the function impl,
+    // which is used to bind unresolved calls of the pattern to the instantiated calls.
+    pub bind_unresolved_calls: bool,
+    pub func_impls_by_position: HashMap<(String, Range), Vec<Rc<RefCell<FuncStructure>>>>,
+    pub in_template_pattern: bool,
+    pub known_include_edges: HashSet<(Arc<String>, Arc<String>)>,
 }
 
 pub fn walk_ast_2_func_call_db(
@@ -34,7 +55,13 @@ pub fn walk_ast_2_func_call_db(
     parsed_ast: VecDeque<ClangAstElement>,
     db: Rc<RefCell<DatabaseSqlite>>,
     ignored_namespaces: &Vec<String>,
+    implicit_destructor_calls: bool,
+    bind_unresolved_calls: bool,
 ) {
+    // Write the whole translation unit at once. If walking fails, the transaction is rolled back
+    // and no partial content of the file is left in the database.
+    let transaction = db.borrow().begin_transaction();
+
     // Make sure that the file is in the database, so that we can reference it.
     let main_file = db.borrow().get_or_add_cpp_file(&file_path);
     let mut current_file_name_str = main_file.borrow().get_name().to_string();
@@ -47,8 +74,20 @@ pub fn walk_ast_2_func_call_db(
         known_classes: HashMap::new(),
         current_class_stack: Vec::new(),
         open_func_call_connections: HashMap::new(),
+        open_func_ref_connections: HashMap::new(),
         current_func_impl_ast_id: 0,
+        current_callee_ast_id: 0,
         ignored_namespaces: ignored_namespaces.clone(),
+        known_constructors: HashMap::new(),
+        known_destructors: HashMap::new(),
+        open_constructor_calls: Vec::new(),
+        open_destructor_calls: Vec::new(),
+        implicit_destructor_calls,
+        current_scope_range: Range::create(0, 0, 0, 0),
+        bind_unresolved_calls,
+        func_impls_by_position: HashMap::new(),
+        in_template_pattern: false,
+        known_include_edges: HashSet::new(),
     };
 
     for ast_element in parsed_ast {
@@ -69,10 +108,127 @@ pub fn walk_ast_2_func_call_db(
             current_file_name_str = walker.current_file.borrow().get_name().to_string();
         }
 
+        register_include_edges(&ast_element, &mut walker, &main_file);
         handle_ast_element(&ast_element, &mut walker, "");
     }
 
     map_open_func_call_connections(&mut walker);
+    map_open_func_ref_connections(&walker);
+    map_open_special_member_func_calls(&walker.open_constructor_calls, &walker.known_constructors);
+    map_open_special_member_func_calls(&walker.open_destructor_calls, &walker.known_destructors);
+    if walker.bind_unresolved_calls {
+        bind_unresolved_func_calls(&walker);
+    }
+
+    transaction.commit();
+}
+
+fn bind_unresolved_func_calls(walker: &ClangAstWalkerInternal) {
+    let db_connection = match walker.db.borrow().get_db_connection() {
+        Some(db_connection) => db_connection,
+        None => return,
+    };
+
+    for func_impls in walker.func_impls_by_position.values() {
+        for pattern in func_impls {
+            let unresolved_func_calls = pattern.borrow_mut().get_unresolved_func_calls().clone();
+            if unresolved_func_calls.is_empty() {
+                continue;
+            }
+
+            for instantiation in func_impls {
+                if instantiation == pattern {
+                    continue;
+                }
+                let mut instantiation = instantiation.borrow_mut();
+                let mut func_calls = instantiation.get_func_calls().clone();
+                func_calls.extend(instantiation.get_virtual_func_calls().clone());
+
+                for unresolved_func_call in &unresolved_func_calls {
+                    let unresolved_func_call = unresolved_func_call.borrow();
+                    for func_call in &func_calls {
+                        let func_call = func_call.borrow();
+                        if func_call.get_range() == unresolved_func_call.get_range()
+                            && func_call.get_name() == unresolved_func_call.get_name()
+                        {
+                            FuncStructure::bind_unresolved_func_call(
+                                &db_connection,
+                                &unresolved_func_call,
+                                &func_call,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn register_include_edges(
+    ast_element: &ClangAstElement,
+    walker: &mut ClangAstWalkerInternal,
+    main_file: &Rc<RefCell<FileStructure>>,
+) {
+    for include_edge in &ast_element.include_edges {
+        let (included_file, including_file) = include_edge;
+        if **included_file == walker.file_path || walker.known_include_edges.contains(include_edge)
+        {
+            continue;
+        }
+        walker.known_include_edges.insert(include_edge.clone());
+
+        let included_hpp_file = walker.db.borrow().get_or_add_hpp_file(included_file);
+        included_hpp_file
+            .borrow_mut()
+            .add_referenced_from_source_file(main_file);
+
+        // Headers included by the main file are already referenced from it.
+        if **including_file != walker.file_path {
+            let including_hpp_file = walker.db.borrow().get_or_add_hpp_file(including_file);
+            including_hpp_file
+                .borrow_mut()
+                .add_referenced_from_source_file(main_file);
+            included_hpp_file
+                .borrow_mut()
+                .add_referenced_from_header_file(&including_hpp_file);
+        }
+    }
+
+    for inner_element in &ast_element.inner {
+        register_include_edges(inner_element, walker, main_file);
+    }
+}
+
+fn map_open_special_member_func_calls(
+    open_calls: &Vec<(String, Range, Rc<RefCell<FuncStructure>>)>,
+    known_funcs: &HashMap<String, Rc<RefCell<FuncStructure>>>,
+) {
+    for (key, range, func_impl) in open_calls {
+        // Calls to implicit or ignored constructors and destructors are dropped.
+        if let Some(func) = known_funcs.get(key) {
+            let creation_args = func.borrow().convert_func2func_creation_args4call(range);
+            func_impl.borrow_mut().get_or_add_func_call(&creation_args);
+        }
+    }
+}
+
+fn map_open_func_ref_connections(walker: &ClangAstWalkerInternal) {
+    let mut sorted_keys: Vec<_> = walker.open_func_ref_connections.keys().collect();
+    sorted_keys.sort();
+
+    for func_decl_id in sorted_keys {
+        let func_decl = match walker.known_func_decls_and_impls.get(func_decl_id) {
+            Some(func_decl) => func_decl,
+            None => continue,
+        };
+
+        for (range, func_impl) in &walker.open_func_ref_connections[func_decl_id] {
+            let creation_args = func_decl
+                .borrow()
+                .convert_func2func_creation_args4call(range);
+            func_impl.borrow_mut().get_or_add_func_ref(&creation_args);
+        }
+    }
 }
 
 fn map_open_func_call_connections(walker: &mut ClangAstWalkerInternal) {
@@ -115,7 +271,10 @@ fn handle_ast_element(
         ClangAstElementType::FunctionDecl => {
             handle_function_decl(ast_element, walker, name_prefix, None);
         }
-        ClangAstElementType::CXXMethodDecl => {
+        ClangAstElementType::CXXMethodDecl
+        | ClangAstElementType::CXXConstructorDecl
+        | ClangAstElementType::CXXConversionDecl
+        | ClangAstElementType::CXXDestructorDecl => {
             handle_function_decl(ast_element, walker, name_prefix, None);
         }
         ClangAstElementType::NamespaceDecl => {
@@ -148,20 +307,26 @@ fn handle_function_template_decl(
 ) {
     let template_func_name = &ast_element.attributes;
 
-    'func_decls_loop: for inner_element in &ast_element.inner {
+    for inner_element in &ast_element.inner {
         if inner_element.element_type == ClangAstElementType::FunctionDecl {
-            for inner_inner_element in &inner_element.inner {
-                if inner_inner_element.element_type == ClangAstElementType::TemplateArgument {
-                    handle_function_decl(
-                        inner_element,
-                        walker,
-                        name_prefix,
-                        Some(template_func_name),
-                    );
-
-                    continue 'func_decls_loop;
-                }
-            }
+            let templates = collect_template_specialization(inner_element);
+
+            // The function declaration without template arguments is the uninstantiated pattern.
+            let is_pattern = templates.is_empty();
+            let used_templates = if is_pattern {
+                collect_template_params(ast_element)
+            } else {
+                templates
+            };
+            let outer_in_template_pattern = walker.in_template_pattern;
+            walker.in_template_pattern |= is_pattern;
+            handle_function_decl(
+                inner_element,
+                walker,
+                name_prefix,
+                Some((template_func_name, used_templates)),
+            );
+            walker.in_template_pattern = outer_in_template_pattern;
         }
     }
 }
@@ -205,6 +370,7 @@ fn handle_class_template_decl(
                     .attributes
                     .ends_with(format!("class {} definition", template_class_name).as_str())
                 {
+                    handle_class_template_pattern(ast_element, inner_element, walker, name_prefix);
                     continue;
                 }
                 handle_cxx_record_decl(inner_element, walker, &new_name_prefix);
@@ -221,6 +387,59 @@ fn handle_class_template_decl(
     walker.current_class_stack.pop();
 }
 
+// The members of the uninstantiated class are named after the template parameters,
+// e.g. "TemplateClass<T>::foo 'int (T &)'".
+fn handle_class_template_pattern(
+    ast_element: &ClangAstElement,
+    pattern_element: &ClangAstElement,
+    walker: &mut ClangAstWalkerInternal,
+    name_prefix: &str,
+) {
+    let new_name_prefix = format!(
+        "{}{}<{}>::",
+        name_prefix,
+        ast_element.attributes,
+        collect_template_params(ast_element).join(", ")
+    );
+
+    let outer_in_template_pattern = walker.in_template_pattern;
+    walker.in_template_pattern = true;
+    for inner_element in &pattern_element.inner {
+        match inner_element.element_type {
+            ClangAstElementType::public
+            | ClangAstElementType::protected
+            | ClangAstElementType::private => {}
+            _ => handle_ast_element(inner_element, walker, &new_name_prefix),
+        }
+    }
+    walker.in_template_pattern = outer_in_template_pattern;
+}
+
+fn collect_template_params(ast_element: &ClangAstElement) -> Vec<&str> {
+    let mut template_params: Vec<&str> = Vec::new();
+
+    for inner_element in &ast_element.inner {
+        match inner_element.element_type {
+            ClangAstElementType::TemplateTypeParmDecl
+            | ClangAstElementType::NonTypeTemplateParmDecl
+            | ClangAstElementType::TemplateTemplateParmDecl => {
+                let splitted_attributes: Vec<&str> = inner_element.attributes.split(' ').collect();
+                let param_name = splitted_attributes[splitted_attributes.len() - 1];
+
+                // Unnamed parameters end with their index.
+                if splitted_attributes.len() < 2
+                    || splitted_attributes[splitted_attributes.len() - 2] != "index"
+                {
+                    template_params.push(param_name);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    template_params
+}
+
 fn collect_template_specialization(ast_element: &ClangAstElement) -> Vec<&str> {
     let mut templates: Vec<&str> = Vec::new();
 
@@ -371,7 +590,7 @@ fn handle_function_decl(
     ast_element: &ClangAstElement,
     walker: &mut ClangAstWalkerInternal,
     name_prefix: &str,
-    template_func_name: Option<&str>,
+    template_func_name: Option<(&str, Vec<&str>)>,
 ) {
     if ast_element
         .attributes
@@ -399,9 +618,7 @@ fn handle_function_decl(
         ast_element.create_func_creation_args(Some(walker), name_prefix)
     };
 
-    if template_func_name.is_some() {
-        let used_template_func_name = template_func_name.unwrap().to_string();
-        let templates: Vec<&str> = collect_template_specialization(ast_element);
+    if let Some((used_template_func_name, templates)) = template_func_name {
         func_creation_args.set_new_qualified_name(format!(
             "{}{}<{}>{}",
             name_prefix,
@@ -411,6 +628,19 @@ fn handle_function_decl(
         ));
     }
 
+    // The text dump doesn't contain mangled names, so the qualified name is used instead. This
+    // only separates functions with internal linkage, overloads still differ by the qual type.
+    if func_creation_args.usr.is_none() {
+        let has_internal_linkage = is_in_anonymous_namespace(name_prefix)
+            || (ast_element.element_type == ClangAstElementType::FunctionDecl
+                && has_static_storage(&ast_element.attributes));
+        func_creation_args.usr = Some(create_usr(
+            &func_creation_args.qualified_name,
+            has_internal_linkage,
+            &ast_element.file,
+        ));
+    }
+
     match compound_stmt {
         Some(_compound_stmt) => {
             let func_impl = if walker.current_class_stack.len() > 0 {
@@ -429,6 +659,10 @@ fn handle_function_decl(
             walker
                 .known_func_decls_and_impls
                 .insert(ast_element.element_id, func_impl.clone());
+            add_func_impl_position(walker, &ast_element.range, &func_impl);
+            if walker.in_template_pattern {
+                mark_template_pattern(walker, &func_impl);
+            }
 
             walker.current_func_impl_ast_id = ast_element.element_id;
             for inner_element in &ast_element.inner {
@@ -473,6 +707,61 @@ fn handle_function_decl(
             }
         }
     }
+
+    register_special_member_func(ast_element, walker);
+}
+
+fn mark_template_pattern(walker: &ClangAstWalkerInternal, func_impl: &Rc<RefCell<FuncStructure>>) {
+    if let Some(db_connection) = walker.db.borrow().get_db_connection() {
+        FuncStructure::mark_template_pattern(&db_connection, &func_impl.borrow());
+    }
+}
+
+fn add_func_impl_position(
+    walker: &mut ClangAstWalkerInternal,
+    range: &Range,
+    func_impl: &Rc<RefCell<FuncStructure>>,
+) {
+    let file_name = walker.current_file.borrow().get_name().to_string();
+    let func_impls = walker
+        .func_impls_by_position
+        .entry((file_name, range.clone()))
+        .or_default();
+    if !func_impls.contains(func_impl) {
+        func_impls.push(func_impl.clone());
+    }
+}
+
+fn register_special_member_func(
+    ast_element: &ClangAstElement,
+    walker: &mut ClangAstWalkerInternal,
+) {
+    let func = match walker
+        .known_func_decls_and_impls
+        .get(&ast_element.element_id)
+    {
+        Some(func) => func.clone(),
+        None => return,
+    };
+    let qualified_name = func.borrow().get_qualified_name().to_string();
+
+    match ast_element.element_type {
+        ClangAstElementType::CXXConstructorDecl => {
+            walker.known_constructors.insert(qualified_name, func);
+        }
+        ClangAstElementType::CXXDestructorDecl => {
+            let func_name = match qualified_name.find(" '") {
+                Some(index) => &qualified_name[..index],
+                None => &qualified_name,
+            };
+            if let Some((class_name, _)) = func_name.rsplit_once("::") {
+                walker
+                    .known_destructors
+                    .insert(class_name.to_string(), func);
+            }
+        }
+        _ => {}
+    }
 }
 
 fn walk_func_impl_inner(
@@ -501,12 +790,20 @@ fn walk_func_impl_inner(
                         if let Ok(hex_value) =
                             usize::from_str_radix(&splitted_attributes[index + 1][2..], 16)
                         {
-                            walker_func_impl_inner_decl(
-                                func_impl,
-                                walker,
-                                hex_value,
-                                used_current_range,
-                            );
+                            if ast_element.element_id == walker.current_callee_ast_id {
+                                walker_func_impl_inner_decl(
+                                    func_impl,
+                                    walker,
+                                    hex_value,
+                                    used_current_range,
+                                );
+                            } else {
+                                walker
+                                    .open_func_ref_connections
+                                    .entry(hex_value)
+                                    .or_default()
+                                    .push((ast_element.range.clone(), func_impl.clone()));
+                            }
                         }
                     }
                 }
@@ -524,8 +821,96 @@ fn walk_func_impl_inner(
                 }
             }
         }
-        ClangAstElementType::CallExpr | ClangAstElementType::CXXMemberCallExpr => {
+        ClangAstElementType::CallExpr
+        | ClangAstElementType::CXXMemberCallExpr
+        | ClangAstElementType::CXXOperatorCallExpr => {
             used_current_range = &ast_element.range;
+            if let Some(callee) = ast_element.inner.front() {
+                walker.current_callee_ast_id = callee.element_id;
+            }
+        }
+        ClangAstElementType::CXXConstructExpr | ClangAstElementType::CXXTemporaryObjectExpr => {
+            let quoted_types = get_quoted_types(&ast_element.attributes);
+            if quoted_types.len() >= 2 {
+                let class_name = get_plain_class_name(&quoted_types[0]);
+                let constructor_name = format!(
+                    "{}::{} '{}'",
+                    class_name,
+                    get_unqualified_class_name(class_name),
+                    quoted_types[1]
+                );
+                walker.open_constructor_calls.push((
+                    constructor_name,
+                    ast_element.range.clone(),
+                    func_impl.clone(),
+                ));
+            }
+        }
+        // Temporaries are destroyed at the end of the full expression creating them.
+        ClangAstElementType::CXXBindTemporaryExpr => {
+            let quoted_types = get_quoted_types(&ast_element.attributes);
+            if let Some(type_name) = quoted_types.first() {
+                walker.open_destructor_calls.push((
+                    get_plain_class_name(type_name).to_string(),
+                    ast_element.range.clone(),
+                    func_impl.clone(),
+                ));
+            }
+        }
+        ClangAstElementType::CXXDeleteExpr => {
+            if let Some(class_name) = get_deleted_class_name(ast_element) {
+                walker.open_destructor_calls.push((
+                    class_name,
+                    ast_element.range.clone(),
+                    func_impl.clone(),
+                ));
+            }
+        }
+        ClangAstElementType::VarDecl
+            if walker.implicit_destructor_calls && is_destroyed_at_scope_exit(ast_element) =>
+        {
+            let quoted_types = get_quoted_types(&ast_element.attributes);
+            let scope_end = &walker.current_scope_range.end;
+            walker.open_destructor_calls.push((
+                get_plain_class_name(&quoted_types[0]).to_string(),
+                Range::create(
+                    scope_end.line,
+                    scope_end.column.saturating_sub(1),
+                    scope_end.line,
+                    scope_end.column,
+                ),
+                func_impl.clone(),
+            ));
+        }
+        ClangAstElementType::UnresolvedLookupExpr
+        | ClangAstElementType::UnresolvedMemberExpr
+        | ClangAstElementType::CXXDependentScopeMemberExpr
+            if ast_element.element_id == walker.current_callee_ast_id =>
+        {
+            if let Some(name) = get_unresolved_func_name(ast_element) {
+                func_impl
+                    .borrow_mut()
+                    .get_or_add_unresolved_func_call(&FuncCreationArgs::new(
+                        name,
+                        name,
+                        None,
+                        "",
+                        used_current_range.clone(),
+                    ));
+            }
+        }
+        ClangAstElementType::LambdaExpr => {
+            handle_lambda_expr(ast_element, func_impl, walker);
+            return;
+        }
+        ClangAstElementType::CompoundStmt => {
+            let outer_scope_range =
+                std::mem::replace(&mut walker.current_scope_range, ast_element.range.clone());
+            for inner_element in &ast_element.inner {
+                walk_func_impl_inner(inner_element, func_impl, walker, used_current_range);
+            }
+            walker.current_scope_range = outer_scope_range;
+            return;
         }
         _ => {}
     }
@@ -535,6 +920,206 @@ fn walk_func_impl_inner(
     }
 }
 
+// Lambdas are stored as own function impls next to the enclosing function, named after the
+// enclosing function and their position, e.g. "main::lambda@12:12 'int () const'".
+fn handle_lambda_expr(
+    ast_element: &ClangAstElement,
+    func_impl: &Rc<RefCell<FuncStructure>>,
+    walker: &mut ClangAstWalkerInternal,
+) {
+    let call_operator = match get_lambda_call_operator(ast_element) {
+        Some(call_operator) => call_operator,
+        None => return,
+    };
+    let quoted_types = get_quoted_types(&call_operator.attributes);
+    if quoted_types.is_empty() {
+        return;
+    }
+
+    let lambda_name = format!(
+        "lambda@{}:{}",
+        ast_element.range.start.line, ast_element.range.start.column
+    );
+    let parent_qualified_name = func_impl.borrow().get_qualified_name().to_string();
+    let parent_name = match parent_qualified_name.find(" '") {
+        Some(index) => &parent_qualified_name[..index],
+        None => &parent_qualified_name,
+    };
+    let func_creation_args = FuncCreationArgs::new(
+        &lambda_name,
+        &format!("{}::{} '{}'", parent_name, lambda_name, quoted_types[0]),
+        None,
+        &quoted_types[0],
+        ast_element.range.clone(),
+    );
+
+    let lambda_impl = if !walker.current_class_stack.is_empty() {
+        walker
+            .current_class_stack
+            .last()
+            .unwrap()
+            .borrow_mut()
+            .get_or_add_func_impl(func_creation_args)
+    } else {
+        walker
+            .current_file
+            .borrow_mut()
+            .get_or_add_func_impl(func_creation_args)
+    };
+    // Invocations of the lambda reference its call operator.
+    walker
+        .known_func_decls_and_impls
+        .insert(call_operator.element_id, lambda_impl.clone());
+    if walker.in_template_pattern {
+        mark_template_pattern(walker, &lambda_impl);
+    }
+
+    // The creation of the lambda is recorded as reference from the enclosing function, as the
+    // lambda isn't called at this point.
+    let creation_args = lambda_impl
+        .borrow()
+        .convert_func2func_creation_args4call(&ast_element.range);
+    func_impl.borrow_mut().get_or_add_func_ref(&creation_args);
+
+    let outer_func_impl_ast_id = walker.current_func_impl_ast_id;
+    walker.current_func_impl_ast_id = call_operator.element_id;
+    for inner_element in &call_operator.inner {
+        walk_func_impl_inner(inner_element, &lambda_impl, walker, &ast_element.range);
+    }
+    walker.current_func_impl_ast_id = outer_func_impl_ast_id;
+
+    // Captures are initialized in the enclosing function, the body is a copy of the call
+    // operator body.
+    for inner_element in &ast_element.inner {
+        match inner_element.element_type {
+            ClangAstElementType::CXXRecordDecl | ClangAstElementType::CompoundStmt => {}
+            _ => walk_func_impl_inner(inner_element, func_impl, walker, &ast_element.range),
+        }
+    }
+}
+
+// Lookups store the name quoted, e.g. "'<overloaded function type>' lvalue (ADL) = 'foo'",
+// while member expressions end with it, e.g. "'<dependent type>' lvalue .ba".
+fn get_unresolved_func_name(ast_element: &ClangAstElement) -> Option<&str> {
+    let attributes = &ast_element.attributes;
+
+    if ast_element.element_type == ClangAstElementType::UnresolvedLookupExpr {
+        let name_start = attributes.find("= '")? + "= '".len();
+        let name_length = attributes[name_start..].find('\'')?;
+        return Some(&attributes[name_start..name_start + name_length]);
+    }
+
+    let member = attributes
+        .split(' ')
+        .rev()
+        .find(|attr| attr.starts_with('.') || attr.starts_with("->"))?;
+    let name = member.trim_start_matches("->").trim_start_matches('.');
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+// Generic lambdas have their call operator wrapped in a template declaration.
+fn get_lambda_call_operator(ast_element: &ClangAstElement) -> Option<&ClangAstElement> {
+    let lambda_class = ast_element
+        .inner
+        .iter()
+        .find(|inner_element| inner_element.element_type == ClangAstElementType::CXXRecordDecl)?;
+
+    for inner_element in &lambda_class.inner {
+        let candidates: Vec<&ClangAstElement> = match inner_element.element_type {
+            ClangAstElementType::CXXMethodDecl => vec![inner_element],
+            ClangAstElementType::FunctionTemplateDecl => inner_element.inner.iter().collect(),
+            _ => continue,
+        };
+        for candidate in candidates {
+            if candidate.element_type == ClangAstElementType::CXXMethodDecl
+                && candidate.attributes.contains("operator() '")
+                && get_compound_stmt(candidate).is_some()
+            {
+                return Some(candidate);
+            }
+        }
+    }
+    None
+}
+
+// Returns the quoted types of an attribute string, using the desugared type if available.
+// E.g. "'Foo':'ns::Foo' 'void (int)' elidable" results in ["ns::Foo", "void (int)"].
+fn get_quoted_types(attributes: &str) -> Vec<String> {
+    let mut quoted_types: Vec<String> = Vec::new();
+    let mut previous_separator = "";
+
+    for (i, part) in attributes.split('\'').enumerate() {
+        if i % 2 == 0 {
+            previous_separator = part;
+        } else if previous_separator == ":" && !quoted_types.is_empty() {
+            *quoted_types.last_mut().unwrap() = part.to_string();
+        } else {
+            quoted_types.push(part.to_string());
+        }
+    }
+
+    quoted_types
+}
+
+fn get_plain_class_name(type_name: &str) -> &str {
+    let mut class_name = type_name;
+    for prefix in ["const ", "volatile ", "class ", "struct "] {
+        class_name = class_name.strip_prefix(prefix).unwrap_or(class_name);
+    }
+    class_name
+}
+
+// "ns::Foo<ns::Bar>" results in "Foo" as this is the name of the constructor.
+fn get_unqualified_class_name(class_name: &str) -> &str {
+    let mut template_depth = 0;
+    let mut name_start = 0;
+    let bytes = class_name.as_bytes();
+
+    for (i, c) in bytes.iter().enumerate() {
+        match c {
+            b'<' => template_depth += 1,
+            b'>' => template_depth -= 1,
+            b':' if template_depth == 0 && i + 1 < bytes.len() && bytes[i + 1] == b':' => {
+                name_start = i + 2;
+            }
+            _ => {}
+        }
+    }
+
+    let unqualified_name = &class_name[name_start..];
+    match unqualified_name.find('<') {
+        Some(index) => &unqualified_name[..index],
+        None => unqualified_name,
+    }
+}
+
+// The deleted pointer is the only child, e.g. "DeclRefExpr ... 'ns::Foo *' lvalue Var ...".
+fn get_deleted_class_name(ast_element: &ClangAstElement) -> Option<String> {
+    let quoted_types = get_quoted_types(&ast_element.inner.front()?.attributes);
+    let pointee_type = quoted_types.first()?.strip_suffix('*')?.trim_end();
+    Some(get_plain_class_name(pointee_type).to_string())
+}
+
+fn is_destroyed_at_scope_exit(ast_element: &ClangAstElement) -> bool {
+    let quoted_types = get_quoted_types(&ast_element.attributes);
+    if quoted_types.is_empty()
+        || quoted_types[0].ends_with('&')
+        || quoted_types[0].ends_with('*')
+        || quoted_types[0].ends_with(']')
+    {
+        return false;
+    }
+
+    // Static and extern variables aren't destroyed at the end of the scope.
+    let type_end = ast_element.attributes.rfind('\'').unwrap();
+    let storage_attributes = &ast_element.attributes[type_end..];
+    !storage_attributes.contains(" static") && !storage_attributes.contains(" extern")
+}
+
 fn walker_func_impl_inner_decl(
     func_impl: &Rc<RefCell<FuncStructure>>,
     walker: &mut ClangAstWalkerInternal,
@@ -577,6 +1162,43 @@ fn get_compound_stmt(ast_element: &ClangAstElement) -> Option<&ClangAstElement>
     None
 }
 
+// Functions with internal linkage get the defining file as prefix, so that equally named
+// functions of different files stay distinct while statics of a header are shared.
+fn create_usr(name: &str, has_internal_linkage: bool, file_path: &str) -> String {
+    if has_internal_linkage {
+        format!("{}:{}", file_path, name)
+    } else {
+        name.to_string()
+    }
+}
+
+// The mangled name is the same in all translation units for functions with external linkage.
+fn has_mangled_internal_linkage(mangled_name: &str, is_static: bool) -> bool {
+    if mangled_name.starts_with("_Z") {
+        mangled_name.starts_with("_ZL")
+            || mangled_name.contains("_GLOBAL__N_")
+            || mangled_name.contains("$_")
+    } else {
+        // C functions aren't mangled, so only the storage class tells about the linkage.
+        is_static
+    }
+}
+
+// The storage class follows the type, e.g. "add 'int (int, int)' static".
+fn has_static_storage(attributes: &str) -> bool {
+    match attributes.rfind('\'') {
+        Some(type_end) => attributes[type_end..]
+            .split(' ')
+            .any(|attribute| attribute == "static"),
+        None => false,
+    }
+}
+
+// Anonymous namespaces add an empty name to the prefix, e.g. "::" or "ns::::".
+fn is_in_anonymous_namespace(name_prefix: &str) -> bool {
+    name_prefix.starts_with("::") || name_prefix.contains("::::")
+}
+
 impl ClangAstElement {
     fn create_func_creation_args(
         &self,
@@ -586,12 +1208,13 @@ impl ClangAstElement {
         let splitted_attributes: Vec<&str> = self.attributes.split(" ").collect();
         let start_index = get_in_function_qual_type_start_index(&splitted_attributes);
         let end_index = get_in_function_qual_type_end_index(&splitted_attributes);
+        let name_start_index = get_func_name_start_index(&splitted_attributes, start_index);
         let binding = splitted_attributes[start_index..end_index + 1].join(" ");
         let qualified_type = binding.as_str();
         let qualified_name = &format!(
             "{}{}",
             name_prefix,
-            splitted_attributes[start_index - 1..end_index + 1]
+            splitted_attributes[name_start_index..end_index + 1]
                 .join(" ")
                 .as_str()
         );
@@ -604,15 +1227,26 @@ impl ClangAstElement {
             base_qualified_name = Some(qualified_name.clone())
         }
 
-        FuncCreationArgs::new(
-            splitted_attributes[start_index - 1],
+        let mut func_creation_args = FuncCreationArgs::new(
+            splitted_attributes[name_start_index..start_index]
+                .join(" ")
+                .as_str(),
             qualified_name,
             base_qualified_name,
             qualified_type[1..qualified_type.len() - 1]
                 .to_string()
                 .as_str(),
             self.range.clone(),
-        )
+        );
+        if let Some(mangled_name) = &self.mangled_name {
+            func_creation_args.usr = Some(create_usr(
+                mangled_name,
+                has_mangled_internal_linkage(mangled_name, has_static_storage(&self.attributes)),
+                &self.file,
+            ));
+        }
+
+        func_creation_args
     }
 
     fn get_base_qualified_name_from_override(
@@ -666,6 +1300,14 @@ fn get_in_function_qual_type_start_index(current_vec: &Vec<&str>) -> usize {
     panic!("No name start found in func name: {:?}", current_vec);
 }
 
+// Operator names like "operator int" or "operator new" contain spaces.
+fn get_func_name_start_index(current_vec: &[&str], qual_type_start_index: usize) -> usize {
+    current_vec[..qual_type_start_index]
+        .iter()
+        .position(|elem| elem.starts_with("operator"))
+        .unwrap_or(qual_type_start_index - 1)
+}
+
 fn get_in_function_qual_type_end_index(current_vec: &Vec<&str>) -> usize {
     for (i, elem) in current_vec.iter().enumerate().rev() {
         if elem.ends_with("'") {
@@ -677,7 +1319,14 @@ fn get_in_function_qual_type_end_index(current_vec: &Vec<&str>) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::location::range::Range;
+    use std::sync::Arc;
+
+    use crate::{
+        ast_reader::clang_ast_parser::{ClangAstParser, ClangAstParserImpl},
+        call_graph::data_structure::func_structure::FuncMentionType,
+        location::range::Range,
+        process::dummy_process::DummyProcess,
+    };
 
     use super::*;
 
@@ -714,9 +1363,11 @@ mod tests {
             element_id: 0x123011160,
             parent_element_id: 0,
             prev_element_id: 0,
-            file: Rc::new("test.cpp".to_string()),
+            file: Arc::new("test.cpp".to_string()),
             range: Range::create(1, 2, 3, 4),
             inner: VecDeque::new(),
+            include_edges: Vec::new(),
+            mangled_name: None,
             attributes: "add 'int (int, int)'".to_string(),
         };
         let converted_args = input.create_func_creation_args(None, "");
@@ -727,6 +1378,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(1, 2, 3, 4),
+            usr: None,
         };
 
         assert_eq!(converted_args, expected_args);
@@ -739,9 +1391,11 @@ mod tests {
             element_id: 0x123011160,
             parent_element_id: 0,
             prev_element_id: 0,
-            file: Rc::new("test.cpp".to_string()),
+            file: Arc::new("test.cpp".to_string()),
             range: Range::create(1, 2, 3, 4),
             inner: VecDeque::new(),
+            include_edges: Vec::new(),
+            mangled_name: None,
             attributes: "used add 'int (int, int)'".to_string(),
         };
         let converted_args = input.create_func_creation_args(None, "");
@@ -752,6 +1406,36 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(1, 2, 3, 4),
+            usr: None,
+        };
+
+        assert_eq!(converted_args, expected_args);
+    }
+
+    #[test]
+    fn create_func_creation_args_test_with_conversion_operator() {
+        let input = ClangAstElement {
+            element_type: ClangAstElementType::CXXConversionDecl,
+            element_id: 0x123011160,
+            parent_element_id: 0,
+            prev_element_id: 0,
+            file: Arc::new("test.cpp".to_string()),
+            range: Range::create(1, 2, 3, 4),
+            inner: VecDeque::new(),
+            include_edges: Vec::new(),
+            mangled_name: None,
+            attributes: "used operator const char * 'const char *() const' implicit-inline"
+                .to_string(),
+        };
+        let converted_args = input.create_func_creation_args(None, "TestClass::");
+
+        let expected_args = FuncCreationArgs {
+            name: "operator const char *".to_string(),
+            qualified_name: "TestClass::operator const char * 'const char *() const'".to_string(),
+            base_qualified_name: None,
+            qualified_type: "const char *() const".to_string(),
+            range: Range::create(1, 2, 3, 4),
+            usr: None,
         };
 
         assert_eq!(converted_args, expected_args);
@@ -764,9 +1448,11 @@ mod tests {
             element_id: 0x123011160,
             parent_element_id: 0,
             prev_element_id: 0,
-            file: Rc::new("test.cpp".to_string()),
+            file: Arc::new("test.cpp".to_string()),
             range: Range::create(1, 2, 3, 4),
             inner: VecDeque::new(),
+            include_edges: Vec::new(),
+            mangled_name: None,
             attributes: "add 'int (int, int)' extern".to_string(),
         };
         let converted_args = input.create_func_creation_args(None, "");
@@ -777,8 +1463,360 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(1, 2, 3, 4),
+            usr: None,
         };
 
         assert_eq!(converted_args, expected_args);
     }
+
+    #[test]
+    fn create_func_creation_args_test_with_mangled_name() {
+        let mut input = create_test_element(
+            ClangAstElementType::FunctionDecl,
+            0x123011160,
+            "add 'int (int, int)'",
+            vec![],
+        );
+        input.mangled_name = Some("_Z3addii".to_string());
+        let converted_args = input.create_func_creation_args(None, "");
+
+        assert_eq!(converted_args.qualified_name, "add 'int (int, int)'");
+        assert_eq!(converted_args.usr, Some("_Z3addii".to_string()));
+    }
+
+    #[test]
+    fn create_usr_test() {
+        assert_eq!(create_usr("_Z3addii", false, "main.cpp"), "_Z3addii");
+        assert_eq!(
+            create_usr("_ZL3addii", true, "main.cpp"),
+            "main.cpp:_ZL3addii"
+        );
+
+        assert!(!has_mangled_internal_linkage("_Z3addii", false));
+        assert!(!has_mangled_internal_linkage("add", false));
+        assert!(has_mangled_internal_linkage("add", true));
+        assert!(has_mangled_internal_linkage("_ZL3addii", false));
+        assert!(has_mangled_internal_linkage(
+            "_ZN12_GLOBAL__N_13addEii",
+            false
+        ));
+        // Static member functions have external linkage.
+        assert!(!has_mangled_internal_linkage("_ZN9TestClass3addEii", true));
+
+        assert!(has_static_storage("used add 'int (int, int)' static"));
+        assert!(!has_static_storage("used add 'int (int, int)'"));
+        assert!(is_in_anonymous_namespace("::"));
+        assert!(is_in_anonymous_namespace("ns::::"));
+        assert!(!is_in_anonymous_namespace("ns::"));
+    }
+
+    #[test]
+    fn get_quoted_types_test() {
+        assert_eq!(
+            get_quoted_types("'Foo':'ns::Foo' 'void (int)' elidable"),
+            vec!["ns::Foo", "void (int)"]
+        );
+        assert_eq!(
+            get_quoted_types("test 'TestClass' callinit"),
+            vec!["TestClass"]
+        );
+        assert_eq!(get_quoted_types("callinit"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn get_unqualified_class_name_test() {
+        assert_eq!(get_unqualified_class_name("Foo"), "Foo");
+        assert_eq!(get_unqualified_class_name("ns::Foo"), "Foo");
+        assert_eq!(get_unqualified_class_name("ns::Foo<ns::Bar, int>"), "Foo");
+        assert_eq!(get_plain_class_name("const ns::Foo"), "ns::Foo");
+    }
+
+    fn create_test_element(
+        element_type: ClangAstElementType,
+        element_id: usize,
+        attributes: &str,
+        inner: Vec<ClangAstElement>,
+    ) -> ClangAstElement {
+        ClangAstElement {
+            element_type,
+            element_id,
+            parent_element_id: 0,
+            prev_element_id: 0,
+            file: Arc::new("test.cpp".to_string()),
+            range: Range::create(1, 2, 3, 4),
+            inner: VecDeque::from(inner),
+            include_edges: Vec::new(),
+            mangled_name: None,
+            attributes: attributes.to_string(),
+        }
+    }
+
+    #[test]
+    fn get_lambda_call_operator_test_with_generic_lambda() {
+        let call_operator = create_test_element(
+            ClangAstElementType::CXXMethodDecl,
+            0x30,
+            "used constexpr operator() 'auto (int) const' inline",
+            vec![create_test_element(
+                ClangAstElementType::CompoundStmt,
+                0x40,
+                "",
+                vec![],
+            )],
+        );
+        let lambda_class = create_test_element(
+            ClangAstElementType::CXXRecordDecl,
+            0x10,
+            "implicit class definition",
+            vec![create_test_element(
+                ClangAstElementType::FunctionTemplateDecl,
+                0x20,
+                "operator()",
+                vec![
+                    create_test_element(
+                        ClangAstElementType::CXXMethodDecl,
+                        0x21,
+                        "constexpr operator() 'auto (auto) const' inline",
+                        vec![],
+                    ),
+                    call_operator,
+                ],
+            )],
+        );
+        let lambda = create_test_element(
+            ClangAstElementType::LambdaExpr,
+            0x1,
+            "'(lambda at test.cpp:1:2)'",
+            vec![lambda_class],
+        );
+
+        assert_eq!(get_lambda_call_operator(&lambda).unwrap().element_id, 0x30);
+        assert!(get_lambda_call_operator(&lambda.inner[0]).is_none());
+    }
+
+    #[test]
+    fn get_unresolved_func_name_test() {
+        let lookup = create_test_element(
+            ClangAstElementType::UnresolvedLookupExpr,
+            0x1,
+            "'<overloaded function type>' lvalue (ADL) = 'draw' 0x12a0eeec8",
+            vec![],
+        );
+        let dependent_member = create_test_element(
+            ClangAstElementType::CXXDependentScopeMemberExpr,
+            0x2,
+            "'<dependent type>' lvalue .area",
+            vec![],
+        );
+        let unresolved_member = create_test_element(
+            ClangAstElementType::UnresolvedMemberExpr,
+            0x3,
+            "'<bound member function type>' lvalue ->area",
+            vec![],
+        );
+
+        assert_eq!(get_unresolved_func_name(&lookup), Some("draw"));
+        assert_eq!(get_unresolved_func_name(&dependent_member), Some("area"));
+        assert_eq!(get_unresolved_func_name(&unresolved_member), Some("area"));
+    }
+
+    #[test]
+    fn collect_template_params_test() {
+        let template_decl = create_test_element(
+            ClangAstElementType::ClassTemplateDecl,
+            0x1,
+            "TemplateClass",
+            vec![
+                create_test_element(
+                    ClangAstElementType::TemplateTypeParmDecl,
+                    0x2,
+                    "referenced class depth 0 index 0 T",
+                    vec![],
+                ),
+                create_test_element(
+                    ClangAstElementType::NonTypeTemplateParmDecl,
+                    0x3,
+                    "'int' depth 0 index 1 N",
+                    vec![],
+                ),
+                create_test_element(
+                    ClangAstElementType::TemplateTypeParmDecl,
+                    0x4,
+                    "class depth 0 index 2",
+                    vec![],
+                ),
+            ],
+        );
+
+        assert_eq!(collect_template_params(&template_decl), vec!["T", "N"]);
+    }
+
+    #[test]
+    fn walk_ast_2_func_call_db_test_with_include_edges() {
+        let mut func_decl = create_test_element(
+            ClangAstElementType::FunctionDecl,
+            0x1,
+            "foo 'void ()'",
+            vec![],
+        );
+        func_decl.file = Arc::new("inner.h".to_string());
+        func_decl.include_edges = vec![
+            (
+                Arc::new("inner.h".to_string()),
+                Arc::new("outer.h".to_string()),
+            ),
+            (
+                Arc::new("outer.h".to_string()),
+                Arc::new("test.cpp".to_string()),
+            ),
+        ];
+
+        let db = Rc::new(RefCell::new(DatabaseSqlite::create_in_memory_database()));
+        walk_ast_2_func_call_db(
+            "test.cpp",
+            VecDeque::from(vec![func_decl]),
+            db.clone(),
+            &vec![],
+            false,
+            false,
+        );
+
+        let db = db.borrow();
+        assert_eq!(
+            db.get_file_includers("inner.h"),
+            vec!["outer.h", "test.cpp"]
+        );
+        assert_eq!(
+            db.get_file_includees("test.cpp"),
+            vec!["inner.h", "outer.h"]
+        );
+        assert_eq!(db.get_file_includees("outer.h"), vec!["inner.h"]);
+    }
+
+    #[test]
+    fn walk_ast_2_func_call_db_test_commits_transaction() {
+        let func_decl = create_test_element(
+            ClangAstElementType::FunctionDecl,
+            0x1,
+            "foo 'void ()'",
+            vec![],
+        );
+
+        let db = Rc::new(RefCell::new(DatabaseSqlite::create_in_memory_database()));
+        walk_ast_2_func_call_db(
+            "test.cpp",
+            VecDeque::from(vec![func_decl]),
+            db.clone(),
+            &vec![],
+            false,
+            false,
+        );
+
+        let db = db.borrow();
+        assert!(db.get_db_connection().unwrap().db.is_autocommit());
+        assert_eq!(
+            db.get_cpp_file("test.cpp")
+                .unwrap()
+                .borrow_mut()
+                .get_func_decls()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn walk_ast_2_func_call_db_test_usr_from_text_dump() {
+        let db = Rc::new(RefCell::new(DatabaseSqlite::create_in_memory_database()));
+        for file in ["a.cpp", "b.cpp"] {
+            let text_dump = format!(
+                r#"TranslationUnitDecl 0x1 <<invalid sloc>> <invalid sloc>
+|-FunctionDecl 0x10 <{}:1:1, line:4:1> line:1:12 used helper 'int ()' static
+| `-CompoundStmt 0x11 <col:21, line:4:1>
+`-FunctionDecl 0x20 <line:6:1, line:9:1> line:6:5 run 'int ()'
+  `-CompoundStmt 0x21 <col:11, line:9:1>
+    `-ReturnStmt 0x22 <line:8:5, col:19>
+      `-CallExpr 0x23 <col:12, col:19> 'int'
+        `-ImplicitCastExpr 0x24 <col:12> 'int (*)()' <FunctionToPointerDecay>
+          `-DeclRefExpr 0x25 <col:12> 'int ()' lvalue Function 0x10 'helper' 'int ()'"#,
+                file
+            );
+            let mut process = DummyProcess::new();
+            for line in text_dump.lines() {
+                process.add_line(line.to_string());
+            }
+            let mut parser = ClangAstParserImpl::new(Box::new(process));
+            walk_ast_2_func_call_db(
+                file,
+                parser.parse_ast().unwrap(),
+                db.clone(),
+                &vec![],
+                false,
+                false,
+            );
+        }
+        let db = db.borrow();
+        db.link_func_calls();
+
+        for table_name in ["func_impls", "func_calls"] {
+            let missing_usrs: u64 = db
+                .get_db_connection()
+                .unwrap()
+                .db
+                .query_row(
+                    &format!("SELECT COUNT(*) FROM {} WHERE usr IS NULL", table_name),
+                    [],
+                    |row| row.get(0),
+                )
+                .unwrap();
+            assert_eq!(missing_usrs, 0);
+        }
+
+        // The static helpers share the qualified name, but each call stays in its own file.
+        for file in ["a.cpp", "b.cpp"] {
+            let funcs = db.get_funcs_from_name_or_position(&format!("{}:8:12", file));
+            let func_call = funcs.last().unwrap().borrow();
+            assert_eq!(func_call.get_func_type(), Some(FuncMentionType::FuncCall));
+            assert_eq!(
+                func_call.get_usr(),
+                Some(format!("{}:helper 'int ()'", file).as_str())
+            );
+
+            let callees = db.get_func_impls_or_one_decl(&func_call);
+            assert_eq!(callees.len(), 1);
+            assert_eq!(callees[0].file, file);
+        }
+    }
+
+    #[test]
+    fn walk_ast_2_func_call_db_test_usr_of_header_static() {
+        let db = Rc::new(RefCell::new(DatabaseSqlite::create_in_memory_database()));
+        let text_dump = r#"TranslationUnitDecl 0x1 <<invalid sloc>> <invalid sloc>
+|-FunctionDecl 0x10 <./helper.h:1:1, line:4:1> line:1:12 used helper 'int ()' static
+| `-CompoundStmt 0x11 <col:21, line:4:1>
+`-FunctionDecl 0x20 <a.cpp:6:1, line:9:1> line:6:5 run 'int ()'
+  `-CompoundStmt 0x21 <col:11, line:9:1>
+    `-ReturnStmt 0x22 <line:8:5, col:19>
+      `-CallExpr 0x23 <col:12, col:19> 'int'
+        `-ImplicitCastExpr 0x24 <col:12> 'int (*)()' <FunctionToPointerDecay>
+          `-DeclRefExpr 0x25 <col:12> 'int ()' lvalue Function 0x10 'helper' 'int ()'"#;
+        let mut process = DummyProcess::new();
+        for line in text_dump.lines() {
+            process.add_line(line.to_string());
+        }
+        let mut parser = ClangAstParserImpl::new(Box::new(process));
+        walk_ast_2_func_call_db(
+            "a.cpp",
+            parser.parse_ast().unwrap(),
+            db.clone(),
+            &vec![],
+            false,
+            false,
+        );
+        let db = db.borrow();
+
+        // A static of a header is prefixed with the header, so every includer shares it.
+        let funcs = db.get_funcs_from_name_or_position("a.cpp:8:12");
+        let func_call = funcs.last().unwrap().borrow();
+        assert_eq!(func_call.get_usr(), Some("./helper.h:helper 'int ()'"));
+    }
 }
@@ -164,7 +164,7 @@ impl CppClass {
     ) -> Self {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             INSERT INTO cpp_classes (class_name, cpp_file_id, hpp_file_id, cpp_class_id)
             VALUES (?, ?, ?, ?)",
@@ -185,7 +185,7 @@ impl CppClass {
     ) -> Vec<Rc<RefCell<CppClass>>> {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT id, class_name
             FROM cpp_classes
@@ -213,7 +213,7 @@ impl CppClass {
             .as_ref()
             .unwrap()
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT parent_class_id
             FROM cpp_classes_2_cpp_classes
@@ -244,7 +244,7 @@ impl CppClass {
     ) -> Option<Rc<RefCell<Self>>> {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT class_name
             FROM cpp_classes
@@ -315,7 +315,7 @@ impl CppClass {
             .as_ref()
             .unwrap()
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT parent_class_id
             FROM cpp_classes_2_cpp_classes
@@ -357,7 +357,7 @@ impl CppClass {
             .as_ref()
             .unwrap()
             .db
-            .prepare(
+            .prepare_cached(
                 "
             INSERT INTO cpp_classes_2_cpp_classes (parent_class_id, child_class_id)
             VALUES (?, ?)",
@@ -381,7 +381,10 @@ CREATE TABLE cpp_classes (
     FOREIGN KEY (cpp_file_id) REFERENCES cpp_files(id) ON DELETE CASCADE,
     FOREIGN KEY (hpp_file_id) REFERENCES hpp_files(id) ON DELETE CASCADE,
     FOREIGN KEY (cpp_class_id) REFERENCES cpp_classes(id) ON DELETE CASCADE
-)
+);
+CREATE INDEX IF NOT EXISTS cpp_classes_cpp_file_id ON cpp_classes (cpp_file_id);
+CREATE INDEX IF NOT EXISTS cpp_classes_hpp_file_id ON cpp_classes (hpp_file_id);
+CREATE INDEX IF NOT EXISTS cpp_classes_cpp_class_id ON cpp_classes (cpp_class_id);
 ";
 
 pub const CPP_CLASS_2_CLASS_SQL_CREATE_TABLE: &str = "
@@ -392,12 +395,15 @@ CREATE TABLE cpp_classes_2_cpp_classes (
     PRIMARY KEY (parent_class_id, child_class_id),
     FOREIGN KEY (parent_class_id) REFERENCES cpp_classes(id) ON DELETE CASCADE,
     FOREIGN KEY (child_class_id) REFERENCES cpp_classes(id) ON DELETE CASCADE
-)
+);
+CREATE INDEX IF NOT EXISTS cpp_classes_2_cpp_classes_child_class_id ON cpp_classes_2_cpp_classes (child_class_id);
 ";
 
-pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) {
-    let _ = db_connection.db.execute_batch(CPP_CLASS_SQL_CREATE_TABLE);
-    let _ = db_connection
+pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection.db.execute_batch(CPP_CLASS_SQL_CREATE_TABLE)?;
+    db_connection
         .db
-        .execute_batch(CPP_CLASS_2_CLASS_SQL_CREATE_TABLE);
+        .execute_batch(CPP_CLASS_2_CLASS_SQL_CREATE_TABLE)?;
+
+    Ok(())
 }
@@ -23,7 +23,7 @@ impl FileStructure {
 
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             INSERT INTO cpp_files (file_name, last_analyzed)
             VALUES (?, ?)",
@@ -46,7 +46,7 @@ impl FileStructure {
     ) -> Option<Rc<RefCell<FileStructure>>> {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT id, last_analyzed
             FROM cpp_files
@@ -76,7 +76,7 @@ impl FileStructure {
     ) -> Vec<Rc<RefCell<FileStructure>>> {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT id, file_name, last_analyzed
             FROM cpp_files",
@@ -108,7 +108,7 @@ impl FileStructure {
             .as_ref()
             .unwrap()
             .db
-            .prepare(
+            .prepare_cached(
                 "
             UPDATE cpp_files
             SET last_analyzed = ?
@@ -136,6 +136,8 @@ CREATE TABLE cpp_files (
 )
 ";
 
-pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) {
-    let _ = db_connection.db.execute_batch(CPP_FILE_SQL_CREATE_TABLE);
+pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection.db.execute_batch(CPP_FILE_SQL_CREATE_TABLE)?;
+
+    Ok(())
 }
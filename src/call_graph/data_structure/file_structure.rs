@@ -1,5 +1,6 @@
 use std::{cell::RefCell, rc::Rc};
 
+use rusqlite::params;
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -205,7 +206,19 @@ impl FileStructure {
     }
 
     pub fn get_includes(&self) -> Vec<Rc<RefCell<FileStructure>>> {
-        todo!()
+        let db_connection = match &self.db_connection {
+            Some(db_connection) => db_connection,
+            None => return Vec::new(),
+        };
+
+        self.read_included_header_files()
+            .iter()
+            .filter_map(|name| FileStructure::get_hpp_file(db_connection, name))
+            .collect()
+    }
+
+    pub fn is_header(&self) -> bool {
+        self.file_is_header
     }
 
     pub fn get_last_analyzed(&self) -> usize {
@@ -229,4 +242,54 @@ impl FileStructure {
                 .as_millis() as usize,
         );
     }
+
+    pub fn get_file_name_from_parent_id(
+        db_connection: &DatabaseSqliteInternal,
+        parent_id: (Option<u64>, Option<u64>, Option<u64>),
+    ) -> Option<String> {
+        let (cpp_file_id, hpp_file_id) = match parent_id {
+            (Some(cpp_file_id), _, _) => (Some(cpp_file_id), None),
+            (_, Some(hpp_file_id), _) => (None, Some(hpp_file_id)),
+            (_, _, Some(cpp_class_id)) => {
+                // Classes can be nested, so we have to climb up to the outermost class.
+                let mut stmt = db_connection
+                    .db
+                    .prepare_cached(
+                        "
+            WITH RECURSIVE class_chain(id, cpp_file_id, hpp_file_id, cpp_class_id) AS (
+                SELECT id, cpp_file_id, hpp_file_id, cpp_class_id
+                FROM cpp_classes
+                WHERE id = ?
+                UNION ALL
+                SELECT c.id, c.cpp_file_id, c.hpp_file_id, c.cpp_class_id
+                FROM cpp_classes AS c
+                JOIN class_chain AS cc ON c.id = cc.cpp_class_id
+            )
+            SELECT cpp_file_id, hpp_file_id
+            FROM class_chain
+            WHERE cpp_class_id IS NULL",
+                    )
+                    .unwrap();
+                let mut rows = stmt.query(params![cpp_class_id]).unwrap();
+                match rows.next().unwrap() {
+                    Some(row) => (row.get(0).unwrap(), row.get(1).unwrap()),
+                    None => return None,
+                }
+            }
+            (None, None, None) => return None,
+        };
+
+        let (table, id) = match (cpp_file_id, hpp_file_id) {
+            (Some(cpp_file_id), _) => ("cpp_files", cpp_file_id),
+            (_, Some(hpp_file_id)) => ("hpp_files", hpp_file_id),
+            (None, None) => return None,
+        };
+
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(&format!("SELECT file_name FROM {} WHERE id = ?", table))
+            .unwrap();
+        let mut rows = stmt.query(params![id]).unwrap();
+        rows.next().unwrap().map(|row| row.get(0).unwrap())
+    }
 }
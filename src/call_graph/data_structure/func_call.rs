@@ -20,18 +20,19 @@ impl FuncStructure {
     ) -> Self {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
-            INSERT INTO func_calls (name, qualified_name, qual_type,
+            INSERT INTO func_calls (name, qualified_name, qual_type, usr,
                 range_start_line, range_start_column, range_end_line, range_end_column,
                 func_impl_id, virtual_func_impl_id)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             )
             .unwrap();
         let result = stmt.insert(params![
             args.name.clone(),
             args.qualified_name.clone(),
             args.qualified_type.clone(),
+            args.usr.clone(),
             args.range.start.line.to_string(),
             args.range.start.column.to_string(),
             args.range.end.line.to_string(),
@@ -47,6 +48,7 @@ impl FuncStructure {
             args.qualified_name.clone(),
             None,
             args.qualified_type.clone(),
+            args.usr.clone(),
             args.range.clone(),
             Some(FuncMentionType::FuncCall),
         )
@@ -58,10 +60,10 @@ impl FuncStructure {
     ) -> Vec<Rc<RefCell<FuncStructure>>> {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT id, name, qualified_name, qual_type,
-                range_start_line, range_start_column, range_end_line, range_end_column
+                range_start_line, range_start_column, range_end_line, range_end_column, usr
             FROM func_calls
             WHERE func_impl_id = ?
                 OR virtual_func_impl_id = ?",
@@ -76,6 +78,7 @@ impl FuncStructure {
                     row.get(2).unwrap(),
                     None,
                     row.get(3).unwrap(),
+                    row.get(8).unwrap(),
                     Range::new(
                         Position::new(row.get(4).unwrap(), row.get(5).unwrap()),
                         Position::new(row.get(6).unwrap(), row.get(7).unwrap()),
@@ -99,20 +102,23 @@ impl FuncStructure {
     ) -> Vec<Rc<RefCell<FuncStructure>>> {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT id, name, qualified_name, qual_type,
-                range_start_line, range_start_column, range_end_line, range_end_column
+                range_start_line, range_start_column, range_end_line, range_end_column, usr
             FROM func_calls
-            WHERE name = ? AND qualified_name = ? AND qual_type = ?",
+            WHERE usr = ?4
+                OR ((usr IS NULL OR ?4 IS NULL)
+                    AND name = ?1 AND qualified_name = ?2 AND qual_type = ?3)",
             )
             .unwrap();
         let rows = stmt
             .query_map(
-                [
+                params![
                     func.get_name(),
                     func.get_qualified_name(),
                     func.get_qual_type(),
+                    func.get_usr()
                 ],
                 |row| {
                     Ok(FuncStructure::new(
@@ -122,6 +128,7 @@ impl FuncStructure {
                         row.get(2).unwrap(),
                         None,
                         row.get(3).unwrap(),
+                        row.get(8).unwrap(),
                         Range::new(
                             Position::new(row.get(4).unwrap(), row.get(5).unwrap()),
                             Position::new(row.get(6).unwrap(), row.get(7).unwrap()),
@@ -139,6 +146,59 @@ impl FuncStructure {
 
         func_calls
     }
+
+    // Links every function call to all matching implementations. Calls without any
+    // implementation are linked to one matching declaration instead. The links are rebuilt
+    // completely, so this has to be called after all translation units are walked.
+    pub fn link_func_calls_2_funcs(db_connection: &DatabaseSqliteInternal) {
+        db_connection
+            .db
+            .execute_batch(
+                "
+            DELETE FROM func_calls_2_funcs;
+            INSERT INTO func_calls_2_funcs (func_call_id, func_impl_id)
+                SELECT func_calls.id, func_impls.id
+                FROM func_calls
+                JOIN func_impls
+                    ON func_impls.usr = func_calls.usr
+                    OR ((func_impls.usr IS NULL OR func_calls.usr IS NULL)
+                        AND func_impls.name = func_calls.name
+                        AND func_impls.qualified_name = func_calls.qualified_name
+                        AND func_impls.qual_type = func_calls.qual_type);
+            INSERT INTO func_calls_2_funcs (func_call_id, virtual_func_impl_id)
+                SELECT func_calls.id, virtual_func_impls.id
+                FROM func_calls
+                JOIN virtual_func_impls
+                    ON virtual_func_impls.usr = func_calls.usr
+                    OR ((virtual_func_impls.usr IS NULL OR func_calls.usr IS NULL)
+                        AND virtual_func_impls.name = func_calls.name
+                        AND virtual_func_impls.qualified_name = func_calls.qualified_name
+                        AND virtual_func_impls.qual_type = func_calls.qual_type);
+            INSERT INTO func_calls_2_funcs (func_call_id, func_decl_id)
+                SELECT func_calls.id, MIN(func_decls.id)
+                FROM func_calls
+                JOIN func_decls
+                    ON func_decls.usr = func_calls.usr
+                    OR ((func_decls.usr IS NULL OR func_calls.usr IS NULL)
+                        AND func_decls.name = func_calls.name
+                        AND func_decls.qualified_name = func_calls.qualified_name
+                        AND func_decls.qual_type = func_calls.qual_type)
+                WHERE func_calls.id NOT IN (SELECT func_call_id FROM func_calls_2_funcs)
+                GROUP BY func_calls.id;
+            INSERT INTO func_calls_2_funcs (func_call_id, virtual_func_decl_id)
+                SELECT func_calls.id, MIN(virtual_func_decls.id)
+                FROM func_calls
+                JOIN virtual_func_decls
+                    ON virtual_func_decls.usr = func_calls.usr
+                    OR ((virtual_func_decls.usr IS NULL OR func_calls.usr IS NULL)
+                        AND virtual_func_decls.name = func_calls.name
+                        AND virtual_func_decls.qualified_name = func_calls.qualified_name
+                        AND virtual_func_decls.qual_type = func_calls.qual_type)
+                WHERE func_calls.id NOT IN (SELECT func_call_id FROM func_calls_2_funcs)
+                GROUP BY func_calls.id;",
+            )
+            .unwrap();
+    }
 }
 
 pub const FUNC_CALL_SQL_CREATE_TABLE: &str = "
@@ -147,6 +207,7 @@ CREATE TABLE func_calls (
     name                 TEXT NOT NULL,
     qualified_name       TEXT NOT NULL,
     qual_type            TEXT NOT NULL,
+    usr                  TEXT NULL,
     range_start_line     INTEGER,
     range_start_column   INTEGER,
     range_end_line       INTEGER,
@@ -157,9 +218,40 @@ CREATE TABLE func_calls (
 
     FOREIGN KEY (func_impl_id) REFERENCES func_impls(id) ON DELETE CASCADE,
     FOREIGN KEY (virtual_func_impl_id) REFERENCES virtual_func_impls(id) ON DELETE CASCADE
-)
+);
+CREATE INDEX IF NOT EXISTS func_calls_qualified_name ON func_calls (qualified_name);
+CREATE INDEX IF NOT EXISTS func_calls_usr ON func_calls (usr);
+CREATE INDEX IF NOT EXISTS func_calls_func_impl_id ON func_calls (func_impl_id);
+CREATE INDEX IF NOT EXISTS func_calls_virtual_func_impl_id ON func_calls (virtual_func_impl_id);
 ";
 
-pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) {
-    let _ = db_connection.db.execute_batch(FUNC_CALL_SQL_CREATE_TABLE);
+pub const FUNC_CALL_2_FUNC_SQL_CREATE_TABLE: &str = "
+CREATE TABLE func_calls_2_funcs (
+    func_call_id         INTEGER,
+
+    func_decl_id         INTEGER NULL,
+    func_impl_id         INTEGER NULL,
+    virtual_func_decl_id INTEGER NULL,
+    virtual_func_impl_id INTEGER NULL,
+
+    FOREIGN KEY (func_call_id) REFERENCES func_calls(id) ON DELETE CASCADE,
+    FOREIGN KEY (func_decl_id) REFERENCES func_decls(id) ON DELETE CASCADE,
+    FOREIGN KEY (func_impl_id) REFERENCES func_impls(id) ON DELETE CASCADE,
+    FOREIGN KEY (virtual_func_decl_id) REFERENCES virtual_func_decls(id) ON DELETE CASCADE,
+    FOREIGN KEY (virtual_func_impl_id) REFERENCES virtual_func_impls(id) ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS func_calls_2_funcs_func_call_id ON func_calls_2_funcs (func_call_id);
+CREATE INDEX IF NOT EXISTS func_calls_2_funcs_func_decl_id ON func_calls_2_funcs (func_decl_id);
+CREATE INDEX IF NOT EXISTS func_calls_2_funcs_func_impl_id ON func_calls_2_funcs (func_impl_id);
+CREATE INDEX IF NOT EXISTS func_calls_2_funcs_virtual_func_decl_id ON func_calls_2_funcs (virtual_func_decl_id);
+CREATE INDEX IF NOT EXISTS func_calls_2_funcs_virtual_func_impl_id ON func_calls_2_funcs (virtual_func_impl_id);
+";
+
+pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection.db.execute_batch(FUNC_CALL_SQL_CREATE_TABLE)?;
+    db_connection
+        .db
+        .execute_batch(FUNC_CALL_2_FUNC_SQL_CREATE_TABLE)?;
+
+    Ok(())
 }
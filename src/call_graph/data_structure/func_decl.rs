@@ -3,10 +3,12 @@ use std::rc::Rc;
 
 use rusqlite::params;
 
+use crate::call_graph::function_search::function_occurrence::FunctionOccurrence;
 use crate::location::position::Position;
 use crate::location::range::Range;
 
 use super::super::database::database_sqlite_internal::DatabaseSqliteInternal;
+use super::file_structure::FileStructure;
 use super::func_structure::FuncMentionType;
 use super::func_structure::FuncStructure;
 use super::helper::func_creation_args::FuncCreationArgs;
@@ -19,18 +21,19 @@ impl FuncStructure {
     ) -> Self {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
-            INSERT INTO func_decls (name, qualified_name, qual_type,
+            INSERT INTO func_decls (name, qualified_name, qual_type, usr,
                 range_start_line, range_start_column, range_end_line, range_end_column,
                 cpp_file_id, hpp_file_id, cpp_class_id)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             )
             .unwrap();
         let result = stmt.insert(params![
             args.name.clone(),
             args.qualified_name.clone(),
             args.qualified_type.clone(),
+            args.usr.clone(),
             args.range.start.line.to_string(),
             args.range.start.column.to_string(),
             args.range.end.line.to_string(),
@@ -47,6 +50,7 @@ impl FuncStructure {
             args.qualified_name.clone(),
             None,
             args.qualified_type.clone(),
+            args.usr.clone(),
             args.range.clone(),
             Some(FuncMentionType::FuncDecl),
         )
@@ -58,11 +62,11 @@ impl FuncStructure {
     ) -> Vec<Rc<RefCell<FuncStructure>>> {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT id, name, qualified_name, qual_type,
                 range_start_line, range_start_column, range_end_line, range_end_column,
-                cpp_file_id, hpp_file_id, cpp_class_id
+                cpp_file_id, hpp_file_id, cpp_class_id, usr
             FROM func_decls
             WHERE cpp_file_id = ?
                 OR hpp_file_id = ?
@@ -78,6 +82,7 @@ impl FuncStructure {
                     row.get(2)?,
                     None,
                     row.get(3)?,
+                    row.get(11)?,
                     Range::new(
                         Position::new(row.get(4)?, row.get(5)?),
                         Position::new(row.get(6)?, row.get(7)?),
@@ -94,6 +99,104 @@ impl FuncStructure {
 
         func_decls
     }
+
+    pub fn get_func_decls_by_qualified_name_glob(
+        db_connection: &DatabaseSqliteInternal,
+        pattern: &str,
+    ) -> Vec<Rc<RefCell<FuncStructure>>> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            SELECT id, name, qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column, usr
+            FROM func_decls
+            WHERE qualified_name GLOB ?1 OR qualified_name GLOB ?2",
+            )
+            .unwrap();
+        let func_decl_iter = stmt
+            .query_map(params![pattern, format!("{} '*", pattern)], |row| {
+                Ok(FuncStructure::new(
+                    row.get(0)?,
+                    Some(db_connection.clone()),
+                    row.get(1)?,
+                    row.get(2)?,
+                    None,
+                    row.get(3)?,
+                    row.get(8)?,
+                    Range::new(
+                        Position::new(row.get(4)?, row.get(5)?),
+                        Position::new(row.get(6)?, row.get(7)?),
+                    ),
+                    Some(FuncMentionType::FuncDecl),
+                ))
+            })
+            .unwrap();
+
+        let mut func_decls = Vec::new();
+        for func_decl in func_decl_iter {
+            func_decls.push(Rc::new(RefCell::new(func_decl.unwrap())));
+        }
+
+        func_decls
+    }
+
+    pub fn get_func_decls_linked_to_call(
+        db_connection: &DatabaseSqliteInternal,
+        call_id: (Option<u64>, Option<u64>),
+    ) -> Vec<FunctionOccurrence> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            SELECT id, name, qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column,
+                cpp_file_id, hpp_file_id, cpp_class_id, usr
+            FROM func_decls
+            WHERE id IN (
+                    SELECT func_decl_id
+                    FROM func_calls_2_funcs
+                    WHERE func_call_id = ?)
+                OR id IN (
+                    SELECT func_decl_id
+                    FROM virtual_func_calls_2_funcs
+                    WHERE virtual_func_call_id = ?)",
+            )
+            .unwrap();
+        let mut rows = stmt.query(params![call_id.0, call_id.1]).unwrap();
+
+        let mut func_decls = Vec::new();
+        while let Some(row) = rows.next().unwrap() {
+            let file = FileStructure::get_file_name_from_parent_id(
+                db_connection,
+                (
+                    row.get(8).unwrap(),
+                    row.get(9).unwrap(),
+                    row.get(10).unwrap(),
+                ),
+            );
+            let func_decl = FuncStructure::new(
+                row.get(0).unwrap(),
+                Some(db_connection.clone()),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                None,
+                row.get(3).unwrap(),
+                row.get(11).unwrap(),
+                Range::new(
+                    Position::new(row.get(4).unwrap(), row.get(5).unwrap()),
+                    Position::new(row.get(6).unwrap(), row.get(7).unwrap()),
+                ),
+                Some(FuncMentionType::FuncDecl),
+            );
+            func_decls.push(FunctionOccurrence::new(
+                file.unwrap_or_default(),
+                Rc::new(RefCell::new(func_decl)),
+            ));
+        }
+
+        func_decls
+    }
 }
 
 pub const FUNC_DECL_SQL_CREATE_TABLE: &str = "
@@ -102,6 +205,7 @@ CREATE TABLE func_decls (
     name               TEXT NOT NULL,
     qualified_name     TEXT NOT NULL,
     qual_type          TEXT NOT NULL,
+    usr                TEXT NULL,
     range_start_line   INTEGER,
     range_start_column INTEGER,
     range_end_line     INTEGER,
@@ -114,9 +218,16 @@ CREATE TABLE func_decls (
     FOREIGN KEY (cpp_file_id) REFERENCES cpp_files(id) ON DELETE CASCADE,
     FOREIGN KEY (hpp_file_id) REFERENCES hpp_files(id) ON DELETE CASCADE,
     FOREIGN KEY (cpp_class_id) REFERENCES cpp_classes(id) ON DELETE CASCADE
-)
+);
+CREATE INDEX IF NOT EXISTS func_decls_qualified_name ON func_decls (qualified_name);
+CREATE INDEX IF NOT EXISTS func_decls_usr ON func_decls (usr);
+CREATE INDEX IF NOT EXISTS func_decls_cpp_file_id ON func_decls (cpp_file_id);
+CREATE INDEX IF NOT EXISTS func_decls_hpp_file_id ON func_decls (hpp_file_id);
+CREATE INDEX IF NOT EXISTS func_decls_cpp_class_id ON func_decls (cpp_class_id);
 ";
 
-pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) {
-    let _ = db_connection.db.execute_batch(FUNC_DECL_SQL_CREATE_TABLE);
+pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection.db.execute_batch(FUNC_DECL_SQL_CREATE_TABLE)?;
+
+    Ok(())
 }
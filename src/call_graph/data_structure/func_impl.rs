@@ -3,12 +3,15 @@ use std::rc::Rc;
 
 use rusqlite::params;
 
+use crate::call_graph::function_search::function_occurrence::FunctionOccurrence;
 use crate::location::position::Position;
 use crate::location::range::Range;
 
 use super::super::database::database_sqlite_internal::DatabaseSqliteInternal;
+use super::file_structure::FileStructure;
 use super::func_structure::{FuncMentionType, FuncStructure};
 use super::helper::func_creation_args::FuncCreationArgs;
+use super::FuncBasics;
 
 impl FuncStructure {
     pub fn create_func_impl(
@@ -18,18 +21,19 @@ impl FuncStructure {
     ) -> Self {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
-        INSERT INTO func_impls (name, qualified_name, qual_type,
+        INSERT INTO func_impls (name, qualified_name, qual_type, usr,
             range_start_line, range_start_column, range_end_line, range_end_column,
             cpp_file_id, hpp_file_id, cpp_class_id)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             )
             .unwrap();
         let result = stmt.insert(params![
             args.name.clone(),
             args.qualified_name.clone(),
             args.qualified_type.clone(),
+            args.usr.clone(),
             args.range.start.line.to_string(),
             args.range.start.column.to_string(),
             args.range.end.line.to_string(),
@@ -46,6 +50,7 @@ impl FuncStructure {
             args.qualified_name.clone(),
             None,
             args.qualified_type.clone(),
+            args.usr.clone(),
             args.range.clone(),
             Some(FuncMentionType::FuncImpl),
         )
@@ -57,10 +62,10 @@ impl FuncStructure {
     ) -> Vec<Rc<RefCell<FuncStructure>>> {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT id, name, qualified_name, qual_type,
-                range_start_line, range_start_column, range_end_line, range_end_column
+                range_start_line, range_start_column, range_end_line, range_end_column, usr
             FROM func_impls
             WHERE cpp_file_id = ? OR hpp_file_id = ? OR cpp_class_id = ?",
             )
@@ -78,6 +83,7 @@ impl FuncStructure {
                 row.get(2).unwrap(),
                 None,
                 row.get(3).unwrap(),
+                row.get(8).unwrap(),
                 Range {
                     start: Position {
                         line: row.get(4).unwrap(),
@@ -94,29 +100,370 @@ impl FuncStructure {
 
         virtual_func_decls
     }
+
+    pub fn get_func_impls_by_qualified_name_glob(
+        db_connection: &DatabaseSqliteInternal,
+        pattern: &str,
+    ) -> Vec<Rc<RefCell<FuncStructure>>> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            SELECT id, name, qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column, usr
+            FROM func_impls
+            WHERE qualified_name GLOB ?1 OR qualified_name GLOB ?2",
+            )
+            .unwrap();
+        let mut rows = stmt
+            .query(params![pattern, format!("{} '*", pattern)])
+            .unwrap();
+
+        let mut func_impls = Vec::new();
+        while let Some(row) = rows.next().unwrap() {
+            func_impls.push(Rc::new(RefCell::new(FuncStructure::new(
+                row.get(0).unwrap(),
+                Some(db_connection.clone()),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                None,
+                row.get(3).unwrap(),
+                row.get(8).unwrap(),
+                Range::new(
+                    Position::new(row.get(4).unwrap(), row.get(5).unwrap()),
+                    Position::new(row.get(6).unwrap(), row.get(7).unwrap()),
+                ),
+                Some(FuncMentionType::FuncImpl),
+            ))));
+        }
+
+        func_impls
+    }
+
+    pub fn get_func_impls_calling(
+        db_connection: &DatabaseSqliteInternal,
+        func: &dyn FuncBasics,
+    ) -> Vec<FunctionOccurrence> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            SELECT id, name, qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column,
+                cpp_file_id, hpp_file_id, cpp_class_id, usr
+            FROM func_impls
+            WHERE id IN (
+                    SELECT func_impl_id
+                    FROM func_calls
+                    WHERE usr = ?4
+                        OR ((usr IS NULL OR ?4 IS NULL)
+                            AND name = ?1 AND qualified_name = ?2 AND qual_type = ?3))
+                OR id IN (
+                    SELECT func_impl_id
+                    FROM virtual_func_calls
+                    WHERE usr = ?4
+                        OR ((usr IS NULL OR ?4 IS NULL)
+                            AND name = ?1 AND qualified_name = ?2 AND qual_type = ?3))",
+            )
+            .unwrap();
+        let mut rows = stmt
+            .query(params![
+                func.get_name(),
+                func.get_qualified_name(),
+                func.get_qual_type(),
+                func.get_usr()
+            ])
+            .unwrap();
+
+        let mut callers = Vec::new();
+        while let Some(row) = rows.next().unwrap() {
+            let file = FileStructure::get_file_name_from_parent_id(
+                db_connection,
+                (
+                    row.get(8).unwrap(),
+                    row.get(9).unwrap(),
+                    row.get(10).unwrap(),
+                ),
+            );
+            let func_impl = FuncStructure::new(
+                row.get(0).unwrap(),
+                Some(db_connection.clone()),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                None,
+                row.get(3).unwrap(),
+                row.get(11).unwrap(),
+                Range::new(
+                    Position::new(row.get(4).unwrap(), row.get(5).unwrap()),
+                    Position::new(row.get(6).unwrap(), row.get(7).unwrap()),
+                ),
+                Some(FuncMentionType::FuncImpl),
+            );
+            callers.push(FunctionOccurrence::new(
+                file.unwrap_or_default(),
+                Rc::new(RefCell::new(func_impl)),
+            ));
+        }
+
+        callers
+    }
+
+    pub fn get_func_impls_referencing(
+        db_connection: &DatabaseSqliteInternal,
+        func: &dyn FuncBasics,
+    ) -> Vec<FunctionOccurrence> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            SELECT id, name, qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column,
+                cpp_file_id, hpp_file_id, cpp_class_id, usr
+            FROM func_impls
+            WHERE id IN (
+                SELECT func_impl_id
+                FROM func_refs
+                WHERE usr = ?4
+                    OR ((usr IS NULL OR ?4 IS NULL)
+                        AND name = ?1 AND qualified_name = ?2 AND qual_type = ?3))",
+            )
+            .unwrap();
+        let mut rows = stmt
+            .query(params![
+                func.get_name(),
+                func.get_qualified_name(),
+                func.get_qual_type(),
+                func.get_usr()
+            ])
+            .unwrap();
+
+        let mut referencers = Vec::new();
+        while let Some(row) = rows.next().unwrap() {
+            let file = FileStructure::get_file_name_from_parent_id(
+                db_connection,
+                (
+                    row.get(8).unwrap(),
+                    row.get(9).unwrap(),
+                    row.get(10).unwrap(),
+                ),
+            );
+            let func_impl = FuncStructure::new(
+                row.get(0).unwrap(),
+                Some(db_connection.clone()),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                None,
+                row.get(3).unwrap(),
+                row.get(11).unwrap(),
+                Range::new(
+                    Position::new(row.get(4).unwrap(), row.get(5).unwrap()),
+                    Position::new(row.get(6).unwrap(), row.get(7).unwrap()),
+                ),
+                Some(FuncMentionType::FuncImpl),
+            );
+            referencers.push(FunctionOccurrence::new(
+                file.unwrap_or_default(),
+                Rc::new(RefCell::new(func_impl)),
+            ));
+        }
+
+        referencers
+    }
+
+    pub fn get_matching_func_impls(
+        db_connection: &DatabaseSqliteInternal,
+        func: &dyn FuncBasics,
+    ) -> Vec<FunctionOccurrence> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            SELECT id, name, qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column,
+                cpp_file_id, hpp_file_id, cpp_class_id, usr
+            FROM func_impls
+            WHERE usr = ?4
+                OR ((usr IS NULL OR ?4 IS NULL)
+                    AND name = ?1 AND qualified_name = ?2 AND qual_type = ?3)",
+            )
+            .unwrap();
+        let mut rows = stmt
+            .query(params![
+                func.get_name(),
+                func.get_qualified_name(),
+                func.get_qual_type(),
+                func.get_usr()
+            ])
+            .unwrap();
+
+        let mut func_impls = Vec::new();
+        while let Some(row) = rows.next().unwrap() {
+            let file = FileStructure::get_file_name_from_parent_id(
+                db_connection,
+                (
+                    row.get(8).unwrap(),
+                    row.get(9).unwrap(),
+                    row.get(10).unwrap(),
+                ),
+            );
+            let func_impl = FuncStructure::new(
+                row.get(0).unwrap(),
+                Some(db_connection.clone()),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                None,
+                row.get(3).unwrap(),
+                row.get(11).unwrap(),
+                Range::new(
+                    Position::new(row.get(4).unwrap(), row.get(5).unwrap()),
+                    Position::new(row.get(6).unwrap(), row.get(7).unwrap()),
+                ),
+                Some(FuncMentionType::FuncImpl),
+            );
+            func_impls.push(FunctionOccurrence::new(
+                file.unwrap_or_default(),
+                Rc::new(RefCell::new(func_impl)),
+            ));
+        }
+
+        func_impls
+    }
+
+    pub fn get_func_impls_linked_to_call(
+        db_connection: &DatabaseSqliteInternal,
+        call_id: (Option<u64>, Option<u64>),
+    ) -> Vec<FunctionOccurrence> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            SELECT id, name, qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column,
+                cpp_file_id, hpp_file_id, cpp_class_id, usr
+            FROM func_impls
+            WHERE id IN (
+                    SELECT func_impl_id
+                    FROM func_calls_2_funcs
+                    WHERE func_call_id = ?)
+                OR id IN (
+                    SELECT func_impl_id
+                    FROM virtual_func_calls_2_funcs
+                    WHERE virtual_func_call_id = ?)",
+            )
+            .unwrap();
+        let mut rows = stmt.query(params![call_id.0, call_id.1]).unwrap();
+
+        let mut func_impls = Vec::new();
+        while let Some(row) = rows.next().unwrap() {
+            let file = FileStructure::get_file_name_from_parent_id(
+                db_connection,
+                (
+                    row.get(8).unwrap(),
+                    row.get(9).unwrap(),
+                    row.get(10).unwrap(),
+                ),
+            );
+            let func_impl = FuncStructure::new(
+                row.get(0).unwrap(),
+                Some(db_connection.clone()),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                None,
+                row.get(3).unwrap(),
+                row.get(11).unwrap(),
+                Range::new(
+                    Position::new(row.get(4).unwrap(), row.get(5).unwrap()),
+                    Position::new(row.get(6).unwrap(), row.get(7).unwrap()),
+                ),
+                Some(FuncMentionType::FuncImpl),
+            );
+            func_impls.push(FunctionOccurrence::new(
+                file.unwrap_or_default(),
+                Rc::new(RefCell::new(func_impl)),
+            ));
+        }
+
+        func_impls
+    }
+
+    // Template patterns are the uninstantiated bodies of function and class templates. They
+    // are stored like any other impl, but are never called themselves.
+    pub fn mark_template_pattern(
+        db_connection: &DatabaseSqliteInternal,
+        func_impl: &FuncStructure,
+    ) {
+        let table_name = match func_impl.get_func_type() {
+            Some(FuncMentionType::FuncImpl) => "func_impls",
+            Some(FuncMentionType::VirtualFuncImpl) => "virtual_func_impls",
+            _ => return,
+        };
+
+        db_connection
+            .db
+            .execute(
+                &format!(
+                    "UPDATE {} SET is_template_pattern = 1 WHERE id = ?",
+                    table_name
+                ),
+                params![func_impl.get_id()],
+            )
+            .unwrap();
+    }
+
+    pub fn is_template_pattern(
+        db_connection: &DatabaseSqliteInternal,
+        func_impl: &FuncStructure,
+    ) -> bool {
+        let table_name = match func_impl.get_func_type() {
+            Some(FuncMentionType::FuncImpl) => "func_impls",
+            Some(FuncMentionType::VirtualFuncImpl) => "virtual_func_impls",
+            _ => return false,
+        };
+
+        db_connection
+            .db
+            .query_row(
+                &format!(
+                    "SELECT is_template_pattern FROM {} WHERE id = ?",
+                    table_name
+                ),
+                params![func_impl.get_id()],
+                |row| row.get(0),
+            )
+            .unwrap_or(false)
+    }
 }
 
 pub const FUNC_IMPL_SQL_CREATE_TABLE: &str = "
 CREATE TABLE func_impls (
-    id                 INTEGER PRIMARY KEY AUTOINCREMENT,
-    name               TEXT NOT NULL,
-    qualified_name     TEXT NOT NULL,
-    qual_type          TEXT NOT NULL,
-    range_start_line   INTEGER,
-    range_start_column INTEGER,
-    range_end_line     INTEGER,
-    range_end_column   INTEGER,
-
-    cpp_file_id        INTEGER NULL,
-    hpp_file_id        INTEGER NULL,
-    cpp_class_id       INTEGER NULL,
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    name                TEXT NOT NULL,
+    qualified_name      TEXT NOT NULL,
+    qual_type           TEXT NOT NULL,
+    usr                 TEXT NULL,
+    range_start_line    INTEGER,
+    range_start_column  INTEGER,
+    range_end_line      INTEGER,
+    range_end_column    INTEGER,
+    is_template_pattern INTEGER NOT NULL DEFAULT 0,
+
+    cpp_file_id         INTEGER NULL,
+    hpp_file_id         INTEGER NULL,
+    cpp_class_id        INTEGER NULL,
 
     FOREIGN KEY (cpp_file_id) REFERENCES cpp_files(id) ON DELETE CASCADE,
     FOREIGN KEY (hpp_file_id) REFERENCES hpp_files(id) ON DELETE CASCADE,
     FOREIGN KEY (cpp_class_id) REFERENCES cpp_classes(id) ON DELETE CASCADE
-)
+);
+CREATE INDEX IF NOT EXISTS func_impls_qualified_name ON func_impls (qualified_name);
+CREATE INDEX IF NOT EXISTS func_impls_usr ON func_impls (usr);
+CREATE INDEX IF NOT EXISTS func_impls_cpp_file_id ON func_impls (cpp_file_id);
+CREATE INDEX IF NOT EXISTS func_impls_hpp_file_id ON func_impls (hpp_file_id);
+CREATE INDEX IF NOT EXISTS func_impls_cpp_class_id ON func_impls (cpp_class_id);
 ";
 
-pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) {
-    let _ = db_connection.db.execute_batch(FUNC_IMPL_SQL_CREATE_TABLE);
+pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection.db.execute_batch(FUNC_IMPL_SQL_CREATE_TABLE)?;
+
+    Ok(())
 }
@@ -0,0 +1,130 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rusqlite::params;
+
+use crate::location::position::Position;
+use crate::location::range::Range;
+
+use super::super::database::database_sqlite_internal::DatabaseSqliteInternal;
+use super::func_structure::FuncMentionType;
+use super::func_structure::FuncStructure;
+use super::helper::func_creation_args::FuncCreationArgs;
+
+// A function reference is a mention of a function which doesn't call it at this position,
+// e.g. taking its address to register it as callback.
+impl FuncStructure {
+    pub fn create_func_ref(
+        db_connection: &DatabaseSqliteInternal,
+        args: &FuncCreationArgs,
+        parent_id: (Option<u64>, Option<u64>),
+    ) -> Self {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            INSERT INTO func_refs (name, qualified_name, qual_type, usr,
+                range_start_line, range_start_column, range_end_line, range_end_column,
+                func_impl_id, virtual_func_impl_id)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .unwrap();
+        let result = stmt.insert(params![
+            args.name.clone(),
+            args.qualified_name.clone(),
+            args.qualified_type.clone(),
+            args.usr.clone(),
+            args.range.start.line.to_string(),
+            args.range.start.column.to_string(),
+            args.range.end.line.to_string(),
+            args.range.end.column.to_string(),
+            parent_id.0,
+            parent_id.1,
+        ]);
+
+        FuncStructure::new(
+            result.unwrap() as u64,
+            Some(db_connection.clone()),
+            args.name.clone(),
+            args.qualified_name.clone(),
+            None,
+            args.qualified_type.clone(),
+            args.usr.clone(),
+            args.range.clone(),
+            Some(FuncMentionType::FuncRef),
+        )
+    }
+
+    pub fn get_func_refs_from_id(
+        db_connection: &DatabaseSqliteInternal,
+        parent_id: (Option<u64>, Option<u64>),
+    ) -> Vec<Rc<RefCell<FuncStructure>>> {
+        // Databases created before references were recorded don't have the table.
+        let mut stmt = match db_connection.db.prepare_cached(
+            "
+            SELECT id, name, qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column, usr
+            FROM func_refs
+            WHERE func_impl_id = ?
+                OR virtual_func_impl_id = ?",
+        ) {
+            Ok(stmt) => stmt,
+            Err(_) => return Vec::new(),
+        };
+        let rows = stmt
+            .query_map(params![parent_id.0, parent_id.1], |row| {
+                Ok(FuncStructure::new(
+                    row.get(0).unwrap(),
+                    Some(db_connection.clone()),
+                    row.get(1).unwrap(),
+                    row.get(2).unwrap(),
+                    None,
+                    row.get(3).unwrap(),
+                    row.get(8).unwrap(),
+                    Range::new(
+                        Position::new(row.get(4).unwrap(), row.get(5).unwrap()),
+                        Position::new(row.get(6).unwrap(), row.get(7).unwrap()),
+                    ),
+                    Some(FuncMentionType::FuncRef),
+                ))
+            })
+            .unwrap();
+
+        let mut func_refs = Vec::new();
+        for func_ref in rows {
+            func_refs.push(Rc::new(RefCell::new(func_ref.unwrap())));
+        }
+
+        func_refs
+    }
+}
+
+pub const FUNC_REF_SQL_CREATE_TABLE: &str = "
+CREATE TABLE func_refs (
+    id                   INTEGER PRIMARY KEY AUTOINCREMENT,
+    name                 TEXT NOT NULL,
+    qualified_name       TEXT NOT NULL,
+    qual_type            TEXT NOT NULL,
+    usr                  TEXT NULL,
+    range_start_line     INTEGER,
+    range_start_column   INTEGER,
+    range_end_line       INTEGER,
+    range_end_column     INTEGER,
+
+    func_impl_id         INTEGER NULL,
+    virtual_func_impl_id INTEGER NULL,
+
+    FOREIGN KEY (func_impl_id) REFERENCES func_impls(id) ON DELETE CASCADE,
+    FOREIGN KEY (virtual_func_impl_id) REFERENCES virtual_func_impls(id) ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS func_refs_qualified_name ON func_refs (qualified_name);
+CREATE INDEX IF NOT EXISTS func_refs_usr ON func_refs (usr);
+CREATE INDEX IF NOT EXISTS func_refs_func_impl_id ON func_refs (func_impl_id);
+CREATE INDEX IF NOT EXISTS func_refs_virtual_func_impl_id ON func_refs (virtual_func_impl_id);
+";
+
+pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection.db.execute_batch(FUNC_REF_SQL_CREATE_TABLE)?;
+
+    Ok(())
+}
@@ -15,7 +15,7 @@ use super::FuncImplBasics;
 use super::MatchingFuncs;
 use super::VirtualFuncBasics;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum FuncMentionType {
     FuncDecl = 0,
     FuncImpl = 1,
@@ -23,6 +23,8 @@ pub enum FuncMentionType {
     VirtualFuncDecl = 3,
     VirtualFuncImpl = 4,
     VirtualFuncCall = 5,
+    FuncRef = 6,
+    UnresolvedFuncCall = 7,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone, Eq)]
@@ -35,9 +37,15 @@ pub struct FuncStructure {
     qualified_name: String,
     base_qualified_name: Option<String>,
     qual_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    usr: Option<String>,
     range: Range,
     func_calls: Vec<Rc<RefCell<FuncStructure>>>,
     virtual_func_calls: Vec<Rc<RefCell<FuncStructure>>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    func_refs: Vec<Rc<RefCell<FuncStructure>>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    unresolved_func_calls: Vec<Rc<RefCell<FuncStructure>>>,
 
     #[serde(skip)]
     func_type: Option<FuncMentionType>,
@@ -50,9 +58,12 @@ impl PartialEq for FuncStructure {
             && self.qualified_name == other.qualified_name
             && self.base_qualified_name == other.base_qualified_name
             && self.qual_type == other.qual_type
+            && self.usr == other.usr
             && self.range == other.range
             && self.func_calls == other.func_calls
-            && self.virtual_func_calls == other.virtual_func_calls;
+            && self.virtual_func_calls == other.virtual_func_calls
+            && self.func_refs == other.func_refs
+            && self.unresolved_func_calls == other.unresolved_func_calls;
     }
 }
 
@@ -64,6 +75,7 @@ impl FuncStructure {
         qualified_name: String,
         base_qualified_name: Option<String>,
         qual_type: String,
+        usr: Option<String>,
         range: Range,
         func_type: Option<FuncMentionType>,
     ) -> Self {
@@ -74,24 +86,27 @@ impl FuncStructure {
             qualified_name,
             base_qualified_name,
             qual_type,
+            usr,
             range,
             func_calls: Vec::new(),
             virtual_func_calls: Vec::new(),
+            func_refs: Vec::new(),
+            unresolved_func_calls: Vec::new(),
             func_type,
         };
 
-        if new_func.db_connection.is_some()
-            && (new_func.func_type == Some(FuncMentionType::FuncImpl)
-                || new_func.func_type == Some(FuncMentionType::VirtualFuncImpl))
-        {
-            new_func.func_calls = Self::get_func_calls_from_id(
-                &new_func.db_connection.as_ref().unwrap(),
-                new_func.get_id_func_impls(),
-            );
-            new_func.virtual_func_calls = Self::get_virtual_func_calls_from_id(
-                &new_func.db_connection.as_ref().unwrap(),
-                new_func.get_id_func_impls(),
-            );
+        if let Some(db_connection) = &new_func.db_connection {
+            if new_func.func_type == Some(FuncMentionType::FuncImpl)
+                || new_func.func_type == Some(FuncMentionType::VirtualFuncImpl)
+            {
+                let parent_id = new_func.get_id_func_impls();
+                new_func.func_calls = Self::get_func_calls_from_id(db_connection, parent_id);
+                new_func.virtual_func_calls =
+                    Self::get_virtual_func_calls_from_id(db_connection, parent_id);
+                new_func.func_refs = Self::get_func_refs_from_id(db_connection, parent_id);
+                new_func.unresolved_func_calls =
+                    Self::get_unresolved_func_calls_from_id(db_connection, parent_id);
+            }
         }
 
         new_func
@@ -114,6 +129,19 @@ impl FuncStructure {
     pub fn get_database_connection(&self) -> Option<DatabaseSqliteInternal> {
         self.db_connection.clone()
     }
+
+    // The USR identifies a function if both sides have one, otherwise the names and type are
+    // compared, e.g. for functions from the text dump of the AST.
+    fn is_same_func(&self, func_creation_args: &FuncCreationArgs) -> bool {
+        match (self.get_usr(), &func_creation_args.usr) {
+            (Some(usr), Some(other_usr)) => usr == other_usr,
+            _ => {
+                self.get_name() == func_creation_args.name
+                    && self.get_qualified_name() == func_creation_args.qualified_name
+                    && self.get_qual_type() == func_creation_args.qualified_type
+            }
+        }
+    }
 }
 
 impl FuncBasics for FuncStructure {
@@ -124,6 +152,7 @@ impl FuncBasics for FuncStructure {
             base_qualified_name: self.base_qualified_name.clone(),
             qualified_type: self.get_qual_type().to_string(),
             range: call_range.clone(),
+            usr: self.usr.clone(),
         }
     }
 
@@ -139,6 +168,10 @@ impl FuncBasics for FuncStructure {
         &self.qual_type
     }
 
+    fn get_usr(&self) -> Option<&str> {
+        self.usr.as_deref()
+    }
+
     fn get_range(&self) -> &Range {
         &self.range
     }
@@ -152,10 +185,7 @@ impl FuncBasics for FuncStructure {
     }
 
     fn equals_func_creation_args(&self, func_creation_args: &FuncCreationArgs) -> bool {
-        self.get_name() == func_creation_args.name
-            && self.get_qualified_name() == func_creation_args.qualified_name
-            && self.get_qual_type() == func_creation_args.qualified_type
-            && self.get_range() == &func_creation_args.range
+        self.is_same_func(func_creation_args) && self.get_range() == &func_creation_args.range
     }
 }
 
@@ -170,6 +200,7 @@ impl VirtualFuncBasics for FuncStructure {
             base_qualified_name: Some(self.get_base_qualified_name().to_string()),
             qualified_type: self.get_qual_type().to_string(),
             range: call_range.clone(),
+            usr: self.usr.clone(),
         }
     }
 
@@ -178,11 +209,9 @@ impl VirtualFuncBasics for FuncStructure {
     }
 
     fn equals_virtual_func_creation_args(&self, func_creation_args: &FuncCreationArgs) -> bool {
-        self.get_name() == func_creation_args.name
-            && self.get_qualified_name() == func_creation_args.qualified_name
+        self.is_same_func(func_creation_args)
             && Some(self.get_base_qualified_name().to_string())
                 == func_creation_args.base_qualified_name
-            && self.get_qual_type() == func_creation_args.qualified_type
             && self.get_range() == &func_creation_args.range
     }
 }
@@ -271,6 +300,69 @@ impl FuncImplBasics for FuncStructure {
             self.add_virtual_func_call(virtual_func_call)
         }
     }
+
+    fn get_func_refs(&mut self) -> &mut Vec<Rc<RefCell<FuncStructure>>> {
+        &mut self.func_refs
+    }
+    fn add_func_ref(&mut self, func_ref: &FuncCreationArgs) -> Rc<RefCell<FuncStructure>> {
+        let new_func_ref = Rc::new(RefCell::new(FuncStructure::create_func_ref(
+            self.db_connection.as_ref().unwrap(),
+            func_ref,
+            self.get_id_func_impls(),
+        )));
+
+        self.get_func_refs().push(new_func_ref);
+
+        self.get_func_refs().last().unwrap().clone()
+    }
+    fn get_or_add_func_ref(&mut self, func_ref: &FuncCreationArgs) -> Rc<RefCell<FuncStructure>> {
+        if self
+            .get_func_refs()
+            .iter()
+            .any(|r| r.borrow().equals_func_creation_args(func_ref))
+        {
+            self.get_func_refs()
+                .iter()
+                .find(|r| r.borrow().equals_func_creation_args(func_ref))
+                .unwrap()
+                .clone()
+        } else {
+            self.add_func_ref(func_ref)
+        }
+    }
+
+    fn get_unresolved_func_calls(&mut self) -> &mut Vec<Rc<RefCell<FuncStructure>>> {
+        &mut self.unresolved_func_calls
+    }
+    fn add_unresolved_func_call(
+        &mut self,
+        unresolved_func_call: &FuncCreationArgs,
+    ) -> Rc<RefCell<FuncStructure>> {
+        let new_unresolved_func_call =
+            Rc::new(RefCell::new(FuncStructure::create_unresolved_func_call(
+                self.db_connection.as_ref().unwrap(),
+                unresolved_func_call,
+                self.get_id_func_impls(),
+            )));
+
+        self.get_unresolved_func_calls()
+            .push(new_unresolved_func_call);
+
+        self.get_unresolved_func_calls().last().unwrap().clone()
+    }
+    fn get_or_add_unresolved_func_call(
+        &mut self,
+        unresolved_func_call: &FuncCreationArgs,
+    ) -> Rc<RefCell<FuncStructure>> {
+        let existing_call = self.get_unresolved_func_calls().iter().find(|c| {
+            c.borrow().get_name() == unresolved_func_call.name
+                && c.borrow().get_range() == &unresolved_func_call.range
+        });
+        match existing_call {
+            Some(existing_call) => existing_call.clone(),
+            None => self.add_unresolved_func_call(unresolved_func_call),
+        }
+    }
 }
 
 impl MatchingFuncs for FuncStructure {
@@ -289,5 +381,15 @@ impl MatchingFuncs for FuncStructure {
                 results.push(virtual_func_call.clone());
             }
         }
+        for func_ref in self.func_refs.iter() {
+            if func_ref.borrow().matches_position(position) {
+                results.push(func_ref.clone());
+            }
+        }
+        for unresolved_func_call in self.unresolved_func_calls.iter() {
+            if unresolved_func_call.borrow().matches_position(position) {
+                results.push(unresolved_func_call.clone());
+            }
+        }
     }
 }
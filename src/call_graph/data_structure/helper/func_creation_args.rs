@@ -7,6 +7,8 @@ pub struct FuncCreationArgs {
     pub base_qualified_name: Option<String>,
     pub qualified_type: String,
     pub range: Range,
+    // Identifies the function across translation units, see `create_usr`.
+    pub usr: Option<String>,
 }
 
 impl FuncCreationArgs {
@@ -23,6 +25,7 @@ impl FuncCreationArgs {
             base_qualified_name: base_qualified_name,
             qualified_type: qualified_type.to_string(),
             range,
+            usr: None,
         }
     }
 
@@ -23,7 +23,7 @@ impl FileStructure {
 
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             INSERT INTO hpp_files (file_name, last_analyzed)
             VALUES (?, ?)",
@@ -46,7 +46,7 @@ impl FileStructure {
     ) -> Option<Rc<RefCell<FileStructure>>> {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT id, last_analyzed
             FROM hpp_files
@@ -76,7 +76,7 @@ impl FileStructure {
     ) -> Vec<Rc<RefCell<FileStructure>>> {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT id, file_name, last_analyzed
             FROM hpp_files",
@@ -108,7 +108,7 @@ impl FileStructure {
             .as_ref()
             .unwrap()
             .db
-            .prepare(
+            .prepare_cached(
                 "
             UPDATE hpp_files
             SET last_analyzed = ?
@@ -133,7 +133,7 @@ impl FileStructure {
             .as_ref()
             .unwrap()
             .db
-            .prepare(
+            .prepare_cached(
                 "
             INSERT INTO hpp_files_2_hpp_files (current_hpp_file_id, hpp_file_id)
             VALUES (?, ?)",
@@ -149,7 +149,7 @@ impl FileStructure {
             .as_ref()
             .unwrap()
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT h.file_name
             FROM hpp_files AS h
@@ -174,7 +174,7 @@ impl FileStructure {
             .as_ref()
             .unwrap()
             .db
-            .prepare(
+            .prepare_cached(
                 "
             INSERT INTO cpp_files_2_hpp_files (cpp_file_id, hpp_file_id)
             VALUES (?, ?)",
@@ -190,7 +190,7 @@ impl FileStructure {
             .as_ref()
             .unwrap()
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT c.file_name
             FROM cpp_files AS c
@@ -208,6 +208,122 @@ impl FileStructure {
 
         referenced_from_source_files
     }
+
+    // Source files reference all headers of their translation unit, while headers only
+    // reference the headers they include directly.
+    pub fn read_included_header_files(&self) -> Vec<String> {
+        let binding = self.get_db_connection();
+        let mut stmt = binding
+            .as_ref()
+            .unwrap()
+            .db
+            .prepare_cached(if self.is_header() {
+                "
+            SELECT h.file_name
+            FROM hpp_files AS h
+            JOIN hpp_files_2_hpp_files AS h2h ON h.id = h2h.current_hpp_file_id
+            WHERE h2h.hpp_file_id = ?
+            ORDER BY h.file_name"
+            } else {
+                "
+            SELECT h.file_name
+            FROM hpp_files AS h
+            JOIN cpp_files_2_hpp_files AS c2h ON h.id = c2h.hpp_file_id
+            WHERE c2h.cpp_file_id = ?
+            ORDER BY h.file_name"
+            })
+            .unwrap();
+        let mut rows = stmt.query(params![self.get_id()]).unwrap();
+
+        let mut included_header_files = Vec::new();
+        while let Ok(Some(row)) = rows.next() {
+            let file_name: String = row.get(0).unwrap();
+            included_header_files.push(file_name);
+        }
+
+        included_header_files
+    }
+
+    pub fn get_transitive_includers(
+        db_connection: &DatabaseSqliteInternal,
+        name: &str,
+    ) -> Vec<String> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            WITH RECURSIVE includers(id) AS (
+                SELECT id
+                FROM hpp_files
+                WHERE file_name = ?1
+                UNION
+                SELECT h2h.hpp_file_id
+                FROM hpp_files_2_hpp_files AS h2h
+                JOIN includers AS i ON h2h.current_hpp_file_id = i.id
+            )
+            SELECT file_name
+            FROM hpp_files
+            WHERE id IN includers
+                AND file_name != ?1
+            UNION
+            SELECT c.file_name
+            FROM cpp_files AS c
+            JOIN cpp_files_2_hpp_files AS c2h ON c.id = c2h.cpp_file_id
+            WHERE c2h.hpp_file_id IN includers
+            ORDER BY file_name",
+            )
+            .unwrap();
+        let mut rows = stmt.query(params![name]).unwrap();
+
+        let mut includers = Vec::new();
+        while let Ok(Some(row)) = rows.next() {
+            let file_name: String = row.get(0).unwrap();
+            includers.push(file_name);
+        }
+
+        includers
+    }
+
+    pub fn get_transitive_includees(
+        db_connection: &DatabaseSqliteInternal,
+        name: &str,
+    ) -> Vec<String> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            WITH RECURSIVE includees(id) AS (
+                SELECT c2h.hpp_file_id
+                FROM cpp_files_2_hpp_files AS c2h
+                JOIN cpp_files AS c ON c.id = c2h.cpp_file_id
+                WHERE c.file_name = ?1
+                UNION
+                SELECT h2h.current_hpp_file_id
+                FROM hpp_files_2_hpp_files AS h2h
+                JOIN hpp_files AS h ON h.id = h2h.hpp_file_id
+                WHERE h.file_name = ?1
+                UNION
+                SELECT h2h.current_hpp_file_id
+                FROM hpp_files_2_hpp_files AS h2h
+                JOIN includees AS i ON h2h.hpp_file_id = i.id
+            )
+            SELECT file_name
+            FROM hpp_files
+            WHERE id IN includees
+                AND file_name != ?1
+            ORDER BY file_name",
+            )
+            .unwrap();
+        let mut rows = stmt.query(params![name]).unwrap();
+
+        let mut includees = Vec::new();
+        while let Ok(Some(row)) = rows.next() {
+            let file_name: String = row.get(0).unwrap();
+            includees.push(file_name);
+        }
+
+        includees
+    }
 }
 
 pub const HPP_FILE_SQL_CREATE_TABLE: &str = "
@@ -226,7 +342,8 @@ CREATE TABLE cpp_files_2_hpp_files (
     PRIMARY KEY (cpp_file_id, hpp_file_id),
     FOREIGN KEY (cpp_file_id) REFERENCES cpp_files (id) ON DELETE CASCADE,
     FOREIGN KEY (hpp_file_id) REFERENCES hpp_files (id) ON DELETE CASCADE
-)
+);
+CREATE INDEX IF NOT EXISTS cpp_files_2_hpp_files_hpp_file_id ON cpp_files_2_hpp_files (hpp_file_id);
 ";
 
 pub const HPP_FILE_2_HPP_FILE_SQL_CREATE_TABLE: &str = "
@@ -237,15 +354,18 @@ CREATE TABLE hpp_files_2_hpp_files (
     PRIMARY KEY (current_hpp_file_id, hpp_file_id),
     FOREIGN KEY (current_hpp_file_id) REFERENCES hpp_files (id) ON DELETE CASCADE,
     FOREIGN KEY (hpp_file_id) REFERENCES hpp_files (id) ON DELETE CASCADE
-)
+);
+CREATE INDEX IF NOT EXISTS hpp_files_2_hpp_files_hpp_file_id ON hpp_files_2_hpp_files (hpp_file_id);
 ";
 
-pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) {
-    let _ = db_connection.db.execute_batch(HPP_FILE_SQL_CREATE_TABLE);
-    let _ = db_connection
+pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection.db.execute_batch(HPP_FILE_SQL_CREATE_TABLE)?;
+    db_connection
         .db
-        .execute_batch(CPP_FILE_2_HPP_FILE_SQL_CREATE_TABLE);
-    let _ = db_connection
+        .execute_batch(CPP_FILE_2_HPP_FILE_SQL_CREATE_TABLE)?;
+    db_connection
         .db
-        .execute_batch(HPP_FILE_2_HPP_FILE_SQL_CREATE_TABLE);
+        .execute_batch(HPP_FILE_2_HPP_FILE_SQL_CREATE_TABLE)?;
+
+    Ok(())
 }
@@ -14,9 +14,11 @@ pub mod file_structure;
 pub mod func_call;
 pub mod func_decl;
 pub mod func_impl;
+pub mod func_ref;
 pub mod func_structure;
 pub mod helper;
 pub mod hpp_file;
+pub mod unresolved_func_call;
 pub mod virtual_func_call;
 pub mod virtual_func_decl;
 pub mod virtual_func_impl;
@@ -35,6 +37,7 @@ pub trait FuncBasics {
     fn get_name(&self) -> &str;
     fn get_qualified_name(&self) -> &str;
     fn get_qual_type(&self) -> &str;
+    fn get_usr(&self) -> Option<&str>;
     fn get_range(&self) -> &Range;
 
     fn get_func_type(&self) -> Option<FuncMentionType>;
@@ -69,6 +72,20 @@ pub trait FuncImplBasics: FuncBasics + MatchingFuncs {
         &mut self,
         virtual_func_call: &FuncCreationArgs,
     ) -> Rc<RefCell<FuncStructure>>;
+
+    fn get_func_refs(&mut self) -> &mut Vec<Rc<RefCell<FuncStructure>>>;
+    fn add_func_ref(&mut self, func_ref: &FuncCreationArgs) -> Rc<RefCell<FuncStructure>>;
+    fn get_or_add_func_ref(&mut self, func_ref: &FuncCreationArgs) -> Rc<RefCell<FuncStructure>>;
+
+    fn get_unresolved_func_calls(&mut self) -> &mut Vec<Rc<RefCell<FuncStructure>>>;
+    fn add_unresolved_func_call(
+        &mut self,
+        unresolved_func_call: &FuncCreationArgs,
+    ) -> Rc<RefCell<FuncStructure>>;
+    fn get_or_add_unresolved_func_call(
+        &mut self,
+        unresolved_func_call: &FuncCreationArgs,
+    ) -> Rc<RefCell<FuncStructure>>;
 }
 
 pub trait InFile {
@@ -0,0 +1,230 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rusqlite::params;
+
+use crate::location::position::Position;
+use crate::location::range::Range;
+
+use super::super::database::database_sqlite_internal::DatabaseSqliteInternal;
+use super::func_structure::FuncMentionType;
+use super::func_structure::FuncStructure;
+use super::helper::func_creation_args::FuncCreationArgs;
+use super::FuncBasics;
+
+// An unresolved function call is a call inside a template body which can't be resolved before
+// the template is instantiated. Only the looked up name is known, which is stored as name and
+// qualified name, while the qualified type stays empty.
+impl FuncStructure {
+    pub fn create_unresolved_func_call(
+        db_connection: &DatabaseSqliteInternal,
+        args: &FuncCreationArgs,
+        parent_id: (Option<u64>, Option<u64>),
+    ) -> Self {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            INSERT INTO unresolved_func_calls (name,
+                range_start_line, range_start_column, range_end_line, range_end_column,
+                func_impl_id, virtual_func_impl_id)
+            VALUES (?, ?, ?, ?, ?, ?, ?)",
+            )
+            .unwrap();
+        let result = stmt.insert(params![
+            args.name.clone(),
+            args.range.start.line.to_string(),
+            args.range.start.column.to_string(),
+            args.range.end.line.to_string(),
+            args.range.end.column.to_string(),
+            parent_id.0,
+            parent_id.1,
+        ]);
+
+        FuncStructure::new(
+            result.unwrap() as u64,
+            Some(db_connection.clone()),
+            args.name.clone(),
+            args.name.clone(),
+            None,
+            "".to_string(),
+            None,
+            args.range.clone(),
+            Some(FuncMentionType::UnresolvedFuncCall),
+        )
+    }
+
+    pub fn get_unresolved_func_calls_from_id(
+        db_connection: &DatabaseSqliteInternal,
+        parent_id: (Option<u64>, Option<u64>),
+    ) -> Vec<Rc<RefCell<FuncStructure>>> {
+        // Databases created before unresolved calls were recorded don't have the table.
+        let mut stmt = match db_connection.db.prepare_cached(
+            "
+            SELECT id, name,
+                range_start_line, range_start_column, range_end_line, range_end_column
+            FROM unresolved_func_calls
+            WHERE func_impl_id = ?
+                OR virtual_func_impl_id = ?",
+        ) {
+            Ok(stmt) => stmt,
+            Err(_) => return Vec::new(),
+        };
+        let rows = stmt
+            .query_map(params![parent_id.0, parent_id.1], |row| {
+                Ok(FuncStructure::new(
+                    row.get(0).unwrap(),
+                    Some(db_connection.clone()),
+                    row.get(1).unwrap(),
+                    row.get(1).unwrap(),
+                    None,
+                    "".to_string(),
+                    None,
+                    Range::new(
+                        Position::new(row.get(2).unwrap(), row.get(3).unwrap()),
+                        Position::new(row.get(4).unwrap(), row.get(5).unwrap()),
+                    ),
+                    Some(FuncMentionType::UnresolvedFuncCall),
+                ))
+            })
+            .unwrap();
+
+        let mut unresolved_func_calls = Vec::new();
+        for unresolved_func_call in rows {
+            unresolved_func_calls.push(Rc::new(RefCell::new(unresolved_func_call.unwrap())));
+        }
+
+        unresolved_func_calls
+    }
+
+    // Binds an unresolved call to the call made at the same position in a template
+    // instantiation.
+    pub fn bind_unresolved_func_call(
+        db_connection: &DatabaseSqliteInternal,
+        unresolved_func_call: &FuncStructure,
+        func_call: &FuncStructure,
+    ) {
+        let call_id = match func_call.get_func_type() {
+            Some(FuncMentionType::FuncCall) => (Some(func_call.get_id()), None),
+            Some(FuncMentionType::VirtualFuncCall) => (None, Some(func_call.get_id())),
+            _ => return,
+        };
+
+        db_connection
+            .db
+            .execute(
+                "
+            INSERT INTO unresolved_func_calls_2_func_calls (unresolved_func_call_id,
+                func_call_id, virtual_func_call_id)
+            VALUES (?, ?, ?)",
+                params![unresolved_func_call.get_id(), call_id.0, call_id.1],
+            )
+            .unwrap();
+    }
+
+    pub fn get_func_calls_bound_to_unresolved_call(
+        db_connection: &DatabaseSqliteInternal,
+        unresolved_func_call: &FuncStructure,
+    ) -> Vec<Rc<RefCell<FuncStructure>>> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            SELECT func_calls.id, func_calls.name, func_calls.qualified_name, NULL,
+                func_calls.qual_type,
+                func_calls.range_start_line, func_calls.range_start_column,
+                func_calls.range_end_line, func_calls.range_end_column, func_calls.usr
+            FROM unresolved_func_calls_2_func_calls
+            JOIN func_calls ON func_calls.id = unresolved_func_calls_2_func_calls.func_call_id
+            WHERE unresolved_func_calls_2_func_calls.unresolved_func_call_id = ?1
+            UNION ALL
+            SELECT virtual_func_calls.id, virtual_func_calls.name,
+                virtual_func_calls.qualified_name, virtual_func_calls.base_qualified_name,
+                virtual_func_calls.qual_type,
+                virtual_func_calls.range_start_line, virtual_func_calls.range_start_column,
+                virtual_func_calls.range_end_line, virtual_func_calls.range_end_column,
+                virtual_func_calls.usr
+            FROM unresolved_func_calls_2_func_calls
+            JOIN virtual_func_calls
+                ON virtual_func_calls.id = unresolved_func_calls_2_func_calls.virtual_func_call_id
+            WHERE unresolved_func_calls_2_func_calls.unresolved_func_call_id = ?1",
+            )
+            .unwrap();
+        let rows = stmt
+            .query_map(params![unresolved_func_call.get_id()], |row| {
+                let base_qualified_name: Option<String> = row.get(3).unwrap();
+                let func_type = match base_qualified_name {
+                    Some(_) => FuncMentionType::VirtualFuncCall,
+                    None => FuncMentionType::FuncCall,
+                };
+                Ok(FuncStructure::new(
+                    row.get(0).unwrap(),
+                    Some(db_connection.clone()),
+                    row.get(1).unwrap(),
+                    row.get(2).unwrap(),
+                    base_qualified_name,
+                    row.get(4).unwrap(),
+                    row.get(9).unwrap(),
+                    Range::new(
+                        Position::new(row.get(5).unwrap(), row.get(6).unwrap()),
+                        Position::new(row.get(7).unwrap(), row.get(8).unwrap()),
+                    ),
+                    Some(func_type),
+                ))
+            })
+            .unwrap();
+
+        let mut func_calls = Vec::new();
+        for func_call in rows {
+            func_calls.push(Rc::new(RefCell::new(func_call.unwrap())));
+        }
+
+        func_calls
+    }
+}
+
+pub const UNRESOLVED_FUNC_CALL_SQL_CREATE_TABLE: &str = "
+CREATE TABLE unresolved_func_calls (
+    id                   INTEGER PRIMARY KEY AUTOINCREMENT,
+    name                 TEXT NOT NULL,
+    range_start_line     INTEGER,
+    range_start_column   INTEGER,
+    range_end_line       INTEGER,
+    range_end_column     INTEGER,
+
+    func_impl_id         INTEGER NULL,
+    virtual_func_impl_id INTEGER NULL,
+
+    FOREIGN KEY (func_impl_id) REFERENCES func_impls(id) ON DELETE CASCADE,
+    FOREIGN KEY (virtual_func_impl_id) REFERENCES virtual_func_impls(id) ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS unresolved_func_calls_func_impl_id ON unresolved_func_calls (func_impl_id);
+CREATE INDEX IF NOT EXISTS unresolved_func_calls_virtual_func_impl_id ON unresolved_func_calls (virtual_func_impl_id);
+";
+
+pub const UNRESOLVED_FUNC_CALL_2_FUNC_CALL_SQL_CREATE_TABLE: &str = "
+CREATE TABLE unresolved_func_calls_2_func_calls (
+    unresolved_func_call_id INTEGER,
+
+    func_call_id            INTEGER NULL,
+    virtual_func_call_id    INTEGER NULL,
+
+    FOREIGN KEY (unresolved_func_call_id) REFERENCES unresolved_func_calls(id) ON DELETE CASCADE,
+    FOREIGN KEY (func_call_id) REFERENCES func_calls(id) ON DELETE CASCADE,
+    FOREIGN KEY (virtual_func_call_id) REFERENCES virtual_func_calls(id) ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS unresolved_func_calls_2_func_calls_unresolved_func_call_id ON unresolved_func_calls_2_func_calls (unresolved_func_call_id);
+CREATE INDEX IF NOT EXISTS unresolved_func_calls_2_func_calls_func_call_id ON unresolved_func_calls_2_func_calls (func_call_id);
+CREATE INDEX IF NOT EXISTS unresolved_func_calls_2_func_calls_virtual_func_call_id ON unresolved_func_calls_2_func_calls (virtual_func_call_id);
+";
+
+pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection
+        .db
+        .execute_batch(UNRESOLVED_FUNC_CALL_SQL_CREATE_TABLE)?;
+    db_connection
+        .db
+        .execute_batch(UNRESOLVED_FUNC_CALL_2_FUNC_CALL_SQL_CREATE_TABLE)?;
+
+    Ok(())
+}
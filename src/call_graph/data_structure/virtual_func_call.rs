@@ -20,12 +20,12 @@ impl FuncStructure {
     ) -> Self {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
-            INSERT INTO virtual_func_calls (name, qualified_name, base_qualified_name, qual_type,
+            INSERT INTO virtual_func_calls (name, qualified_name, base_qualified_name, qual_type, usr,
                 range_start_line, range_start_column, range_end_line, range_end_column,
                 func_impl_id, virtual_func_impl_id)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             )
             .unwrap();
         let result = stmt.insert(params![
@@ -33,6 +33,7 @@ impl FuncStructure {
             args.qualified_name.clone(),
             args.base_qualified_name.clone(),
             args.qualified_type.clone(),
+            args.usr.clone(),
             args.range.start.line.to_string(),
             args.range.start.column.to_string(),
             args.range.end.line.to_string(),
@@ -48,6 +49,7 @@ impl FuncStructure {
             args.qualified_name.clone(),
             args.base_qualified_name.clone(),
             args.qualified_type.clone(),
+            args.usr.clone(),
             args.range.clone(),
             Some(FuncMentionType::VirtualFuncCall),
         )
@@ -59,10 +61,10 @@ impl FuncStructure {
     ) -> Vec<Rc<RefCell<FuncStructure>>> {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT id, name, qualified_name, base_qualified_name, qual_type,
-                range_start_line, range_start_column, range_end_line, range_end_column
+                range_start_line, range_start_column, range_end_line, range_end_column, usr
             FROM virtual_func_calls
             WHERE func_impl_id = ?
                 OR virtual_func_impl_id = ?",
@@ -77,6 +79,7 @@ impl FuncStructure {
                     row.get(2).unwrap(),
                     Some(row.get(3).unwrap()),
                     row.get(4).unwrap(),
+                    row.get(9).unwrap(),
                     Range::new(
                         Position::new(row.get(5).unwrap(), row.get(6).unwrap()),
                         Position::new(row.get(7).unwrap(), row.get(8).unwrap()),
@@ -100,20 +103,23 @@ impl FuncStructure {
     ) -> Vec<Rc<RefCell<FuncStructure>>> {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT id, name, qualified_name, base_qualified_name, qual_type,
-                range_start_line, range_start_column, range_end_line, range_end_column
+                range_start_line, range_start_column, range_end_line, range_end_column, usr
             FROM virtual_func_calls
-            WHERE name = ? AND qualified_name = ? AND qual_type = ?",
+            WHERE usr = ?4
+                OR ((usr IS NULL OR ?4 IS NULL)
+                    AND name = ?1 AND qualified_name = ?2 AND qual_type = ?3)",
             )
             .unwrap();
         let rows = stmt
             .query_map(
-                [
+                params![
                     func.get_name(),
                     func.get_qualified_name(),
                     func.get_qual_type(),
+                    func.get_usr()
                 ],
                 |row| {
                     Ok(FuncStructure::new(
@@ -123,6 +129,7 @@ impl FuncStructure {
                         row.get(2).unwrap(),
                         Some(row.get(3).unwrap()),
                         row.get(4).unwrap(),
+                        row.get(9).unwrap(),
                         Range::new(
                             Position::new(row.get(5).unwrap(), row.get(6).unwrap()),
                             Position::new(row.get(7).unwrap(), row.get(8).unwrap()),
@@ -140,6 +147,59 @@ impl FuncStructure {
 
         func_calls
     }
+
+    // Links every virtual function call to all matching implementations. Calls without any
+    // implementation are linked to one matching declaration instead. The links are rebuilt
+    // completely, so this has to be called after all translation units are walked.
+    pub fn link_virtual_func_calls_2_funcs(db_connection: &DatabaseSqliteInternal) {
+        db_connection
+            .db
+            .execute_batch(
+                "
+            DELETE FROM virtual_func_calls_2_funcs;
+            INSERT INTO virtual_func_calls_2_funcs (virtual_func_call_id, func_impl_id)
+                SELECT virtual_func_calls.id, func_impls.id
+                FROM virtual_func_calls
+                JOIN func_impls
+                    ON func_impls.usr = virtual_func_calls.usr
+                    OR ((func_impls.usr IS NULL OR virtual_func_calls.usr IS NULL)
+                        AND func_impls.name = virtual_func_calls.name
+                        AND func_impls.qualified_name = virtual_func_calls.qualified_name
+                        AND func_impls.qual_type = virtual_func_calls.qual_type);
+            INSERT INTO virtual_func_calls_2_funcs (virtual_func_call_id, virtual_func_impl_id)
+                SELECT virtual_func_calls.id, virtual_func_impls.id
+                FROM virtual_func_calls
+                JOIN virtual_func_impls
+                    ON virtual_func_impls.usr = virtual_func_calls.usr
+                    OR ((virtual_func_impls.usr IS NULL OR virtual_func_calls.usr IS NULL)
+                        AND virtual_func_impls.name = virtual_func_calls.name
+                        AND virtual_func_impls.qualified_name = virtual_func_calls.qualified_name
+                        AND virtual_func_impls.qual_type = virtual_func_calls.qual_type);
+            INSERT INTO virtual_func_calls_2_funcs (virtual_func_call_id, func_decl_id)
+                SELECT virtual_func_calls.id, MIN(func_decls.id)
+                FROM virtual_func_calls
+                JOIN func_decls
+                    ON func_decls.usr = virtual_func_calls.usr
+                    OR ((func_decls.usr IS NULL OR virtual_func_calls.usr IS NULL)
+                        AND func_decls.name = virtual_func_calls.name
+                        AND func_decls.qualified_name = virtual_func_calls.qualified_name
+                        AND func_decls.qual_type = virtual_func_calls.qual_type)
+                WHERE virtual_func_calls.id NOT IN (SELECT virtual_func_call_id FROM virtual_func_calls_2_funcs)
+                GROUP BY virtual_func_calls.id;
+            INSERT INTO virtual_func_calls_2_funcs (virtual_func_call_id, virtual_func_decl_id)
+                SELECT virtual_func_calls.id, MIN(virtual_func_decls.id)
+                FROM virtual_func_calls
+                JOIN virtual_func_decls
+                    ON virtual_func_decls.usr = virtual_func_calls.usr
+                    OR ((virtual_func_decls.usr IS NULL OR virtual_func_calls.usr IS NULL)
+                        AND virtual_func_decls.name = virtual_func_calls.name
+                        AND virtual_func_decls.qualified_name = virtual_func_calls.qualified_name
+                        AND virtual_func_decls.qual_type = virtual_func_calls.qual_type)
+                WHERE virtual_func_calls.id NOT IN (SELECT virtual_func_call_id FROM virtual_func_calls_2_funcs)
+                GROUP BY virtual_func_calls.id;",
+            )
+            .unwrap();
+    }
 }
 
 pub const VIRTUAL_FUNC_CALL_SQL_CREATE_TABLE: &str = "
@@ -149,6 +209,7 @@ CREATE TABLE virtual_func_calls (
     qualified_name       TEXT NOT NULL,
     base_qualified_name  TEXT NOT NULL,
     qual_type            TEXT NOT NULL,
+    usr                  TEXT NULL,
     range_start_line     INTEGER,
     range_start_column   INTEGER,
     range_end_line       INTEGER,
@@ -159,11 +220,43 @@ CREATE TABLE virtual_func_calls (
 
     FOREIGN KEY (func_impl_id) REFERENCES func_impls(id) ON DELETE CASCADE,
     FOREIGN KEY (virtual_func_impl_id) REFERENCES virtual_func_impls(id) ON DELETE CASCADE
-)
+);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_qualified_name ON virtual_func_calls (qualified_name);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_usr ON virtual_func_calls (usr);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_base_qualified_name ON virtual_func_calls (base_qualified_name);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_func_impl_id ON virtual_func_calls (func_impl_id);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_virtual_func_impl_id ON virtual_func_calls (virtual_func_impl_id);
+";
+
+pub const VIRTUAL_FUNC_CALL_2_FUNC_SQL_CREATE_TABLE: &str = "
+CREATE TABLE virtual_func_calls_2_funcs (
+    virtual_func_call_id INTEGER,
+
+    func_decl_id         INTEGER NULL,
+    func_impl_id         INTEGER NULL,
+    virtual_func_decl_id INTEGER NULL,
+    virtual_func_impl_id INTEGER NULL,
+
+    FOREIGN KEY (virtual_func_call_id) REFERENCES virtual_func_calls(id) ON DELETE CASCADE,
+    FOREIGN KEY (func_decl_id) REFERENCES func_decls(id) ON DELETE CASCADE,
+    FOREIGN KEY (func_impl_id) REFERENCES func_impls(id) ON DELETE CASCADE,
+    FOREIGN KEY (virtual_func_decl_id) REFERENCES virtual_func_decls(id) ON DELETE CASCADE,
+    FOREIGN KEY (virtual_func_impl_id) REFERENCES virtual_func_impls(id) ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_2_funcs_virtual_func_call_id ON virtual_func_calls_2_funcs (virtual_func_call_id);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_2_funcs_func_decl_id ON virtual_func_calls_2_funcs (func_decl_id);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_2_funcs_func_impl_id ON virtual_func_calls_2_funcs (func_impl_id);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_2_funcs_virtual_func_decl_id ON virtual_func_calls_2_funcs (virtual_func_decl_id);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_2_funcs_virtual_func_impl_id ON virtual_func_calls_2_funcs (virtual_func_impl_id);
 ";
 
-pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) {
-    let _ = db_connection
+pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection
         .db
-        .execute_batch(VIRTUAL_FUNC_CALL_SQL_CREATE_TABLE);
+        .execute_batch(VIRTUAL_FUNC_CALL_SQL_CREATE_TABLE)?;
+    db_connection
+        .db
+        .execute_batch(VIRTUAL_FUNC_CALL_2_FUNC_SQL_CREATE_TABLE)?;
+
+    Ok(())
 }
@@ -3,10 +3,12 @@ use std::rc::Rc;
 
 use rusqlite::params;
 
+use crate::call_graph::function_search::function_occurrence::FunctionOccurrence;
 use crate::location::position::Position;
 use crate::location::range::Range;
 
 use super::super::database::database_sqlite_internal::DatabaseSqliteInternal;
+use super::file_structure::FileStructure;
 use super::func_structure::FuncMentionType;
 use super::func_structure::FuncStructure;
 use super::helper::func_creation_args::FuncCreationArgs;
@@ -19,12 +21,12 @@ impl FuncStructure {
     ) -> Self {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
-            INSERT INTO virtual_func_decls (name, qualified_name, base_qualified_name, qual_type,
+            INSERT INTO virtual_func_decls (name, qualified_name, base_qualified_name, qual_type, usr,
                 range_start_line, range_start_column, range_end_line, range_end_column,
                 cpp_file_id, hpp_file_id, cpp_class_id)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             )
             .unwrap();
         let result = stmt.insert(params![
@@ -32,6 +34,7 @@ impl FuncStructure {
             args.qualified_name.clone(),
             args.base_qualified_name.clone(),
             args.qualified_type.clone(),
+            args.usr.clone(),
             args.range.start.line.to_string(),
             args.range.start.column.to_string(),
             args.range.end.line.to_string(),
@@ -48,6 +51,7 @@ impl FuncStructure {
             args.qualified_name.clone(),
             args.base_qualified_name.clone(),
             args.qualified_type.clone(),
+            args.usr.clone(),
             args.range.clone(),
             Some(FuncMentionType::VirtualFuncDecl),
         )
@@ -59,10 +63,10 @@ impl FuncStructure {
     ) -> Vec<Rc<RefCell<FuncStructure>>> {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT id, name, qualified_name, base_qualified_name, qual_type,
-                range_start_line, range_start_column, range_end_line, range_end_column
+                range_start_line, range_start_column, range_end_line, range_end_column, usr
             FROM virtual_func_decls
             WHERE cpp_file_id = ? OR hpp_file_id = ? OR cpp_class_id = ?",
             )
@@ -80,6 +84,7 @@ impl FuncStructure {
                 row.get(2).unwrap(),
                 Some(row.get(3).unwrap()),
                 row.get(4).unwrap(),
+                row.get(9).unwrap(),
                 Range {
                     start: Position {
                         line: row.get(5).unwrap(),
@@ -96,6 +101,102 @@ impl FuncStructure {
 
         virtual_func_decls
     }
+
+    pub fn get_virtual_func_decls_by_qualified_name_glob(
+        db_connection: &DatabaseSqliteInternal,
+        pattern: &str,
+    ) -> Vec<Rc<RefCell<FuncStructure>>> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            SELECT id, name, qualified_name, base_qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column, usr
+            FROM virtual_func_decls
+            WHERE qualified_name GLOB ?1 OR qualified_name GLOB ?2",
+            )
+            .unwrap();
+        let mut rows = stmt
+            .query(params![pattern, format!("{} '*", pattern)])
+            .unwrap();
+
+        let mut virtual_func_decls = Vec::new();
+        while let Some(row) = rows.next().unwrap() {
+            virtual_func_decls.push(Rc::new(RefCell::new(FuncStructure::new(
+                row.get(0).unwrap(),
+                Some(db_connection.clone()),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                Some(row.get(3).unwrap()),
+                row.get(4).unwrap(),
+                row.get(9).unwrap(),
+                Range::new(
+                    Position::new(row.get(5).unwrap(), row.get(6).unwrap()),
+                    Position::new(row.get(7).unwrap(), row.get(8).unwrap()),
+                ),
+                Some(FuncMentionType::VirtualFuncDecl),
+            ))));
+        }
+
+        virtual_func_decls
+    }
+
+    pub fn get_virtual_func_decls_linked_to_call(
+        db_connection: &DatabaseSqliteInternal,
+        call_id: (Option<u64>, Option<u64>),
+    ) -> Vec<FunctionOccurrence> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            SELECT id, name, qualified_name, base_qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column,
+                cpp_file_id, hpp_file_id, cpp_class_id, usr
+            FROM virtual_func_decls
+            WHERE id IN (
+                    SELECT virtual_func_decl_id
+                    FROM func_calls_2_funcs
+                    WHERE func_call_id = ?)
+                OR id IN (
+                    SELECT virtual_func_decl_id
+                    FROM virtual_func_calls_2_funcs
+                    WHERE virtual_func_call_id = ?)",
+            )
+            .unwrap();
+        let mut rows = stmt.query(params![call_id.0, call_id.1]).unwrap();
+
+        let mut virtual_func_decls = Vec::new();
+        while let Some(row) = rows.next().unwrap() {
+            let file = FileStructure::get_file_name_from_parent_id(
+                db_connection,
+                (
+                    row.get(9).unwrap(),
+                    row.get(10).unwrap(),
+                    row.get(11).unwrap(),
+                ),
+            );
+            let virtual_func_decl = FuncStructure::new(
+                row.get(0).unwrap(),
+                Some(db_connection.clone()),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                Some(row.get(3).unwrap()),
+                row.get(4).unwrap(),
+                row.get(12).unwrap(),
+                Range::new(
+                    Position::new(row.get(5).unwrap(), row.get(6).unwrap()),
+                    Position::new(row.get(7).unwrap(), row.get(8).unwrap()),
+                ),
+                Some(FuncMentionType::VirtualFuncDecl),
+            );
+            virtual_func_decls.push(FunctionOccurrence::new(
+                file.unwrap_or_default(),
+                Rc::new(RefCell::new(virtual_func_decl)),
+            ));
+        }
+
+        virtual_func_decls
+    }
 }
 
 pub const VIRTUAL_FUNC_DECL_SQL_CREATE_TABLE: &str = "
@@ -105,6 +206,7 @@ CREATE TABLE virtual_func_decls (
     qualified_name      TEXT NOT NULL,
     base_qualified_name TEXT NOT NULL,
     qual_type           TEXT NOT NULL,
+    usr                 TEXT NULL,
     range_start_line    INTEGER,
     range_start_column  INTEGER,
     range_end_line      INTEGER,
@@ -117,11 +219,19 @@ CREATE TABLE virtual_func_decls (
     FOREIGN KEY (cpp_file_id) REFERENCES cpp_files(id) ON DELETE CASCADE,
     FOREIGN KEY (hpp_file_id) REFERENCES hpp_files(id) ON DELETE CASCADE,
     FOREIGN KEY (cpp_class_id) REFERENCES cpp_classes(id) ON DELETE CASCADE
-)
+);
+CREATE INDEX IF NOT EXISTS virtual_func_decls_qualified_name ON virtual_func_decls (qualified_name);
+CREATE INDEX IF NOT EXISTS virtual_func_decls_usr ON virtual_func_decls (usr);
+CREATE INDEX IF NOT EXISTS virtual_func_decls_base_qualified_name ON virtual_func_decls (base_qualified_name);
+CREATE INDEX IF NOT EXISTS virtual_func_decls_cpp_file_id ON virtual_func_decls (cpp_file_id);
+CREATE INDEX IF NOT EXISTS virtual_func_decls_hpp_file_id ON virtual_func_decls (hpp_file_id);
+CREATE INDEX IF NOT EXISTS virtual_func_decls_cpp_class_id ON virtual_func_decls (cpp_class_id);
 ";
 
-pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) {
-    let _ = db_connection
+pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection
         .db
-        .execute_batch(VIRTUAL_FUNC_DECL_SQL_CREATE_TABLE);
+        .execute_batch(VIRTUAL_FUNC_DECL_SQL_CREATE_TABLE)?;
+
+    Ok(())
 }
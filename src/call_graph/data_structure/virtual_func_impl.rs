@@ -3,13 +3,17 @@ use std::rc::Rc;
 
 use rusqlite::params;
 
+use crate::call_graph::function_search::function_occurrence::FunctionOccurrence;
 use crate::location::position::Position;
 use crate::location::range::Range;
 
 use super::super::database::database_sqlite_internal::DatabaseSqliteInternal;
+use super::file_structure::FileStructure;
 use super::func_structure::FuncMentionType;
 use super::func_structure::FuncStructure;
 use super::helper::func_creation_args::FuncCreationArgs;
+use super::FuncBasics;
+use super::VirtualFuncBasics;
 
 impl FuncStructure {
     pub fn create_virtual_func_impl(
@@ -19,12 +23,12 @@ impl FuncStructure {
     ) -> Self {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
-        INSERT INTO virtual_func_impls (name, qualified_name, base_qualified_name, qual_type,
+        INSERT INTO virtual_func_impls (name, qualified_name, base_qualified_name, qual_type, usr,
             range_start_line, range_start_column, range_end_line, range_end_column,
             cpp_file_id, hpp_file_id, cpp_class_id)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             )
             .unwrap();
         let result = stmt.insert(params![
@@ -32,6 +36,7 @@ impl FuncStructure {
             args.qualified_name.clone(),
             args.base_qualified_name.clone(),
             args.qualified_type.clone(),
+            args.usr.clone(),
             args.range.start.line.to_string(),
             args.range.start.column.to_string(),
             args.range.end.line.to_string(),
@@ -48,6 +53,7 @@ impl FuncStructure {
             args.qualified_name.clone(),
             args.base_qualified_name.clone(),
             args.qualified_type.clone(),
+            args.usr.clone(),
             args.range.clone(),
             Some(FuncMentionType::VirtualFuncImpl),
         )
@@ -59,10 +65,10 @@ impl FuncStructure {
     ) -> Vec<Rc<RefCell<FuncStructure>>> {
         let mut stmt = db_connection
             .db
-            .prepare(
+            .prepare_cached(
                 "
             SELECT id, name, qualified_name, base_qualified_name, qual_type,
-                range_start_line, range_start_column, range_end_line, range_end_column
+                range_start_line, range_start_column, range_end_line, range_end_column, usr
             FROM virtual_func_impls
             WHERE cpp_file_id = ? OR hpp_file_id = ? OR cpp_class_id = ?",
             )
@@ -80,6 +86,7 @@ impl FuncStructure {
                 row.get(2).unwrap(),
                 Some(row.get(3).unwrap()),
                 row.get(4).unwrap(),
+                row.get(9).unwrap(),
                 Range {
                     start: Position {
                         line: row.get(5).unwrap(),
@@ -96,6 +103,369 @@ impl FuncStructure {
 
         virtual_func_decls
     }
+
+    pub fn get_virtual_func_impls_by_qualified_name_glob(
+        db_connection: &DatabaseSqliteInternal,
+        pattern: &str,
+    ) -> Vec<Rc<RefCell<FuncStructure>>> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            SELECT id, name, qualified_name, base_qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column, usr
+            FROM virtual_func_impls
+            WHERE qualified_name GLOB ?1 OR qualified_name GLOB ?2",
+            )
+            .unwrap();
+        let mut rows = stmt
+            .query(params![pattern, format!("{} '*", pattern)])
+            .unwrap();
+
+        let mut virtual_func_impls = Vec::new();
+        while let Some(row) = rows.next().unwrap() {
+            virtual_func_impls.push(Rc::new(RefCell::new(FuncStructure::new(
+                row.get(0).unwrap(),
+                Some(db_connection.clone()),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                Some(row.get(3).unwrap()),
+                row.get(4).unwrap(),
+                row.get(9).unwrap(),
+                Range::new(
+                    Position::new(row.get(5).unwrap(), row.get(6).unwrap()),
+                    Position::new(row.get(7).unwrap(), row.get(8).unwrap()),
+                ),
+                Some(FuncMentionType::VirtualFuncImpl),
+            ))));
+        }
+
+        virtual_func_impls
+    }
+
+    pub fn get_virtual_func_impls_calling(
+        db_connection: &DatabaseSqliteInternal,
+        func: &dyn FuncBasics,
+    ) -> Vec<FunctionOccurrence> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            SELECT id, name, qualified_name, base_qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column,
+                cpp_file_id, hpp_file_id, cpp_class_id, usr
+            FROM virtual_func_impls
+            WHERE id IN (
+                    SELECT virtual_func_impl_id
+                    FROM func_calls
+                    WHERE usr = ?4
+                        OR ((usr IS NULL OR ?4 IS NULL)
+                            AND name = ?1 AND qualified_name = ?2 AND qual_type = ?3))
+                OR id IN (
+                    SELECT virtual_func_impl_id
+                    FROM virtual_func_calls
+                    WHERE usr = ?4
+                        OR ((usr IS NULL OR ?4 IS NULL)
+                            AND name = ?1 AND qualified_name = ?2 AND qual_type = ?3))",
+            )
+            .unwrap();
+        let mut rows = stmt
+            .query(params![
+                func.get_name(),
+                func.get_qualified_name(),
+                func.get_qual_type(),
+                func.get_usr()
+            ])
+            .unwrap();
+
+        let mut callers = Vec::new();
+        while let Some(row) = rows.next().unwrap() {
+            let file = FileStructure::get_file_name_from_parent_id(
+                db_connection,
+                (
+                    row.get(9).unwrap(),
+                    row.get(10).unwrap(),
+                    row.get(11).unwrap(),
+                ),
+            );
+            let virtual_func_impl = FuncStructure::new(
+                row.get(0).unwrap(),
+                Some(db_connection.clone()),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                Some(row.get(3).unwrap()),
+                row.get(4).unwrap(),
+                row.get(12).unwrap(),
+                Range::new(
+                    Position::new(row.get(5).unwrap(), row.get(6).unwrap()),
+                    Position::new(row.get(7).unwrap(), row.get(8).unwrap()),
+                ),
+                Some(FuncMentionType::VirtualFuncImpl),
+            );
+            callers.push(FunctionOccurrence::new(
+                file.unwrap_or_default(),
+                Rc::new(RefCell::new(virtual_func_impl)),
+            ));
+        }
+
+        callers
+    }
+
+    pub fn get_virtual_func_impls_referencing(
+        db_connection: &DatabaseSqliteInternal,
+        func: &dyn FuncBasics,
+    ) -> Vec<FunctionOccurrence> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            SELECT id, name, qualified_name, base_qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column,
+                cpp_file_id, hpp_file_id, cpp_class_id, usr
+            FROM virtual_func_impls
+            WHERE id IN (
+                SELECT virtual_func_impl_id
+                FROM func_refs
+                WHERE usr = ?4
+                    OR ((usr IS NULL OR ?4 IS NULL)
+                        AND name = ?1 AND qualified_name = ?2 AND qual_type = ?3))",
+            )
+            .unwrap();
+        let mut rows = stmt
+            .query(params![
+                func.get_name(),
+                func.get_qualified_name(),
+                func.get_qual_type(),
+                func.get_usr()
+            ])
+            .unwrap();
+
+        let mut referencers = Vec::new();
+        while let Some(row) = rows.next().unwrap() {
+            let file = FileStructure::get_file_name_from_parent_id(
+                db_connection,
+                (
+                    row.get(9).unwrap(),
+                    row.get(10).unwrap(),
+                    row.get(11).unwrap(),
+                ),
+            );
+            let virtual_func_impl = FuncStructure::new(
+                row.get(0).unwrap(),
+                Some(db_connection.clone()),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                Some(row.get(3).unwrap()),
+                row.get(4).unwrap(),
+                row.get(12).unwrap(),
+                Range::new(
+                    Position::new(row.get(5).unwrap(), row.get(6).unwrap()),
+                    Position::new(row.get(7).unwrap(), row.get(8).unwrap()),
+                ),
+                Some(FuncMentionType::VirtualFuncImpl),
+            );
+            referencers.push(FunctionOccurrence::new(
+                file.unwrap_or_default(),
+                Rc::new(RefCell::new(virtual_func_impl)),
+            ));
+        }
+
+        referencers
+    }
+
+    pub fn get_matching_virtual_func_impls(
+        db_connection: &DatabaseSqliteInternal,
+        func: &dyn FuncBasics,
+    ) -> Vec<FunctionOccurrence> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            SELECT id, name, qualified_name, base_qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column,
+                cpp_file_id, hpp_file_id, cpp_class_id, usr
+            FROM virtual_func_impls
+            WHERE usr = ?4
+                OR ((usr IS NULL OR ?4 IS NULL)
+                    AND name = ?1 AND qualified_name = ?2 AND qual_type = ?3)",
+            )
+            .unwrap();
+        let mut rows = stmt
+            .query(params![
+                func.get_name(),
+                func.get_qualified_name(),
+                func.get_qual_type(),
+                func.get_usr()
+            ])
+            .unwrap();
+
+        let mut virtual_func_impls = Vec::new();
+        while let Some(row) = rows.next().unwrap() {
+            let file = FileStructure::get_file_name_from_parent_id(
+                db_connection,
+                (
+                    row.get(9).unwrap(),
+                    row.get(10).unwrap(),
+                    row.get(11).unwrap(),
+                ),
+            );
+            let virtual_func_impl = FuncStructure::new(
+                row.get(0).unwrap(),
+                Some(db_connection.clone()),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                Some(row.get(3).unwrap()),
+                row.get(4).unwrap(),
+                row.get(12).unwrap(),
+                Range::new(
+                    Position::new(row.get(5).unwrap(), row.get(6).unwrap()),
+                    Position::new(row.get(7).unwrap(), row.get(8).unwrap()),
+                ),
+                Some(FuncMentionType::VirtualFuncImpl),
+            );
+            virtual_func_impls.push(FunctionOccurrence::new(
+                file.unwrap_or_default(),
+                Rc::new(RefCell::new(virtual_func_impl)),
+            ));
+        }
+
+        virtual_func_impls
+    }
+
+    pub fn get_virtual_func_impls_linked_to_call(
+        db_connection: &DatabaseSqliteInternal,
+        call_id: (Option<u64>, Option<u64>),
+    ) -> Vec<FunctionOccurrence> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            SELECT id, name, qualified_name, base_qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column,
+                cpp_file_id, hpp_file_id, cpp_class_id, usr
+            FROM virtual_func_impls
+            WHERE id IN (
+                    SELECT virtual_func_impl_id
+                    FROM func_calls_2_funcs
+                    WHERE func_call_id = ?)
+                OR id IN (
+                    SELECT virtual_func_impl_id
+                    FROM virtual_func_calls_2_funcs
+                    WHERE virtual_func_call_id = ?)",
+            )
+            .unwrap();
+        let mut rows = stmt.query(params![call_id.0, call_id.1]).unwrap();
+
+        let mut virtual_func_impls = Vec::new();
+        while let Some(row) = rows.next().unwrap() {
+            let file = FileStructure::get_file_name_from_parent_id(
+                db_connection,
+                (
+                    row.get(9).unwrap(),
+                    row.get(10).unwrap(),
+                    row.get(11).unwrap(),
+                ),
+            );
+            let virtual_func_impl = FuncStructure::new(
+                row.get(0).unwrap(),
+                Some(db_connection.clone()),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                Some(row.get(3).unwrap()),
+                row.get(4).unwrap(),
+                row.get(12).unwrap(),
+                Range::new(
+                    Position::new(row.get(5).unwrap(), row.get(6).unwrap()),
+                    Position::new(row.get(7).unwrap(), row.get(8).unwrap()),
+                ),
+                Some(FuncMentionType::VirtualFuncImpl),
+            );
+            virtual_func_impls.push(FunctionOccurrence::new(
+                file.unwrap_or_default(),
+                Rc::new(RefCell::new(virtual_func_impl)),
+            ));
+        }
+
+        virtual_func_impls
+    }
+
+    // Returns all implementations overriding the same base function. If a class name is given
+    // only implementations of the class itself and its sub classes are returned.
+    pub fn get_overriding_virtual_func_impls(
+        db_connection: &DatabaseSqliteInternal,
+        func: &dyn VirtualFuncBasics,
+        class_name: Option<&str>,
+    ) -> Vec<FunctionOccurrence> {
+        let mut stmt = db_connection
+            .db
+            .prepare_cached(
+                "
+            WITH RECURSIVE sub_classes(class_name) AS (
+                SELECT ?4
+                UNION
+                SELECT child_classes.class_name
+                FROM sub_classes
+                JOIN cpp_classes AS parent_classes
+                    ON parent_classes.class_name = sub_classes.class_name
+                JOIN cpp_classes_2_cpp_classes
+                    ON cpp_classes_2_cpp_classes.parent_class_id = parent_classes.id
+                JOIN cpp_classes AS child_classes
+                    ON child_classes.id = cpp_classes_2_cpp_classes.child_class_id
+            )
+            SELECT id, name, qualified_name, base_qualified_name, qual_type,
+                range_start_line, range_start_column, range_end_line, range_end_column,
+                cpp_file_id, hpp_file_id, cpp_class_id, usr
+            FROM virtual_func_impls
+            WHERE base_qualified_name = ?1
+                AND name = ?2
+                AND qual_type = ?3
+                AND (?4 IS NULL
+                    OR qualified_name IN (
+                        SELECT class_name || '::' || ?2 || ' ''' || ?3 || ''''
+                        FROM sub_classes))",
+            )
+            .unwrap();
+        let mut rows = stmt
+            .query(params![
+                func.get_base_qualified_name(),
+                func.get_name(),
+                func.get_qual_type(),
+                class_name
+            ])
+            .unwrap();
+
+        let mut virtual_func_impls = Vec::new();
+        while let Some(row) = rows.next().unwrap() {
+            let file = FileStructure::get_file_name_from_parent_id(
+                db_connection,
+                (
+                    row.get(9).unwrap(),
+                    row.get(10).unwrap(),
+                    row.get(11).unwrap(),
+                ),
+            );
+            let virtual_func_impl = FuncStructure::new(
+                row.get(0).unwrap(),
+                Some(db_connection.clone()),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                Some(row.get(3).unwrap()),
+                row.get(4).unwrap(),
+                row.get(12).unwrap(),
+                Range::new(
+                    Position::new(row.get(5).unwrap(), row.get(6).unwrap()),
+                    Position::new(row.get(7).unwrap(), row.get(8).unwrap()),
+                ),
+                Some(FuncMentionType::VirtualFuncImpl),
+            );
+            virtual_func_impls.push(FunctionOccurrence::new(
+                file.unwrap_or_default(),
+                Rc::new(RefCell::new(virtual_func_impl)),
+            ));
+        }
+
+        virtual_func_impls
+    }
 }
 
 pub const VIRTUAL_FUNC_IMPL_SQL_CREATE_TABLE: &str = "
@@ -105,10 +475,12 @@ CREATE TABLE virtual_func_impls (
     qualified_name      TEXT NOT NULL,
     base_qualified_name TEXT NOT NULL,
     qual_type           TEXT NOT NULL,
+    usr                 TEXT NULL,
     range_start_line    INTEGER,
     range_start_column  INTEGER,
     range_end_line      INTEGER,
     range_end_column    INTEGER,
+    is_template_pattern INTEGER NOT NULL DEFAULT 0,
 
     cpp_file_id         INTEGER NULL,
     hpp_file_id         INTEGER NULL,
@@ -117,11 +489,19 @@ CREATE TABLE virtual_func_impls (
     FOREIGN KEY (cpp_file_id) REFERENCES cpp_files(id) ON DELETE CASCADE,
     FOREIGN KEY (hpp_file_id) REFERENCES hpp_files(id) ON DELETE CASCADE,
     FOREIGN KEY (cpp_class_id) REFERENCES cpp_classes(id) ON DELETE CASCADE
-)
+);
+CREATE INDEX IF NOT EXISTS virtual_func_impls_qualified_name ON virtual_func_impls (qualified_name);
+CREATE INDEX IF NOT EXISTS virtual_func_impls_usr ON virtual_func_impls (usr);
+CREATE INDEX IF NOT EXISTS virtual_func_impls_base_qualified_name ON virtual_func_impls (base_qualified_name);
+CREATE INDEX IF NOT EXISTS virtual_func_impls_cpp_file_id ON virtual_func_impls (cpp_file_id);
+CREATE INDEX IF NOT EXISTS virtual_func_impls_hpp_file_id ON virtual_func_impls (hpp_file_id);
+CREATE INDEX IF NOT EXISTS virtual_func_impls_cpp_class_id ON virtual_func_impls (cpp_class_id);
 ";
 
-pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) {
-    let _ = db_connection
+pub fn create_database_tables(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection
         .db
-        .execute_batch(VIRTUAL_FUNC_IMPL_SQL_CREATE_TABLE);
+        .execute_batch(VIRTUAL_FUNC_IMPL_SQL_CREATE_TABLE)?;
+
+    Ok(())
 }
@@ -44,10 +44,10 @@ mod tests {
 
     #[test]
     fn test_database_content() {
-        let database_sqlite = DatabaseSqlite::create_database(
-            &PathBuf::from(file_in_directory!("database_content_test.db")),
-            false,
-        );
+        // Opening the database upgrades its schema, so keep the checked in file untouched.
+        let file = PathBuf::from(file_in_directory!("database_content_test_copy.db"));
+        std::fs::copy(file_in_directory!("database_content_test.db"), &file).unwrap();
+        let database_sqlite = DatabaseSqlite::create_database(&file, false);
 
         let sqlite_content = database_sqlite.get_db_content();
 
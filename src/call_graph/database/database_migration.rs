@@ -0,0 +1,234 @@
+use super::database_sqlite_internal::DatabaseSqliteInternal;
+
+// The SQL of each migration is kept as it was when the version was introduced, so that later
+// changes of the table definitions don't change what an old database is upgraded to.
+
+const MIGRATION_TO_VERSION_2_SQL: &str = "
+CREATE TABLE func_calls_2_funcs (
+    func_call_id         INTEGER,
+
+    func_decl_id         INTEGER NULL,
+    func_impl_id         INTEGER NULL,
+    virtual_func_decl_id INTEGER NULL,
+    virtual_func_impl_id INTEGER NULL,
+
+    FOREIGN KEY (func_call_id) REFERENCES func_calls(id) ON DELETE CASCADE,
+    FOREIGN KEY (func_decl_id) REFERENCES func_decls(id) ON DELETE CASCADE,
+    FOREIGN KEY (func_impl_id) REFERENCES func_impls(id) ON DELETE CASCADE,
+    FOREIGN KEY (virtual_func_decl_id) REFERENCES virtual_func_decls(id) ON DELETE CASCADE,
+    FOREIGN KEY (virtual_func_impl_id) REFERENCES virtual_func_impls(id) ON DELETE CASCADE
+);
+CREATE TABLE virtual_func_calls_2_funcs (
+    virtual_func_call_id INTEGER,
+
+    func_decl_id         INTEGER NULL,
+    func_impl_id         INTEGER NULL,
+    virtual_func_decl_id INTEGER NULL,
+    virtual_func_impl_id INTEGER NULL,
+
+    FOREIGN KEY (virtual_func_call_id) REFERENCES virtual_func_calls(id) ON DELETE CASCADE,
+    FOREIGN KEY (func_decl_id) REFERENCES func_decls(id) ON DELETE CASCADE,
+    FOREIGN KEY (func_impl_id) REFERENCES func_impls(id) ON DELETE CASCADE,
+    FOREIGN KEY (virtual_func_decl_id) REFERENCES virtual_func_decls(id) ON DELETE CASCADE,
+    FOREIGN KEY (virtual_func_impl_id) REFERENCES virtual_func_impls(id) ON DELETE CASCADE
+);
+CREATE TABLE func_refs (
+    id                   INTEGER PRIMARY KEY AUTOINCREMENT,
+    name                 TEXT NOT NULL,
+    qualified_name       TEXT NOT NULL,
+    qual_type            TEXT NOT NULL,
+    range_start_line     INTEGER,
+    range_start_column   INTEGER,
+    range_end_line       INTEGER,
+    range_end_column     INTEGER,
+
+    func_impl_id         INTEGER NULL,
+    virtual_func_impl_id INTEGER NULL,
+
+    FOREIGN KEY (func_impl_id) REFERENCES func_impls(id) ON DELETE CASCADE,
+    FOREIGN KEY (virtual_func_impl_id) REFERENCES virtual_func_impls(id) ON DELETE CASCADE
+);
+CREATE TABLE unresolved_func_calls (
+    id                   INTEGER PRIMARY KEY AUTOINCREMENT,
+    name                 TEXT NOT NULL,
+    range_start_line     INTEGER,
+    range_start_column   INTEGER,
+    range_end_line       INTEGER,
+    range_end_column     INTEGER,
+
+    func_impl_id         INTEGER NULL,
+    virtual_func_impl_id INTEGER NULL,
+
+    FOREIGN KEY (func_impl_id) REFERENCES func_impls(id) ON DELETE CASCADE,
+    FOREIGN KEY (virtual_func_impl_id) REFERENCES virtual_func_impls(id) ON DELETE CASCADE
+);
+CREATE TABLE unresolved_func_calls_2_func_calls (
+    unresolved_func_call_id INTEGER,
+
+    func_call_id            INTEGER NULL,
+    virtual_func_call_id    INTEGER NULL,
+
+    FOREIGN KEY (unresolved_func_call_id) REFERENCES unresolved_func_calls(id) ON DELETE CASCADE,
+    FOREIGN KEY (func_call_id) REFERENCES func_calls(id) ON DELETE CASCADE,
+    FOREIGN KEY (virtual_func_call_id) REFERENCES virtual_func_calls(id) ON DELETE CASCADE
+);
+INSERT INTO func_calls_2_funcs (func_call_id, func_impl_id)
+    SELECT func_calls.id, func_impls.id
+    FROM func_calls
+    JOIN func_impls
+        ON func_impls.name = func_calls.name
+        AND func_impls.qualified_name = func_calls.qualified_name
+        AND func_impls.qual_type = func_calls.qual_type;
+INSERT INTO func_calls_2_funcs (func_call_id, virtual_func_impl_id)
+    SELECT func_calls.id, virtual_func_impls.id
+    FROM func_calls
+    JOIN virtual_func_impls
+        ON virtual_func_impls.name = func_calls.name
+        AND virtual_func_impls.qualified_name = func_calls.qualified_name
+        AND virtual_func_impls.qual_type = func_calls.qual_type;
+INSERT INTO func_calls_2_funcs (func_call_id, func_decl_id)
+    SELECT func_calls.id, MIN(func_decls.id)
+    FROM func_calls
+    JOIN func_decls
+        ON func_decls.name = func_calls.name
+        AND func_decls.qualified_name = func_calls.qualified_name
+        AND func_decls.qual_type = func_calls.qual_type
+    WHERE func_calls.id NOT IN (SELECT func_call_id FROM func_calls_2_funcs)
+    GROUP BY func_calls.id;
+INSERT INTO func_calls_2_funcs (func_call_id, virtual_func_decl_id)
+    SELECT func_calls.id, MIN(virtual_func_decls.id)
+    FROM func_calls
+    JOIN virtual_func_decls
+        ON virtual_func_decls.name = func_calls.name
+        AND virtual_func_decls.qualified_name = func_calls.qualified_name
+        AND virtual_func_decls.qual_type = func_calls.qual_type
+    WHERE func_calls.id NOT IN (SELECT func_call_id FROM func_calls_2_funcs)
+    GROUP BY func_calls.id;
+INSERT INTO virtual_func_calls_2_funcs (virtual_func_call_id, func_impl_id)
+    SELECT virtual_func_calls.id, func_impls.id
+    FROM virtual_func_calls
+    JOIN func_impls
+        ON func_impls.name = virtual_func_calls.name
+        AND func_impls.qualified_name = virtual_func_calls.qualified_name
+        AND func_impls.qual_type = virtual_func_calls.qual_type;
+INSERT INTO virtual_func_calls_2_funcs (virtual_func_call_id, virtual_func_impl_id)
+    SELECT virtual_func_calls.id, virtual_func_impls.id
+    FROM virtual_func_calls
+    JOIN virtual_func_impls
+        ON virtual_func_impls.name = virtual_func_calls.name
+        AND virtual_func_impls.qualified_name = virtual_func_calls.qualified_name
+        AND virtual_func_impls.qual_type = virtual_func_calls.qual_type;
+INSERT INTO virtual_func_calls_2_funcs (virtual_func_call_id, func_decl_id)
+    SELECT virtual_func_calls.id, MIN(func_decls.id)
+    FROM virtual_func_calls
+    JOIN func_decls
+        ON func_decls.name = virtual_func_calls.name
+        AND func_decls.qualified_name = virtual_func_calls.qualified_name
+        AND func_decls.qual_type = virtual_func_calls.qual_type
+    WHERE virtual_func_calls.id NOT IN (SELECT virtual_func_call_id FROM virtual_func_calls_2_funcs)
+    GROUP BY virtual_func_calls.id;
+INSERT INTO virtual_func_calls_2_funcs (virtual_func_call_id, virtual_func_decl_id)
+    SELECT virtual_func_calls.id, MIN(virtual_func_decls.id)
+    FROM virtual_func_calls
+    JOIN virtual_func_decls
+        ON virtual_func_decls.name = virtual_func_calls.name
+        AND virtual_func_decls.qualified_name = virtual_func_calls.qualified_name
+        AND virtual_func_decls.qual_type = virtual_func_calls.qual_type
+    WHERE virtual_func_calls.id NOT IN (SELECT virtual_func_call_id FROM virtual_func_calls_2_funcs)
+    GROUP BY virtual_func_calls.id;
+";
+
+const MIGRATION_TO_VERSION_3_SQL: &str = "
+CREATE INDEX IF NOT EXISTS cpp_classes_cpp_file_id ON cpp_classes (cpp_file_id);
+CREATE INDEX IF NOT EXISTS cpp_classes_hpp_file_id ON cpp_classes (hpp_file_id);
+CREATE INDEX IF NOT EXISTS cpp_classes_cpp_class_id ON cpp_classes (cpp_class_id);
+CREATE INDEX IF NOT EXISTS cpp_classes_2_cpp_classes_child_class_id ON cpp_classes_2_cpp_classes (child_class_id);
+CREATE INDEX IF NOT EXISTS func_calls_qualified_name ON func_calls (qualified_name);
+CREATE INDEX IF NOT EXISTS func_calls_func_impl_id ON func_calls (func_impl_id);
+CREATE INDEX IF NOT EXISTS func_calls_virtual_func_impl_id ON func_calls (virtual_func_impl_id);
+CREATE INDEX IF NOT EXISTS func_calls_2_funcs_func_call_id ON func_calls_2_funcs (func_call_id);
+CREATE INDEX IF NOT EXISTS func_calls_2_funcs_func_decl_id ON func_calls_2_funcs (func_decl_id);
+CREATE INDEX IF NOT EXISTS func_calls_2_funcs_func_impl_id ON func_calls_2_funcs (func_impl_id);
+CREATE INDEX IF NOT EXISTS func_calls_2_funcs_virtual_func_decl_id ON func_calls_2_funcs (virtual_func_decl_id);
+CREATE INDEX IF NOT EXISTS func_calls_2_funcs_virtual_func_impl_id ON func_calls_2_funcs (virtual_func_impl_id);
+CREATE INDEX IF NOT EXISTS func_decls_qualified_name ON func_decls (qualified_name);
+CREATE INDEX IF NOT EXISTS func_decls_cpp_file_id ON func_decls (cpp_file_id);
+CREATE INDEX IF NOT EXISTS func_decls_hpp_file_id ON func_decls (hpp_file_id);
+CREATE INDEX IF NOT EXISTS func_decls_cpp_class_id ON func_decls (cpp_class_id);
+CREATE INDEX IF NOT EXISTS func_impls_qualified_name ON func_impls (qualified_name);
+CREATE INDEX IF NOT EXISTS func_impls_cpp_file_id ON func_impls (cpp_file_id);
+CREATE INDEX IF NOT EXISTS func_impls_hpp_file_id ON func_impls (hpp_file_id);
+CREATE INDEX IF NOT EXISTS func_impls_cpp_class_id ON func_impls (cpp_class_id);
+CREATE INDEX IF NOT EXISTS func_refs_qualified_name ON func_refs (qualified_name);
+CREATE INDEX IF NOT EXISTS func_refs_func_impl_id ON func_refs (func_impl_id);
+CREATE INDEX IF NOT EXISTS func_refs_virtual_func_impl_id ON func_refs (virtual_func_impl_id);
+CREATE INDEX IF NOT EXISTS cpp_files_2_hpp_files_hpp_file_id ON cpp_files_2_hpp_files (hpp_file_id);
+CREATE INDEX IF NOT EXISTS hpp_files_2_hpp_files_hpp_file_id ON hpp_files_2_hpp_files (hpp_file_id);
+CREATE INDEX IF NOT EXISTS unresolved_func_calls_func_impl_id ON unresolved_func_calls (func_impl_id);
+CREATE INDEX IF NOT EXISTS unresolved_func_calls_virtual_func_impl_id ON unresolved_func_calls (virtual_func_impl_id);
+CREATE INDEX IF NOT EXISTS unresolved_func_calls_2_func_calls_unresolved_func_call_id ON unresolved_func_calls_2_func_calls (unresolved_func_call_id);
+CREATE INDEX IF NOT EXISTS unresolved_func_calls_2_func_calls_func_call_id ON unresolved_func_calls_2_func_calls (func_call_id);
+CREATE INDEX IF NOT EXISTS unresolved_func_calls_2_func_calls_virtual_func_call_id ON unresolved_func_calls_2_func_calls (virtual_func_call_id);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_qualified_name ON virtual_func_calls (qualified_name);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_base_qualified_name ON virtual_func_calls (base_qualified_name);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_func_impl_id ON virtual_func_calls (func_impl_id);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_virtual_func_impl_id ON virtual_func_calls (virtual_func_impl_id);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_2_funcs_virtual_func_call_id ON virtual_func_calls_2_funcs (virtual_func_call_id);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_2_funcs_func_decl_id ON virtual_func_calls_2_funcs (func_decl_id);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_2_funcs_func_impl_id ON virtual_func_calls_2_funcs (func_impl_id);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_2_funcs_virtual_func_decl_id ON virtual_func_calls_2_funcs (virtual_func_decl_id);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_2_funcs_virtual_func_impl_id ON virtual_func_calls_2_funcs (virtual_func_impl_id);
+CREATE INDEX IF NOT EXISTS virtual_func_decls_qualified_name ON virtual_func_decls (qualified_name);
+CREATE INDEX IF NOT EXISTS virtual_func_decls_base_qualified_name ON virtual_func_decls (base_qualified_name);
+CREATE INDEX IF NOT EXISTS virtual_func_decls_cpp_file_id ON virtual_func_decls (cpp_file_id);
+CREATE INDEX IF NOT EXISTS virtual_func_decls_hpp_file_id ON virtual_func_decls (hpp_file_id);
+CREATE INDEX IF NOT EXISTS virtual_func_decls_cpp_class_id ON virtual_func_decls (cpp_class_id);
+CREATE INDEX IF NOT EXISTS virtual_func_impls_qualified_name ON virtual_func_impls (qualified_name);
+CREATE INDEX IF NOT EXISTS virtual_func_impls_base_qualified_name ON virtual_func_impls (base_qualified_name);
+CREATE INDEX IF NOT EXISTS virtual_func_impls_cpp_file_id ON virtual_func_impls (cpp_file_id);
+CREATE INDEX IF NOT EXISTS virtual_func_impls_hpp_file_id ON virtual_func_impls (hpp_file_id);
+CREATE INDEX IF NOT EXISTS virtual_func_impls_cpp_class_id ON virtual_func_impls (cpp_class_id);
+";
+
+const MIGRATION_TO_VERSION_4_SQL: &str = "
+ALTER TABLE func_calls ADD COLUMN usr TEXT NULL;
+ALTER TABLE func_decls ADD COLUMN usr TEXT NULL;
+ALTER TABLE func_impls ADD COLUMN usr TEXT NULL;
+ALTER TABLE func_refs ADD COLUMN usr TEXT NULL;
+ALTER TABLE virtual_func_calls ADD COLUMN usr TEXT NULL;
+ALTER TABLE virtual_func_decls ADD COLUMN usr TEXT NULL;
+ALTER TABLE virtual_func_impls ADD COLUMN usr TEXT NULL;
+CREATE INDEX IF NOT EXISTS func_calls_usr ON func_calls (usr);
+CREATE INDEX IF NOT EXISTS func_decls_usr ON func_decls (usr);
+CREATE INDEX IF NOT EXISTS func_impls_usr ON func_impls (usr);
+CREATE INDEX IF NOT EXISTS func_refs_usr ON func_refs (usr);
+CREATE INDEX IF NOT EXISTS virtual_func_calls_usr ON virtual_func_calls (usr);
+CREATE INDEX IF NOT EXISTS virtual_func_decls_usr ON virtual_func_decls (usr);
+CREATE INDEX IF NOT EXISTS virtual_func_impls_usr ON virtual_func_impls (usr);
+";
+
+const MIGRATION_TO_VERSION_5_SQL: &str = "
+ALTER TABLE func_impls ADD COLUMN is_template_pattern INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE virtual_func_impls ADD COLUMN is_template_pattern INTEGER NOT NULL DEFAULT 0;
+";
+
+// Adds the links of calls to their functions, function references and unresolved calls. The
+// calls already stored are linked right away.
+pub fn migrate_to_version_2(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection.db.execute_batch(MIGRATION_TO_VERSION_2_SQL)
+}
+
+// Adds the indexes of all tables.
+pub fn migrate_to_version_3(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection.db.execute_batch(MIGRATION_TO_VERSION_3_SQL)
+}
+
+// Adds the USR identifying functions across translation units.
+pub fn migrate_to_version_4(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection.db.execute_batch(MIGRATION_TO_VERSION_4_SQL)
+}
+
+// Adds the marker of uninstantiated template bodies.
+pub fn migrate_to_version_5(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection.db.execute_batch(MIGRATION_TO_VERSION_5_SQL)
+}
@@ -4,38 +4,68 @@ use std::{path::PathBuf, rc::Rc};
 use rusqlite::Connection;
 
 use crate::call_graph::data_structure::file_structure::FileStructure;
+use crate::call_graph::data_structure::func_structure::{FuncMentionType, FuncStructure};
 use crate::call_graph::data_structure::{
-    cpp_class, cpp_file, func_call, func_decl, func_impl, hpp_file, virtual_func_call,
-    virtual_func_decl, virtual_func_impl,
+    cpp_class, cpp_file, func_call, func_decl, func_impl, func_ref, hpp_file, unresolved_func_call,
+    virtual_func_call, virtual_func_decl, virtual_func_impl, FuncBasics, MainDeclPosition,
+    MatchingFuncs,
 };
+use crate::call_graph::function_search::function_occurrence::FunctionOccurrence;
+use crate::call_graph::function_search::name_match::{get_name_glob_pattern, NameMatch};
+use crate::call_graph::function_search::virtual_call_targets::{
+    get_receiver_class_name, VirtualCallTargets,
+};
+use crate::location::position::{split_file_position, Position};
 
 use super::database_content::DatabaseContent;
-use super::database_sqlite_internal::DatabaseSqliteInternal;
+use super::database_migration::{
+    migrate_to_version_2, migrate_to_version_3, migrate_to_version_4, migrate_to_version_5,
+};
+use super::database_sqlite_internal::{DatabaseSqliteInternal, DatabaseTransaction};
 
 pub struct DatabaseSqlite {
     db_connection: Option<DatabaseSqliteInternal>,
 }
 
 impl DatabaseSqlite {
+    // Panics if an existing database can't be opened, see `open_database`.
     pub fn create_database(file: &PathBuf, clean: bool) -> Self {
-        let existed_before = file.exists();
-        let mut db_removed = false;
+        match DatabaseSqlite::open_database(file, clean) {
+            Ok(db) => db,
+            Err(error) => panic!("{}", error),
+        }
+    }
 
-        if existed_before && clean {
+    // Opens the database, creating it if necessary. Databases of an older schema are upgraded
+    // in place, databases of a newer schema are rejected.
+    pub fn open_database(file: &PathBuf, clean: bool) -> Result<Self, String> {
+        if file.exists() && clean {
             std::fs::remove_file(&file).unwrap();
-            db_removed = true;
         }
 
-        let db_inner = Rc::new(Connection::open(file).unwrap());
+        let db_inner = match Connection::open(file) {
+            Ok(db_inner) => Rc::new(db_inner),
+            Err(error) => {
+                return Err(format!(
+                    "Failed to open the database '{}': {}",
+                    file.display(),
+                    error
+                ))
+            }
+        };
         let db_connection = DatabaseSqliteInternal::new(db_inner);
 
-        if !existed_before || db_removed {
-            create_database_tables(&db_connection);
+        if let Err(error) = migrate_database(&db_connection) {
+            return Err(format!(
+                "Failed to open the database '{}': {}",
+                file.display(),
+                error
+            ));
         }
 
-        DatabaseSqlite {
+        Ok(DatabaseSqlite {
             db_connection: Some(db_connection),
-        }
+        })
     }
 
     pub fn create_in_memory_database() -> Self {
@@ -43,7 +73,7 @@ impl DatabaseSqlite {
 
         let db_connection = DatabaseSqliteInternal::new(db_inner);
 
-        create_database_tables(&db_connection);
+        create_database_tables(&db_connection).unwrap();
 
         DatabaseSqlite {
             db_connection: Some(db_connection),
@@ -54,6 +84,10 @@ impl DatabaseSqlite {
         self.db_connection.clone()
     }
 
+    pub fn begin_transaction(&self) -> DatabaseTransaction {
+        self.db_connection.as_ref().unwrap().begin_transaction()
+    }
+
     pub fn get_cpp_files(&self) -> Vec<Rc<RefCell<FileStructure>>> {
         FileStructure::get_cpp_files(&self.db_connection.as_ref().unwrap())
     }
@@ -97,6 +131,15 @@ impl DatabaseSqlite {
 
         FileStructure::create_hpp_file(&self.db_connection.as_ref().unwrap(), name, None)
     }
+    // Returns all headers and source files including the file directly or through other
+    // headers.
+    pub fn get_file_includers(&self, name: &str) -> Vec<String> {
+        FileStructure::get_transitive_includers(self.db_connection.as_ref().unwrap(), name)
+    }
+    // Returns all headers the file includes directly or through other headers.
+    pub fn get_file_includees(&self, name: &str) -> Vec<String> {
+        FileStructure::get_transitive_includees(self.db_connection.as_ref().unwrap(), name)
+    }
     pub fn remove_hpp_file_and_depending_content(&self, name: &str) {
         let hpp_file = self.get_hpp_file(name);
         if let Some(hpp_file) = hpp_file {
@@ -106,9 +149,206 @@ impl DatabaseSqlite {
         }
     }
 
-    // TODO implement the following functions
-    // pub fn get_func_impls_or_one_decl(func: func_basics) -> func_basics[] { todo!() }
-    // pub fn get_func_callers(func: func_basics) -> func_basics[] { todo!() }
+    pub fn get_matching_funcs(
+        &self,
+        file_name: &str,
+        position: &Position,
+    ) -> Vec<Rc<RefCell<FuncStructure>>> {
+        let mut results = Vec::new();
+
+        let file = match self.get_cpp_file(file_name) {
+            Some(cpp_file) => Some(cpp_file),
+            None => self.get_hpp_file(file_name),
+        };
+        if let Some(file) = file {
+            file.borrow().get_matching_funcs(position, &mut results);
+        }
+
+        results
+    }
+
+    pub fn get_funcs_by_qualified_name(
+        &self,
+        qualified_name: &str,
+    ) -> Vec<Rc<RefCell<FuncStructure>>> {
+        self.find_functions_by_name(qualified_name, NameMatch::Exact)
+    }
+
+    // Searches the declarations and implementations, including the virtual ones. A qualified
+    // name matches with or without its type, e.g. "add" matches "add 'int (int, int)'".
+    pub fn find_functions_by_name(
+        &self,
+        name: &str,
+        name_match: NameMatch,
+    ) -> Vec<Rc<RefCell<FuncStructure>>> {
+        let db_connection = self.db_connection.as_ref().unwrap();
+        let pattern = get_name_glob_pattern(name, name_match);
+
+        let mut results = Vec::new();
+        results.append(&mut FuncStructure::get_func_decls_by_qualified_name_glob(
+            db_connection,
+            &pattern,
+        ));
+        results.append(&mut FuncStructure::get_func_impls_by_qualified_name_glob(
+            db_connection,
+            &pattern,
+        ));
+        results.append(
+            &mut FuncStructure::get_virtual_func_decls_by_qualified_name_glob(
+                db_connection,
+                &pattern,
+            ),
+        );
+        results.append(
+            &mut FuncStructure::get_virtual_func_impls_by_qualified_name_glob(
+                db_connection,
+                &pattern,
+            ),
+        );
+
+        results
+    }
+
+    // Accepts either a qualified name or a position in the form "file:line:column".
+    pub fn get_funcs_from_name_or_position(&self, input: &str) -> Vec<Rc<RefCell<FuncStructure>>> {
+        match split_file_position(input) {
+            Some((file_name, position)) => self.get_matching_funcs(file_name, &position),
+            None => self.get_funcs_by_qualified_name(input),
+        }
+    }
+
+    pub fn link_func_calls(&self) {
+        let db_connection = self.db_connection.as_ref().unwrap();
+
+        FuncStructure::link_func_calls_2_funcs(db_connection);
+        FuncStructure::link_virtual_func_calls_2_funcs(db_connection);
+    }
+
+    // Requires the links created by `link_func_calls`.
+    pub fn get_func_impls_or_one_decl(&self, func_call: &FuncStructure) -> Vec<FunctionOccurrence> {
+        let db_connection = self.db_connection.as_ref().unwrap();
+
+        let call_id = match func_call.get_func_type() {
+            Some(FuncMentionType::FuncCall) => (Some(func_call.get_id()), None),
+            Some(FuncMentionType::VirtualFuncCall) => (None, Some(func_call.get_id())),
+            _ => return Vec::new(),
+        };
+
+        let mut funcs = FuncStructure::get_func_impls_linked_to_call(db_connection, call_id);
+        funcs.append(&mut FuncStructure::get_virtual_func_impls_linked_to_call(
+            db_connection,
+            call_id,
+        ));
+        funcs.append(&mut FuncStructure::get_func_decls_linked_to_call(
+            db_connection,
+            call_id,
+        ));
+        funcs.append(&mut FuncStructure::get_virtual_func_decls_linked_to_call(
+            db_connection,
+            call_id,
+        ));
+
+        funcs
+    }
+
+    // Expands a virtual function call into all implementations it could dispatch to.
+    pub fn get_virtual_func_call_targets(
+        &self,
+        virtual_func_call: &FuncStructure,
+        targets: VirtualCallTargets,
+    ) -> Vec<FunctionOccurrence> {
+        let db_connection = self.db_connection.as_ref().unwrap();
+
+        let mut funcs = self.get_func_impls_or_one_decl(virtual_func_call);
+        let class_name = match targets {
+            VirtualCallTargets::Static => return funcs,
+            VirtualCallTargets::AllOverrides => None,
+            VirtualCallTargets::ReceiverSubtree => get_receiver_class_name(virtual_func_call),
+        };
+
+        let overriding_funcs = FuncStructure::get_overriding_virtual_func_impls(
+            db_connection,
+            virtual_func_call,
+            class_name,
+        );
+        if !overriding_funcs.is_empty() {
+            funcs.retain(|func| {
+                func.func.borrow().get_func_type() != Some(FuncMentionType::VirtualFuncDecl)
+            });
+        }
+        for overriding_func in overriding_funcs {
+            if !funcs.contains(&overriding_func) {
+                funcs.push(overriding_func);
+            }
+        }
+
+        funcs
+    }
+
+    pub fn get_func_impls(&self, func: &dyn FuncBasics) -> Vec<FunctionOccurrence> {
+        let db_connection = self.db_connection.as_ref().unwrap();
+
+        let mut func_impls = FuncStructure::get_matching_func_impls(db_connection, func);
+        func_impls.append(&mut FuncStructure::get_matching_virtual_func_impls(
+            db_connection,
+            func,
+        ));
+
+        func_impls
+    }
+
+    pub fn get_func_callers(&self, func: &dyn FuncBasics) -> Vec<FunctionOccurrence> {
+        let db_connection = self.db_connection.as_ref().unwrap();
+
+        let mut callers = FuncStructure::get_func_impls_calling(db_connection, func);
+        callers.append(&mut FuncStructure::get_virtual_func_impls_calling(
+            db_connection,
+            func,
+        ));
+
+        callers
+    }
+
+    pub fn get_func_referencers(&self, func: &dyn FuncBasics) -> Vec<FunctionOccurrence> {
+        let db_connection = self.db_connection.as_ref().unwrap();
+
+        let mut referencers = FuncStructure::get_func_impls_referencing(db_connection, func);
+        referencers.append(&mut FuncStructure::get_virtual_func_impls_referencing(
+            db_connection,
+            func,
+        ));
+
+        referencers
+    }
+
+    // Returns the calls of all template instantiations an unresolved call was bound to.
+    pub fn get_bound_func_calls(
+        &self,
+        unresolved_func_call: &FuncStructure,
+    ) -> Vec<Rc<RefCell<FuncStructure>>> {
+        FuncStructure::get_func_calls_bound_to_unresolved_call(
+            self.db_connection.as_ref().unwrap(),
+            unresolved_func_call,
+        )
+    }
+
+    pub fn is_template_pattern(&self, func_impl: &FuncStructure) -> bool {
+        FuncStructure::is_template_pattern(self.db_connection.as_ref().unwrap(), func_impl)
+    }
+
+    pub fn get_all_func_impls(&self) -> Vec<FunctionOccurrence> {
+        let mut func_impls = Vec::new();
+        for file in self
+            .get_cpp_files()
+            .iter()
+            .chain(self.get_hpp_files().iter())
+        {
+            let file_name = file.borrow().get_name().to_string();
+            collect_func_impls(&mut *file.borrow_mut(), &file_name, &mut func_impls);
+        }
+
+        func_impls
+    }
 
     pub fn get_db_content(&self) -> DatabaseContent {
         DatabaseContent {
@@ -118,6 +358,21 @@ impl DatabaseSqlite {
     }
 }
 
+fn collect_func_impls(
+    main_decl_position: &mut dyn MainDeclPosition,
+    file_name: &str,
+    func_impls: &mut Vec<FunctionOccurrence>,
+) {
+    let mut funcs = main_decl_position.get_func_impls().clone();
+    funcs.append(&mut main_decl_position.get_virtual_func_impls().clone());
+    for func in funcs {
+        func_impls.push(FunctionOccurrence::new(file_name.to_string(), func));
+    }
+    for cpp_class in main_decl_position.get_classes().clone() {
+        collect_func_impls(&mut *cpp_class.borrow_mut(), file_name, func_impls);
+    }
+}
+
 pub fn reset_database(file: &PathBuf) -> DatabaseSqliteInternal {
     if file.exists() {
         std::fs::remove_file(&file).unwrap();
@@ -126,7 +381,7 @@ pub fn reset_database(file: &PathBuf) -> DatabaseSqliteInternal {
     let db_inner = Rc::new(Connection::open(file).unwrap());
     let db_connection = DatabaseSqliteInternal::new(db_inner);
 
-    create_database_tables(&db_connection);
+    create_database_tables(&db_connection).unwrap();
 
     db_connection
 }
@@ -136,28 +391,128 @@ pub fn create_in_memory_database() -> DatabaseSqliteInternal {
 
     let db_connection = DatabaseSqliteInternal::new(db_inner);
 
-    create_database_tables(&db_connection);
+    create_database_tables(&db_connection).unwrap();
 
     db_connection
 }
 
-fn create_database_tables(db_connection: &DatabaseSqliteInternal) {
-    let _ = db_connection.db.execute_batch("PRAGMA foreign_keys = ON");
-
-    cpp_class::create_database_tables(&db_connection);
-    cpp_file::create_database_tables(&db_connection);
-    func_call::create_database_tables(&db_connection);
-    func_decl::create_database_tables(&db_connection);
-    func_impl::create_database_tables(&db_connection);
-    hpp_file::create_database_tables(&db_connection);
-    virtual_func_call::create_database_tables(&db_connection);
-    virtual_func_decl::create_database_tables(&db_connection);
-    virtual_func_impl::create_database_tables(&db_connection);
+// The version of the schema created by `create_database_tables`, stored as `user_version` of
+// the database. Whenever tables or columns are added, the version is increased and a migration
+// from the previous version is appended to `MIGRATIONS`, see `database_migration`.
+pub const SCHEMA_VERSION: u32 = 5;
+
+type Migration = fn(&DatabaseSqliteInternal) -> rusqlite::Result<()>;
+
+// The migration at index i upgrades a database from version i + 1 to version i + 2.
+const MIGRATIONS: [Migration; 4] = [
+    migrate_to_version_2,
+    migrate_to_version_3,
+    migrate_to_version_4,
+    migrate_to_version_5,
+];
+
+fn create_database_tables(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<()> {
+    db_connection.db.execute_batch("PRAGMA foreign_keys = ON")?;
+
+    cpp_class::create_database_tables(db_connection)?;
+    cpp_file::create_database_tables(db_connection)?;
+    func_call::create_database_tables(db_connection)?;
+    func_decl::create_database_tables(db_connection)?;
+    func_impl::create_database_tables(db_connection)?;
+    func_ref::create_database_tables(db_connection)?;
+    hpp_file::create_database_tables(db_connection)?;
+    unresolved_func_call::create_database_tables(db_connection)?;
+    virtual_func_call::create_database_tables(db_connection)?;
+    virtual_func_decl::create_database_tables(db_connection)?;
+    virtual_func_impl::create_database_tables(db_connection)?;
+
+    set_schema_version(db_connection, SCHEMA_VERSION)
+}
+
+// Databases created before the schema was versioned have no `user_version` set. They are
+// recognized as version 1 by their tables, while a database without tables is still empty.
+pub fn get_schema_version(db_connection: &DatabaseSqliteInternal) -> rusqlite::Result<u32> {
+    let version: u32 = db_connection
+        .db
+        .query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    if version != 0 {
+        return Ok(version);
+    }
+
+    let table_count: u32 = db_connection.db.query_row(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'cpp_files'",
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(table_count)
+}
+
+fn set_schema_version(
+    db_connection: &DatabaseSqliteInternal,
+    version: u32,
+) -> rusqlite::Result<()> {
+    db_connection
+        .db
+        .execute_batch(&format!("PRAGMA user_version = {}", version))
+}
+
+fn migrate_database(db_connection: &DatabaseSqliteInternal) -> Result<(), String> {
+    let version = match get_schema_version(db_connection) {
+        Ok(version) => version,
+        Err(error) => return Err(format!("Failed to read the schema version: {}", error)),
+    };
+
+    if version == 0 {
+        return match create_database_tables(db_connection) {
+            Ok(_) => Ok(()),
+            Err(error) => Err(format!("Failed to create the tables: {}", error)),
+        };
+    }
+    if version > SCHEMA_VERSION {
+        return Err(format!(
+            "The database has the schema version {}, but only versions up to {} are supported. \
+            Please use a newer version of this tool or recreate the database.",
+            version, SCHEMA_VERSION
+        ));
+    }
+
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(version as usize - 1) {
+        let next_version = index as u32 + 2;
+        let result = db_connection
+            .db
+            .unchecked_transaction()
+            .and_then(|transaction| {
+                migration(db_connection)?;
+                set_schema_version(db_connection, next_version)?;
+                transaction.commit()
+            });
+        if let Err(error) = result {
+            return Err(format!(
+                "Failed to migrate the schema from version {} to {}: {}. \
+                Please recreate the database.",
+                next_version - 1,
+                next_version,
+                error
+            ));
+        }
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::file_in_directory;
+    use crate::{
+        call_graph::{
+            data_structure::{
+                helper::func_creation_args::FuncCreationArgs, FuncImplBasics, MainDeclPosition,
+                VirtualFuncBasics,
+            },
+            tests::test_database::{add_func_call, add_func_decl, add_func_impl},
+        },
+        file_in_directory,
+        location::range::Range,
+    };
 
     use super::*;
 
@@ -173,4 +528,607 @@ mod tests {
         reset_database(&file);
         assert!(file.exists());
     }
+
+    fn get_table_count(db_connection: &DatabaseSqliteInternal, table_name: &str) -> u32 {
+        db_connection
+            .db
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = ?1",
+                [table_name],
+                |row| row.get(0),
+            )
+            .unwrap()
+    }
+
+    fn get_index_count(db_connection: &DatabaseSqliteInternal, index_name: &str) -> u32 {
+        db_connection
+            .db
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'index' AND name = ?1",
+                [index_name],
+                |row| row.get(0),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_open_new_database() {
+        let file = PathBuf::from(file_in_directory!("test_schema_new.db"));
+        let database_sqlite = DatabaseSqlite::open_database(&file, true).unwrap();
+
+        let db_connection = database_sqlite.db_connection.as_ref().unwrap();
+        assert_eq!(get_schema_version(db_connection).unwrap(), SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_unversioned_database() {
+        // Work on a copy, the original database is used by other tests.
+        let file = PathBuf::from(file_in_directory!("test_schema_migration.db"));
+        std::fs::copy(file_in_directory!("database_content_test.db"), &file).unwrap();
+
+        let database_sqlite = DatabaseSqlite::open_database(&file, false).unwrap();
+        let db_connection = database_sqlite.db_connection.as_ref().unwrap();
+        assert_eq!(get_schema_version(db_connection).unwrap(), SCHEMA_VERSION);
+        assert_eq!(get_table_count(db_connection, "func_calls_2_funcs"), 1);
+        assert_eq!(
+            get_table_count(db_connection, "virtual_func_calls_2_funcs"),
+            1
+        );
+        assert_eq!(get_table_count(db_connection, "func_refs"), 1);
+        assert_eq!(get_table_count(db_connection, "unresolved_func_calls"), 1);
+        assert_eq!(
+            get_table_count(db_connection, "unresolved_func_calls_2_func_calls"),
+            1
+        );
+        assert_eq!(
+            get_index_count(db_connection, "func_decls_qualified_name"),
+            1
+        );
+        assert_eq!(
+            get_index_count(db_connection, "func_calls_2_funcs_func_call_id"),
+            1
+        );
+        assert_eq!(get_index_count(db_connection, "func_calls_usr"), 1);
+        assert!(database_sqlite.has_cpp_file("cpp_test.cpp"));
+
+        // Reopening a migrated database doesn't change it again.
+        drop(database_sqlite);
+        let database_sqlite = DatabaseSqlite::open_database(&file, false).unwrap();
+        let db_connection = database_sqlite.db_connection.as_ref().unwrap();
+        assert_eq!(get_schema_version(db_connection).unwrap(), SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_unversioned_database_links_calls() {
+        let file = PathBuf::from(file_in_directory!("test_schema_migration_links.db"));
+        std::fs::copy(file_in_directory!("database_content_test.db"), &file).unwrap();
+
+        // Add a call with the columns of the unversioned schema.
+        let connection = Connection::open(&file).unwrap();
+        connection
+            .execute_batch(
+                "
+            INSERT INTO cpp_files (file_name, last_analyzed) VALUES ('link_test.cpp', 0);
+            INSERT INTO func_impls (name, qualified_name, qual_type, cpp_file_id)
+                VALUES ('add', 'add ''int (int, int)''', 'int (int, int)',
+                    (SELECT id FROM cpp_files WHERE file_name = 'link_test.cpp'));
+            INSERT INTO func_calls (name, qualified_name, qual_type, func_impl_id)
+                VALUES ('add', 'add ''int (int, int)''', 'int (int, int)',
+                    (SELECT MAX(id) FROM func_impls));",
+            )
+            .unwrap();
+        drop(connection);
+
+        let database_sqlite = DatabaseSqlite::open_database(&file, false).unwrap();
+        let db_connection = database_sqlite.db_connection.as_ref().unwrap();
+        let func_impl_id: u64 = db_connection
+            .db
+            .query_row(
+                "SELECT func_impl_id FROM func_calls_2_funcs
+                WHERE func_call_id = (SELECT MAX(id) FROM func_calls)",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(
+            func_impl_id,
+            db_connection
+                .db
+                .query_row("SELECT MAX(id) FROM func_impls", [], |row| row
+                    .get::<_, u64>(0))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_open_database_with_newer_schema() {
+        let file = PathBuf::from(file_in_directory!("test_schema_newer.db"));
+        let database_sqlite = DatabaseSqlite::open_database(&file, true).unwrap();
+        set_schema_version(
+            database_sqlite.db_connection.as_ref().unwrap(),
+            SCHEMA_VERSION + 1,
+        )
+        .unwrap();
+        drop(database_sqlite);
+
+        let error = DatabaseSqlite::open_database(&file, false).err().unwrap();
+        assert!(error.contains(&format!("schema version {}", SCHEMA_VERSION + 1)));
+    }
+
+    fn create_callers_test_database() -> DatabaseSqlite {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+
+        let hpp_file = database_sqlite.get_or_add_hpp_file("header.h");
+        let add_decl = add_func_decl(
+            &hpp_file,
+            "add",
+            "int (int, int)",
+            Range::create(1, 1, 1, 28),
+        );
+
+        let cpp_file = database_sqlite.get_or_add_cpp_file("main.cpp");
+        let main_impl = add_func_impl(
+            &cpp_file,
+            "main",
+            "int (int, char **)",
+            Range::create(3, 1, 6, 2),
+        );
+        add_func_call(&main_impl, &add_decl, Range::create(5, 12, 5, 21));
+
+        let outer_class = cpp_file.borrow_mut().add_class("Outer");
+        let inner_class = outer_class.borrow_mut().add_class("Outer::Inner");
+        let method_impl = add_func_impl(
+            &inner_class,
+            "Outer::Inner::calc",
+            "int ()",
+            Range::create(10, 5, 13, 6),
+        );
+        add_func_call(&method_impl, &add_decl, Range::create(12, 16, 12, 25));
+
+        database_sqlite
+    }
+
+    #[test]
+    fn test_get_funcs_from_name_or_position() {
+        let database_sqlite = create_callers_test_database();
+
+        let funcs = database_sqlite.get_funcs_from_name_or_position("add");
+        assert_eq!(funcs.len(), 1);
+        assert_eq!(
+            funcs[0].borrow().get_qualified_name(),
+            "add 'int (int, int)'"
+        );
+
+        let funcs = database_sqlite.get_funcs_from_name_or_position("add 'int (int, int)'");
+        assert_eq!(funcs.len(), 1);
+
+        let funcs = database_sqlite.get_funcs_from_name_or_position("ad");
+        assert_eq!(funcs.len(), 0);
+
+        let funcs = database_sqlite.get_funcs_from_name_or_position("main.cpp:5:15");
+        assert_eq!(funcs.len(), 2);
+        assert_eq!(funcs[0].borrow().get_name(), "main");
+        assert_eq!(funcs[1].borrow().get_name(), "add");
+
+        let funcs = database_sqlite.get_funcs_from_name_or_position("unknown.cpp:5:15");
+        assert_eq!(funcs.len(), 0);
+    }
+
+    fn get_sorted_qualified_names(funcs: Vec<Rc<RefCell<FuncStructure>>>) -> Vec<String> {
+        let mut qualified_names: Vec<String> = funcs
+            .iter()
+            .map(|func| func.borrow().get_qualified_name().to_string())
+            .collect();
+        qualified_names.sort();
+        qualified_names
+    }
+
+    #[test]
+    fn test_find_functions_by_name() {
+        let database_sqlite = create_callers_test_database();
+
+        assert_eq!(
+            get_sorted_qualified_names(
+                database_sqlite.find_functions_by_name("Outer::Inner::calc", NameMatch::Exact)
+            ),
+            vec!["Outer::Inner::calc 'int ()'"]
+        );
+        assert!(database_sqlite
+            .find_functions_by_name("Outer::", NameMatch::Exact)
+            .is_empty());
+
+        assert_eq!(
+            get_sorted_qualified_names(
+                database_sqlite.find_functions_by_name("Outer::", NameMatch::Prefix)
+            ),
+            vec!["Outer::Inner::calc 'int ()'"]
+        );
+        assert_eq!(
+            get_sorted_qualified_names(
+                database_sqlite.find_functions_by_name("ma", NameMatch::Prefix)
+            ),
+            vec!["main 'int (int, char **)'"]
+        );
+
+        assert_eq!(
+            get_sorted_qualified_names(
+                database_sqlite.find_functions_by_name("*a[di]*", NameMatch::Glob)
+            ),
+            vec!["add 'int (int, int)'", "main 'int (int, char **)'"]
+        );
+        assert_eq!(
+            get_sorted_qualified_names(
+                database_sqlite.find_functions_by_name("*::calc", NameMatch::Glob)
+            ),
+            vec!["Outer::Inner::calc 'int ()'"]
+        );
+        assert!(database_sqlite
+            .find_functions_by_name("*::", NameMatch::Glob)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_name_lookup_uses_index() {
+        let database_sqlite = create_callers_test_database();
+        let db_connection = database_sqlite.db_connection.as_ref().unwrap();
+
+        let query_plan: String = db_connection
+            .db
+            .query_row(
+                "EXPLAIN QUERY PLAN SELECT id FROM func_calls WHERE qualified_name = 'add'",
+                [],
+                |row| row.get(3),
+            )
+            .unwrap();
+        assert!(query_plan.contains("func_calls_qualified_name"));
+    }
+
+    #[test]
+    fn test_get_func_callers() {
+        let database_sqlite = create_callers_test_database();
+
+        let funcs = database_sqlite.get_funcs_from_name_or_position("header.h:1:5");
+        assert_eq!(funcs.len(), 1);
+
+        let callers = database_sqlite.get_func_callers(&*funcs[0].borrow());
+        assert_eq!(callers.len(), 2);
+        assert_eq!(callers[0].file, "main.cpp");
+        assert_eq!(callers[0].func.borrow().get_name(), "main");
+        assert_eq!(
+            callers[0].func.borrow().get_range(),
+            &Range::create(3, 1, 6, 2)
+        );
+        assert_eq!(callers[1].file, "main.cpp");
+        assert_eq!(callers[1].func.borrow().get_name(), "calc");
+
+        let callers = database_sqlite.get_func_callers(&*callers[0].func.borrow());
+        assert_eq!(callers.len(), 0);
+    }
+
+    #[test]
+    fn test_get_func_referencers() {
+        let database_sqlite = create_callers_test_database();
+
+        let cpp_file = database_sqlite.get_cpp_file("main.cpp").unwrap();
+        let register_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs::new(
+            "register_callbacks",
+            "register_callbacks 'void ()'",
+            None,
+            "void ()",
+            Range::create(20, 1, 23, 2),
+        ));
+        register_impl
+            .borrow_mut()
+            .get_or_add_func_ref(&FuncCreationArgs::new(
+                "add",
+                "add 'int (int, int)'",
+                None,
+                "int (int, int)",
+                Range::create(22, 14, 22, 17),
+            ));
+
+        let funcs = database_sqlite.get_funcs_from_name_or_position("add");
+        let referencers = database_sqlite.get_func_referencers(&*funcs[0].borrow());
+        assert_eq!(referencers.len(), 1);
+        assert_eq!(referencers[0].file, "main.cpp");
+        assert_eq!(
+            referencers[0].func.borrow().get_name(),
+            "register_callbacks"
+        );
+        assert_eq!(
+            referencers[0].func.borrow_mut().get_func_refs()[0]
+                .borrow()
+                .get_func_type(),
+            Some(FuncMentionType::FuncRef)
+        );
+
+        // References aren't calls.
+        let callers = database_sqlite.get_func_callers(&*funcs[0].borrow());
+        assert_eq!(callers.len(), 2);
+
+        let funcs = database_sqlite.get_funcs_from_name_or_position("main.cpp:22:15");
+        assert_eq!(funcs.len(), 2);
+        assert_eq!(
+            funcs[1].borrow().get_func_type(),
+            Some(FuncMentionType::FuncRef)
+        );
+    }
+
+    #[test]
+    fn test_link_func_calls() {
+        let database_sqlite = create_callers_test_database();
+
+        let main_cpp_file = database_sqlite.get_cpp_file("main.cpp").unwrap();
+        main_cpp_file
+            .borrow_mut()
+            .add_func_decl(FuncCreationArgs::new(
+                "add",
+                "add 'int (int, int)'",
+                None,
+                "int (int, int)",
+                Range::create(2, 1, 2, 28),
+            ));
+        let impl_cpp_file = database_sqlite.get_or_add_cpp_file("impl.cpp");
+        impl_cpp_file
+            .borrow_mut()
+            .add_func_impl(FuncCreationArgs::new(
+                "add",
+                "add 'int (int, int)'",
+                None,
+                "int (int, int)",
+                Range::create(3, 1, 6, 2),
+            ));
+        database_sqlite.link_func_calls();
+
+        let funcs = database_sqlite.get_funcs_from_name_or_position("main.cpp:5:15");
+        assert_eq!(funcs.len(), 2);
+        let func_call = funcs[1].borrow();
+        assert_eq!(func_call.get_func_type(), Some(FuncMentionType::FuncCall));
+
+        let callees = database_sqlite.get_func_impls_or_one_decl(&func_call);
+        assert_eq!(callees.len(), 1);
+        assert_eq!(callees[0].file, "impl.cpp");
+        assert_eq!(
+            callees[0].func.borrow().get_func_type(),
+            Some(FuncMentionType::FuncImpl)
+        );
+        assert_eq!(
+            callees[0].func.borrow().get_range(),
+            &Range::create(3, 1, 6, 2)
+        );
+
+        database_sqlite.remove_cpp_file_and_depending_content("impl.cpp");
+        assert_eq!(
+            database_sqlite.get_func_impls_or_one_decl(&func_call).len(),
+            0
+        );
+
+        database_sqlite.link_func_calls();
+        let callees = database_sqlite.get_func_impls_or_one_decl(&func_call);
+        assert_eq!(callees.len(), 1);
+        assert_eq!(callees[0].file, "header.h");
+        assert_eq!(
+            callees[0].func.borrow().get_func_type(),
+            Some(FuncMentionType::FuncDecl)
+        );
+
+        let main_impl = database_sqlite.get_funcs_from_name_or_position("main.cpp:4:1");
+        assert_eq!(
+            database_sqlite
+                .get_func_impls_or_one_decl(&main_impl[0].borrow())
+                .len(),
+            0
+        );
+    }
+
+    fn create_func_creation_args_with_usr(
+        name: &str,
+        qualified_name: &str,
+        usr: &str,
+        range: Range,
+    ) -> FuncCreationArgs {
+        let mut func_creation_args =
+            FuncCreationArgs::new(name, qualified_name, None, "int ()", range);
+        func_creation_args.usr = Some(usr.to_string());
+        func_creation_args
+    }
+
+    #[test]
+    fn test_link_func_calls_by_usr() {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+        let main_cpp_file = database_sqlite.get_or_add_cpp_file("main.cpp");
+        let other_cpp_file = database_sqlite.get_or_add_cpp_file("other.cpp");
+
+        // Static functions of different translation units share the qualified name.
+        let helper_impl =
+            main_cpp_file
+                .borrow_mut()
+                .add_func_impl(create_func_creation_args_with_usr(
+                    "helper",
+                    "helper 'int ()'",
+                    "main.cpp:helper",
+                    Range::create(1, 1, 1, 30),
+                ));
+        other_cpp_file
+            .borrow_mut()
+            .add_func_impl(create_func_creation_args_with_usr(
+                "helper",
+                "helper 'int ()'",
+                "other.cpp:helper",
+                Range::create(1, 1, 1, 30),
+            ));
+        other_cpp_file
+            .borrow_mut()
+            .add_func_impl(create_func_creation_args_with_usr(
+                "get",
+                "ns::get 'int ()'",
+                "_ZN2ns3getEv",
+                Range::create(3, 1, 3, 30),
+            ));
+
+        let main_impl =
+            main_cpp_file
+                .borrow_mut()
+                .add_func_impl(create_func_creation_args_with_usr(
+                    "main",
+                    "main 'int ()'",
+                    "main",
+                    Range::create(3, 1, 6, 2),
+                ));
+        let helper_call = main_impl.borrow_mut().add_func_call(
+            &helper_impl
+                .borrow()
+                .convert_func2func_creation_args4call(&Range::create(4, 5, 4, 13)),
+        );
+        // The qualified name of a call can differ from the implementation, e.g. after a
+        // using-declaration, while the USR is the same.
+        let get_call = main_impl
+            .borrow_mut()
+            .add_func_call(&create_func_creation_args_with_usr(
+                "get",
+                "get 'int ()'",
+                "_ZN2ns3getEv",
+                Range::create(5, 12, 5, 17),
+            ));
+        database_sqlite.link_func_calls();
+
+        let callees = database_sqlite.get_func_impls_or_one_decl(&helper_call.borrow());
+        assert_eq!(callees.len(), 1);
+        assert_eq!(callees[0].file, "main.cpp");
+
+        let callees = database_sqlite.get_func_impls_or_one_decl(&get_call.borrow());
+        assert_eq!(callees.len(), 1);
+        assert_eq!(callees[0].file, "other.cpp");
+        assert_eq!(
+            callees[0].func.borrow().get_qualified_name(),
+            "ns::get 'int ()'"
+        );
+    }
+
+    #[test]
+    fn test_get_virtual_func_call_targets() {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+        let cpp_file = database_sqlite.get_or_add_cpp_file("file.cpp");
+
+        let grand_parent_class = cpp_file.borrow_mut().add_class("GrandParent");
+        let parent_class = cpp_file.borrow_mut().add_class("Parent");
+        parent_class
+            .borrow_mut()
+            .add_parent_class(&grand_parent_class);
+        let child_class = cpp_file.borrow_mut().add_class("Child");
+        child_class.borrow_mut().add_parent_class(&parent_class);
+        let sibling_class = cpp_file.borrow_mut().add_class("Sibling");
+        sibling_class
+            .borrow_mut()
+            .add_parent_class(&grand_parent_class);
+
+        let mut virtual_impls = Vec::new();
+        for (line, cpp_class) in [
+            (1, &grand_parent_class),
+            (10, &parent_class),
+            (20, &child_class),
+            (30, &sibling_class),
+        ] {
+            let class_name = cpp_class.borrow().get_name().to_string();
+            virtual_impls.push(cpp_class.borrow_mut().add_virtual_func_impl(
+                FuncCreationArgs::new(
+                    "add",
+                    &format!("{}::add 'int (int, int)'", class_name),
+                    Some("GrandParent::add 'int (int, int)'".to_string()),
+                    "int (int, int)",
+                    Range::create(line, 5, line + 3, 6),
+                ),
+            ));
+        }
+
+        let main_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs::new(
+            "main",
+            "main 'int ()'",
+            None,
+            "int ()",
+            Range::create(40, 1, 44, 2),
+        ));
+        let call_args = virtual_impls[1]
+            .borrow()
+            .convert_virtual_func2virtual_func_creation_args4call(&Range::create(42, 12, 42, 25));
+        let virtual_func_call = main_impl.borrow_mut().add_virtual_func_call(&call_args);
+        database_sqlite.link_func_calls();
+
+        let get_target_names = |targets: VirtualCallTargets| {
+            database_sqlite
+                .get_virtual_func_call_targets(&virtual_func_call.borrow(), targets)
+                .iter()
+                .map(|target| target.func.borrow().get_qualified_name().to_string())
+                .collect::<Vec<String>>()
+        };
+
+        assert_eq!(
+            get_target_names(VirtualCallTargets::Static),
+            vec!["Parent::add 'int (int, int)'"]
+        );
+        assert_eq!(
+            get_target_names(VirtualCallTargets::ReceiverSubtree),
+            vec![
+                "Parent::add 'int (int, int)'",
+                "Child::add 'int (int, int)'"
+            ]
+        );
+        assert_eq!(
+            get_target_names(VirtualCallTargets::AllOverrides),
+            vec![
+                "Parent::add 'int (int, int)'",
+                "GrandParent::add 'int (int, int)'",
+                "Child::add 'int (int, int)'",
+                "Sibling::add 'int (int, int)'"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_file_includers_and_includees() {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+        let main_file = database_sqlite.get_or_add_cpp_file("main.cpp");
+        let other_file = database_sqlite.get_or_add_cpp_file("other.cpp");
+        let outer_hpp_file = database_sqlite.get_or_add_hpp_file("outer.h");
+        let inner_hpp_file = database_sqlite.get_or_add_hpp_file("inner.h");
+        let unused_hpp_file = database_sqlite.get_or_add_hpp_file("unused.h");
+
+        for hpp_file in [&outer_hpp_file, &inner_hpp_file] {
+            hpp_file
+                .borrow_mut()
+                .add_referenced_from_source_file(&main_file);
+        }
+        unused_hpp_file
+            .borrow_mut()
+            .add_referenced_from_source_file(&other_file);
+        inner_hpp_file
+            .borrow_mut()
+            .add_referenced_from_header_file(&outer_hpp_file);
+
+        assert_eq!(
+            database_sqlite.get_file_includers("inner.h"),
+            vec!["main.cpp", "outer.h"]
+        );
+        assert_eq!(
+            database_sqlite.get_file_includers("outer.h"),
+            vec!["main.cpp"]
+        );
+        assert_eq!(
+            database_sqlite.get_file_includees("main.cpp"),
+            vec!["inner.h", "outer.h"]
+        );
+        assert_eq!(
+            database_sqlite.get_file_includees("outer.h"),
+            vec!["inner.h"]
+        );
+        assert!(database_sqlite.get_file_includees("inner.h").is_empty());
+
+        let includes = database_sqlite
+            .get_hpp_file("outer.h")
+            .unwrap()
+            .borrow()
+            .get_includes();
+        assert_eq!(includes.len(), 1);
+        assert_eq!(includes[0].borrow().get_name(), "inner.h");
+    }
 }
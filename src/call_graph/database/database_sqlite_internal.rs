@@ -15,10 +15,46 @@ impl PartialEq for DatabaseSqliteInternal {
 
 impl Eq for DatabaseSqliteInternal {}
 
+// Enough to keep all statements of the data structures prepared while walking an AST.
+const PREPARED_STATEMENT_CACHE_CAPACITY: usize = 128;
+
 impl DatabaseSqliteInternal {
     pub fn new(db: Rc<Connection>) -> Self {
+        db.set_prepared_statement_cache_capacity(PREPARED_STATEMENT_CACHE_CAPACITY);
         DatabaseSqliteInternal { db }
     }
+
+    pub fn begin_transaction(&self) -> DatabaseTransaction {
+        self.db.execute_batch("BEGIN").unwrap();
+        DatabaseTransaction {
+            db: self.db.clone(),
+            committed: false,
+        }
+    }
+}
+
+// All writes between the creation of the transaction and the commit are stored at once. Dropping
+// the transaction without a commit, e.g. while unwinding from a panic, rolls back these writes.
+// Unlike `rusqlite::Transaction` it doesn't borrow the connection, so it can be kept while the
+// database is shared.
+pub struct DatabaseTransaction {
+    db: Rc<Connection>,
+    committed: bool,
+}
+
+impl DatabaseTransaction {
+    pub fn commit(mut self) {
+        self.db.execute_batch("COMMIT").unwrap();
+        self.committed = true;
+    }
+}
+
+impl Drop for DatabaseTransaction {
+    fn drop(&mut self) {
+        if !self.committed {
+            let _ = self.db.execute_batch("ROLLBACK");
+        }
+    }
 }
 
 #[cfg(test)]
@@ -37,4 +73,35 @@ mod tests {
         let db2_instance = db.clone();
         assert_eq!(db, db2_instance);
     }
+
+    fn get_entry_count(db: &DatabaseSqliteInternal) -> u32 {
+        db.db
+            .query_row("SELECT COUNT(*) FROM entries", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_database_transaction() {
+        let db = DatabaseSqliteInternal::new(Rc::new(Connection::open_in_memory().unwrap()));
+        db.db
+            .execute_batch("CREATE TABLE entries (id INTEGER PRIMARY KEY)")
+            .unwrap();
+
+        let transaction = db.begin_transaction();
+        db.db
+            .execute("INSERT INTO entries (id) VALUES (1)", [])
+            .unwrap();
+        assert!(!db.db.is_autocommit());
+        transaction.commit();
+        assert!(db.db.is_autocommit());
+        assert_eq!(get_entry_count(&db), 1);
+
+        let transaction = db.begin_transaction();
+        db.db
+            .execute("INSERT INTO entries (id) VALUES (2)", [])
+            .unwrap();
+        drop(transaction);
+        assert!(db.db.is_autocommit());
+        assert_eq!(get_entry_count(&db), 1);
+    }
 }
@@ -0,0 +1,179 @@
+use std::path::{Path, PathBuf};
+
+use crate::ast_reader::compile_commands_reader::CompileCommand;
+use crate::call_graph::data_structure::MainDeclPosition;
+
+use super::database_sqlite::DatabaseSqlite;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DatabaseUpdate {
+    pub removed_cpp_files: Vec<String>,
+    pub changed_hpp_files: Vec<String>,
+    pub outdated_cpp_files: Vec<String>,
+}
+
+// Compares the modification times of all known files against their last analysis. Source files
+// are outdated if they changed themselves, one of their headers changed or if they are new.
+pub fn get_database_update(db: &DatabaseSqlite, entries: &[CompileCommand]) -> DatabaseUpdate {
+    let mut database_update = DatabaseUpdate::default();
+    let mut outdated_cpp_files = Vec::new();
+
+    for cpp_file in db.get_cpp_files() {
+        let cpp_file_name = cpp_file.borrow().get_name().to_string();
+        if !entries.iter().any(|entry| entry.file == cpp_file_name) {
+            database_update.removed_cpp_files.push(cpp_file_name);
+        }
+    }
+
+    for hpp_file in db.get_hpp_files() {
+        let hpp_file = hpp_file.borrow();
+        let referenced_from_source_files = hpp_file.get_referenced_from_source_files();
+
+        // Headers are stored as seen by the compiler, so relative paths belong to the
+        // directory of an including source file.
+        let directory = entries
+            .iter()
+            .find(|entry| referenced_from_source_files.contains(&entry.file))
+            .map(|entry| entry.directory.as_str())
+            .unwrap_or_default();
+        let hpp_file_path = get_entry_path(directory, hpp_file.get_name());
+
+        if is_modified_after(&hpp_file_path, hpp_file.get_last_analyzed()) {
+            database_update
+                .changed_hpp_files
+                .push(hpp_file.get_name().to_string());
+            outdated_cpp_files.extend(referenced_from_source_files);
+        }
+    }
+
+    for entry in entries {
+        let is_outdated = match db.get_cpp_file(&entry.file) {
+            Some(cpp_file) => {
+                outdated_cpp_files.contains(&entry.file)
+                    || is_modified_after(
+                        &get_entry_path(&entry.directory, &entry.file),
+                        cpp_file.borrow().get_last_analyzed(),
+                    )
+            }
+            None => true,
+        };
+
+        if is_outdated && !database_update.outdated_cpp_files.contains(&entry.file) {
+            database_update.outdated_cpp_files.push(entry.file.clone());
+        }
+    }
+
+    database_update
+}
+
+pub fn get_modification_time(path: &Path) -> Option<usize> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    let duration = modified.duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some(duration.as_millis() as usize)
+}
+
+// Files which can't be read anymore are handled as modified.
+fn is_modified_after(path: &Path, last_analyzed: usize) -> bool {
+    match get_modification_time(path) {
+        Some(modification_time) => modification_time > last_analyzed,
+        None => true,
+    }
+}
+
+fn get_entry_path(directory: &str, file: &str) -> PathBuf {
+    Path::new(directory).join(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::*;
+
+    fn create_entry(directory: &Path, file: &str) -> CompileCommand {
+        CompileCommand {
+            directory: directory.to_str().unwrap().to_string(),
+            command: Some(format!("clang++ -c {}", file)),
+            arguments: None,
+            file: file.to_string(),
+            output: None,
+        }
+    }
+
+    fn set_modification_time(path: &Path, time: SystemTime) {
+        let file = std::fs::File::options().write(true).open(path).unwrap();
+        file.set_modified(time).unwrap();
+    }
+
+    #[test]
+    fn test_get_database_update() {
+        let directory = std::env::temp_dir().join("rust_clang_call_graph_database_update");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        for file in ["main.cpp", "impl.cpp", "other.cpp", "header.h"] {
+            std::fs::write(directory.join(file), "").unwrap();
+        }
+
+        let analyzed_time = SystemTime::now() - Duration::from_secs(60);
+        let analyzed_millis = analyzed_time
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as usize;
+        for file in ["main.cpp", "impl.cpp", "other.cpp", "header.h"] {
+            set_modification_time(
+                &directory.join(file),
+                analyzed_time - Duration::from_secs(60),
+            );
+        }
+
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+        let main_file = database_sqlite.get_or_add_cpp_file("main.cpp");
+        main_file.borrow_mut().set_last_analyzed(analyzed_millis);
+        let impl_file = database_sqlite.get_or_add_cpp_file("impl.cpp");
+        impl_file.borrow_mut().set_last_analyzed(analyzed_millis);
+        let removed_file = database_sqlite.get_or_add_cpp_file("removed.cpp");
+        removed_file.borrow_mut().set_last_analyzed(analyzed_millis);
+        let hpp_file = database_sqlite.get_or_add_hpp_file("header.h");
+        hpp_file.borrow_mut().set_last_analyzed(analyzed_millis);
+        hpp_file
+            .borrow_mut()
+            .add_referenced_from_source_file(&main_file);
+
+        let entries = vec![
+            create_entry(&directory, "main.cpp"),
+            create_entry(&directory, "impl.cpp"),
+            create_entry(&directory, "other.cpp"),
+        ];
+
+        assert_eq!(
+            get_database_update(&database_sqlite, &entries),
+            DatabaseUpdate {
+                removed_cpp_files: vec!["removed.cpp".to_string()],
+                changed_hpp_files: vec![],
+                outdated_cpp_files: vec!["other.cpp".to_string()],
+            }
+        );
+
+        set_modification_time(&directory.join("impl.cpp"), SystemTime::now());
+        assert_eq!(
+            get_database_update(&database_sqlite, &entries).outdated_cpp_files,
+            vec!["impl.cpp".to_string(), "other.cpp".to_string()]
+        );
+
+        set_modification_time(&directory.join("header.h"), SystemTime::now());
+        assert_eq!(
+            get_database_update(&database_sqlite, &entries),
+            DatabaseUpdate {
+                removed_cpp_files: vec!["removed.cpp".to_string()],
+                changed_hpp_files: vec!["header.h".to_string()],
+                outdated_cpp_files: vec![
+                    "main.cpp".to_string(),
+                    "impl.cpp".to_string(),
+                    "other.cpp".to_string()
+                ],
+            }
+        );
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
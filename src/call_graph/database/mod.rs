@@ -1,3 +1,5 @@
 pub mod database_content;
+pub mod database_migration;
 pub mod database_sqlite;
 pub mod database_sqlite_internal;
+pub mod database_update;
@@ -0,0 +1,373 @@
+use std::collections::VecDeque;
+
+use crate::call_graph::{
+    data_structure::{func_structure::FuncMentionType, FuncBasics, FuncImplBasics},
+    database::database_sqlite::DatabaseSqlite,
+    function_search::{
+        function_occurrence::FunctionOccurrence,
+        virtual_call_targets::{get_receiver_class_name, VirtualCallTargets},
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DotNodeKey {
+    Func(Option<FuncMentionType>, u64),
+    Unresolved(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotNode {
+    key: DotNodeKey,
+    pub label: String,
+    pub file: Option<String>,
+    pub class_name: Option<String>,
+    pub is_root: bool,
+    pub is_decl: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotEdge {
+    pub from: usize,
+    pub to: usize,
+    pub is_virtual: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DotGraph {
+    pub nodes: Vec<DotNode>,
+    pub edges: Vec<DotEdge>,
+}
+
+impl DotGraph {
+    // Collects all functions reachable from the given roots. Without roots all function
+    // implementations of the database are used. A depth of `None` follows all calls.
+    pub fn from_database(
+        db: &DatabaseSqlite,
+        roots: &[FunctionOccurrence],
+        depth: Option<usize>,
+        virtual_call_targets: VirtualCallTargets,
+    ) -> Self {
+        let mut graph = DotGraph::default();
+        let mut open_nodes = VecDeque::new();
+
+        let all_func_impls;
+        let has_roots = !roots.is_empty();
+        let roots = if has_roots {
+            roots
+        } else {
+            all_func_impls = db.get_all_func_impls();
+            &all_func_impls
+        };
+        for root in roots {
+            let (index, is_new) = graph.get_or_add_func_node(root);
+            graph.nodes[index].is_root = has_roots;
+            if is_new {
+                open_nodes.push_back((index, root.clone(), 0));
+            }
+        }
+
+        while let Some((index, func_impl, current_depth)) = open_nodes.pop_front() {
+            if depth.is_some_and(|depth| current_depth >= depth) {
+                continue;
+            }
+
+            let mut calls = func_impl.func.borrow_mut().get_func_calls().clone();
+            calls.append(&mut func_impl.func.borrow_mut().get_virtual_func_calls().clone());
+
+            for call in calls {
+                let call = call.borrow();
+                let is_virtual = call.get_func_type() == Some(FuncMentionType::VirtualFuncCall);
+                let callees = if is_virtual {
+                    db.get_virtual_func_call_targets(&call, virtual_call_targets)
+                } else {
+                    db.get_func_impls_or_one_decl(&call)
+                };
+
+                if callees.is_empty() {
+                    let callee_index = graph.get_or_add_unresolved_node(call.get_qualified_name());
+                    graph.add_edge(index, callee_index, is_virtual);
+                }
+                for callee in callees {
+                    let (callee_index, is_new) = graph.get_or_add_func_node(&callee);
+                    graph.add_edge(index, callee_index, is_virtual);
+                    if is_new && !graph.nodes[callee_index].is_decl {
+                        open_nodes.push_back((callee_index, callee, current_depth + 1));
+                    }
+                }
+            }
+        }
+
+        graph
+    }
+
+    fn get_or_add_func_node(&mut self, func: &FunctionOccurrence) -> (usize, bool) {
+        let func_structure = func.func.borrow();
+        let key = DotNodeKey::Func(func_structure.get_func_type(), func_structure.get_id());
+        if let Some(index) = self.nodes.iter().position(|node| node.key == key) {
+            return (index, false);
+        }
+
+        self.nodes.push(DotNode {
+            key,
+            label: func_structure.get_qualified_name().to_string(),
+            file: Some(func.file.clone()),
+            class_name: get_receiver_class_name(&*func_structure).map(|name| name.to_string()),
+            is_root: false,
+            is_decl: matches!(
+                func_structure.get_func_type(),
+                Some(FuncMentionType::FuncDecl) | Some(FuncMentionType::VirtualFuncDecl)
+            ),
+        });
+        (self.nodes.len() - 1, true)
+    }
+
+    fn get_or_add_unresolved_node(&mut self, qualified_name: &str) -> usize {
+        let key = DotNodeKey::Unresolved(qualified_name.to_string());
+        if let Some(index) = self.nodes.iter().position(|node| node.key == key) {
+            return index;
+        }
+
+        self.nodes.push(DotNode {
+            key,
+            label: qualified_name.to_string(),
+            file: None,
+            class_name: None,
+            is_root: false,
+            is_decl: false,
+        });
+        self.nodes.len() - 1
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, is_virtual: bool) {
+        let edge = DotEdge {
+            from,
+            to,
+            is_virtual,
+        };
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph call_graph {\n    node [shape=box];\n");
+
+        let mut files: Vec<&str> = Vec::new();
+        for node in self.nodes.iter() {
+            if let Some(file) = node.file.as_deref() {
+                if !files.contains(&file) {
+                    files.push(file);
+                }
+            }
+        }
+
+        for (file_index, file) in files.iter().enumerate() {
+            dot.push_str(&format!("    subgraph cluster_{} {{\n", file_index));
+            dot.push_str(&format!("        label=\"{}\";\n", escape(file)));
+
+            let file_nodes: Vec<usize> = (0..self.nodes.len())
+                .filter(|index| self.nodes[*index].file.as_deref() == Some(file))
+                .collect();
+            let mut class_names: Vec<&str> = Vec::new();
+            for index in file_nodes.iter() {
+                match self.nodes[*index].class_name.as_deref() {
+                    Some(class_name) => {
+                        if !class_names.contains(&class_name) {
+                            class_names.push(class_name);
+                        }
+                    }
+                    None => self.push_node(&mut dot, *index, 2),
+                }
+            }
+
+            for (class_index, class_name) in class_names.iter().enumerate() {
+                dot.push_str(&format!(
+                    "        subgraph cluster_{}_{} {{\n",
+                    file_index, class_index
+                ));
+                dot.push_str(&format!("            label=\"{}\";\n", escape(class_name)));
+                for index in file_nodes.iter() {
+                    if self.nodes[*index].class_name.as_deref() == Some(class_name) {
+                        self.push_node(&mut dot, *index, 3);
+                    }
+                }
+                dot.push_str("        }\n");
+            }
+
+            dot.push_str("    }\n");
+        }
+
+        for index in 0..self.nodes.len() {
+            if self.nodes[index].file.is_none() {
+                self.push_node(&mut dot, index, 1);
+            }
+        }
+
+        for edge in self.edges.iter() {
+            let style = if edge.is_virtual {
+                " [style=dashed]"
+            } else {
+                ""
+            };
+            dot.push_str(&format!("    n{} -> n{}{};\n", edge.from, edge.to, style));
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn push_node(&self, dot: &mut String, index: usize, indent: usize) {
+        let node = &self.nodes[index];
+
+        let mut attributes = vec![format!("label=\"{}\"", escape(&node.label))];
+        if node.is_root {
+            attributes.push("style=bold".to_string());
+        } else if node.is_decl {
+            attributes.push("style=dashed".to_string());
+        } else if node.file.is_none() {
+            attributes.push("style=dotted".to_string());
+        }
+
+        dot.push_str(&format!(
+            "{}n{} [{}];\n",
+            "    ".repeat(indent),
+            index,
+            attributes.join(", ")
+        ));
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        call_graph::{
+            data_structure::{
+                helper::func_creation_args::FuncCreationArgs, MainDeclPosition, VirtualFuncBasics,
+            },
+            tests::test_database::{add_func_call, add_func_decl, add_func_impl},
+        },
+        location::range::Range,
+    };
+
+    use super::*;
+
+    fn create_dot_graph_test_database() -> (DatabaseSqlite, FunctionOccurrence) {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+
+        let hpp_file = database_sqlite.get_or_add_hpp_file("header.h");
+        let printf_decl = add_func_decl(
+            &hpp_file,
+            "printf",
+            "int (const char *, ...)",
+            Range::create(1, 1, 1, 30),
+        );
+
+        let cpp_file = database_sqlite.get_or_add_cpp_file("file.cpp");
+        let cpp_class = cpp_file.borrow_mut().add_class("Foo");
+        let virtual_impl = cpp_class
+            .borrow_mut()
+            .add_virtual_func_impl(FuncCreationArgs::new(
+                "bar",
+                "Foo::bar 'void ()'",
+                Some("Foo::bar 'void ()'".to_string()),
+                "void ()",
+                Range::create(3, 5, 6, 6),
+            ));
+        add_func_call(&virtual_impl, &printf_decl, Range::create(5, 9, 5, 22));
+        virtual_impl
+            .borrow_mut()
+            .add_func_call(&FuncCreationArgs::new(
+                "puts",
+                "puts 'int (const char *)'",
+                None,
+                "int (const char *)",
+                Range::create(4, 9, 4, 20),
+            ));
+
+        let main_impl = add_func_impl(&cpp_file, "main", "int ()", Range::create(8, 1, 12, 2));
+        let call_args = virtual_impl
+            .borrow()
+            .convert_virtual_func2virtual_func_creation_args4call(&Range::create(10, 5, 10, 14));
+        main_impl.borrow_mut().add_virtual_func_call(&call_args);
+        database_sqlite.link_func_calls();
+
+        let main_occurrence = database_sqlite.get_func_impls(&*main_impl.borrow())[0].clone();
+        (database_sqlite, main_occurrence)
+    }
+
+    #[test]
+    fn test_dot_graph_from_root() {
+        let (database_sqlite, main_occurrence) = create_dot_graph_test_database();
+
+        let dot_graph = DotGraph::from_database(
+            &database_sqlite,
+            &[main_occurrence],
+            None,
+            VirtualCallTargets::AllOverrides,
+        );
+
+        assert_eq!(
+            dot_graph.to_dot(),
+            "digraph call_graph {
+    node [shape=box];
+    subgraph cluster_0 {
+        label=\"file.cpp\";
+        n0 [label=\"main 'int ()'\", style=bold];
+        subgraph cluster_0_0 {
+            label=\"Foo\";
+            n1 [label=\"Foo::bar 'void ()'\"];
+        }
+    }
+    subgraph cluster_1 {
+        label=\"header.h\";
+        n2 [label=\"printf 'int (const char *, ...)'\", style=dashed];
+    }
+    n3 [label=\"puts 'int (const char *)'\", style=dotted];
+    n0 -> n1 [style=dashed];
+    n1 -> n2;
+    n1 -> n3;
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_dot_graph_with_depth() {
+        let (database_sqlite, main_occurrence) = create_dot_graph_test_database();
+
+        let dot_graph = DotGraph::from_database(
+            &database_sqlite,
+            &[main_occurrence.clone()],
+            Some(0),
+            VirtualCallTargets::AllOverrides,
+        );
+        assert_eq!(dot_graph.nodes.len(), 1);
+        assert_eq!(dot_graph.edges.len(), 0);
+
+        let dot_graph = DotGraph::from_database(
+            &database_sqlite,
+            &[main_occurrence],
+            Some(1),
+            VirtualCallTargets::AllOverrides,
+        );
+        assert_eq!(dot_graph.nodes.len(), 2);
+        assert_eq!(dot_graph.edges.len(), 1);
+        assert!(dot_graph.edges[0].is_virtual);
+    }
+
+    #[test]
+    fn test_dot_graph_without_root() {
+        let (database_sqlite, _) = create_dot_graph_test_database();
+
+        let dot_graph =
+            DotGraph::from_database(&database_sqlite, &[], None, VirtualCallTargets::Static);
+
+        assert_eq!(dot_graph.nodes.len(), 4);
+        assert!(dot_graph.nodes.iter().all(|node| !node.is_root));
+        assert_eq!(dot_graph.edges.len(), 3);
+    }
+}
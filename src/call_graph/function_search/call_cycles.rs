@@ -0,0 +1,325 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use crate::call_graph::{
+    data_structure::{
+        func_structure::{FuncMentionType, FuncStructure},
+        FuncBasics, FuncImplBasics,
+    },
+    database::database_sqlite::DatabaseSqlite,
+};
+
+use super::{function_occurrence::FunctionOccurrence, virtual_call_targets::VirtualCallTargets};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleCall {
+    pub caller: FunctionOccurrence,
+    pub call: Rc<RefCell<FuncStructure>>,
+    pub callee: FunctionOccurrence,
+}
+
+// A set of functions which can all reach each other through calls, either a single directly
+// recursive function or multiple mutually recursive functions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallCycle {
+    pub funcs: Vec<FunctionOccurrence>,
+    // All calls between the functions of the cycle.
+    pub calls: Vec<CycleCall>,
+}
+
+struct CallGraphNode {
+    func_impl: FunctionOccurrence,
+    calls: Vec<(Rc<RefCell<FuncStructure>>, usize)>,
+}
+
+// Finds all cycles as strongly connected components of the call graph between function
+// implementations. Calls are resolved via the links created by `DatabaseSqlite::link_func_calls`.
+pub fn get_call_cycles(
+    db: &DatabaseSqlite,
+    virtual_call_targets: VirtualCallTargets,
+) -> Vec<CallCycle> {
+    let nodes = create_call_graph(db, virtual_call_targets);
+
+    let mut cycles = Vec::new();
+    for component in get_strongly_connected_components(&nodes) {
+        let mut calls = Vec::new();
+        for node_index in component.iter() {
+            let node = &nodes[*node_index];
+            for (call, callee_index) in node.calls.iter() {
+                if component.contains(callee_index) {
+                    calls.push(CycleCall {
+                        caller: node.func_impl.clone(),
+                        call: call.clone(),
+                        callee: nodes[*callee_index].func_impl.clone(),
+                    });
+                }
+            }
+        }
+
+        // A single function without a call to itself is no cycle.
+        if calls.is_empty() {
+            continue;
+        }
+        cycles.push(CallCycle {
+            funcs: component
+                .iter()
+                .map(|node_index| nodes[*node_index].func_impl.clone())
+                .collect(),
+            calls,
+        });
+    }
+
+    cycles
+}
+
+fn create_call_graph(
+    db: &DatabaseSqlite,
+    virtual_call_targets: VirtualCallTargets,
+) -> Vec<CallGraphNode> {
+    let func_impls = db.get_all_func_impls();
+    let node_indices: HashMap<(Option<FuncMentionType>, u64), usize> = func_impls
+        .iter()
+        .enumerate()
+        .map(|(index, func_impl)| (get_func_key(&func_impl.func), index))
+        .collect();
+
+    let mut nodes = Vec::new();
+    for func_impl in func_impls {
+        let mut calls = func_impl.func.borrow_mut().get_func_calls().clone();
+        calls.append(&mut func_impl.func.borrow_mut().get_virtual_func_calls().clone());
+
+        let mut resolved_calls = Vec::new();
+        for call in calls {
+            let callees = if call.borrow().get_func_type() == Some(FuncMentionType::VirtualFuncCall)
+            {
+                db.get_virtual_func_call_targets(&call.borrow(), virtual_call_targets)
+            } else {
+                db.get_func_impls_or_one_decl(&call.borrow())
+            };
+            for callee in callees {
+                if let Some(callee_index) = node_indices.get(&get_func_key(&callee.func)) {
+                    resolved_calls.push((call.clone(), *callee_index));
+                }
+            }
+        }
+
+        nodes.push(CallGraphNode {
+            func_impl,
+            calls: resolved_calls,
+        });
+    }
+
+    nodes
+}
+
+struct TarjanState {
+    next_index: usize,
+    indices: Vec<Option<usize>>,
+    low_links: Vec<usize>,
+    stack: Vec<usize>,
+    on_stack: Vec<bool>,
+    components: Vec<Vec<usize>>,
+}
+
+// Tarjan's algorithm, the components are returned in the order their roots were finished.
+fn get_strongly_connected_components(nodes: &[CallGraphNode]) -> Vec<Vec<usize>> {
+    let mut state = TarjanState {
+        next_index: 0,
+        indices: vec![None; nodes.len()],
+        low_links: vec![0; nodes.len()],
+        stack: Vec::new(),
+        on_stack: vec![false; nodes.len()],
+        components: Vec::new(),
+    };
+
+    for node_index in 0..nodes.len() {
+        if state.indices[node_index].is_none() {
+            visit_node(nodes, node_index, &mut state);
+        }
+    }
+
+    state.components
+}
+
+fn visit_node(nodes: &[CallGraphNode], node_index: usize, state: &mut TarjanState) {
+    state.indices[node_index] = Some(state.next_index);
+    state.low_links[node_index] = state.next_index;
+    state.next_index += 1;
+    state.stack.push(node_index);
+    state.on_stack[node_index] = true;
+
+    for (_, callee_index) in nodes[node_index].calls.iter() {
+        match state.indices[*callee_index] {
+            None => {
+                visit_node(nodes, *callee_index, state);
+                state.low_links[node_index] =
+                    state.low_links[node_index].min(state.low_links[*callee_index]);
+            }
+            Some(callee_index_value) if state.on_stack[*callee_index] => {
+                state.low_links[node_index] = state.low_links[node_index].min(callee_index_value);
+            }
+            Some(_) => {}
+        }
+    }
+
+    if Some(state.low_links[node_index]) == state.indices[node_index] {
+        let mut component = Vec::new();
+        while let Some(stack_index) = state.stack.pop() {
+            state.on_stack[stack_index] = false;
+            component.push(stack_index);
+            if stack_index == node_index {
+                break;
+            }
+        }
+        component.reverse();
+        state.components.push(component);
+    }
+}
+
+fn get_func_key(func: &Rc<RefCell<FuncStructure>>) -> (Option<FuncMentionType>, u64) {
+    let func = func.borrow();
+    (func.get_func_type(), func.get_id())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        call_graph::data_structure::{
+            helper::func_creation_args::FuncCreationArgs, MainDeclPosition,
+        },
+        location::range::Range,
+    };
+
+    use super::*;
+
+    fn get_qualified_names(funcs: &[FunctionOccurrence]) -> Vec<String> {
+        funcs
+            .iter()
+            .map(|func| func.func.borrow().get_qualified_name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_get_call_cycles() {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+        let even_cpp_file = database_sqlite.get_or_add_cpp_file("even.cpp");
+        let odd_cpp_file = database_sqlite.get_or_add_cpp_file("odd.cpp");
+
+        let factorial_impl = even_cpp_file
+            .borrow_mut()
+            .add_func_impl(FuncCreationArgs::new(
+                "factorial",
+                "factorial 'int (int)'",
+                None,
+                "int (int)",
+                Range::create(1, 1, 4, 2),
+            ));
+        let is_even_impl = even_cpp_file
+            .borrow_mut()
+            .add_func_impl(FuncCreationArgs::new(
+                "is_even",
+                "is_even 'bool (int)'",
+                None,
+                "bool (int)",
+                Range::create(6, 1, 8, 2),
+            ));
+        let is_odd_impl = odd_cpp_file
+            .borrow_mut()
+            .add_func_impl(FuncCreationArgs::new(
+                "is_odd",
+                "is_odd 'bool (int)'",
+                None,
+                "bool (int)",
+                Range::create(1, 1, 3, 2),
+            ));
+        let main_impl = odd_cpp_file
+            .borrow_mut()
+            .add_func_impl(FuncCreationArgs::new(
+                "main",
+                "main 'int (int, char **)'",
+                None,
+                "int (int, char **)",
+                Range::create(5, 1, 8, 2),
+            ));
+
+        let call_args = factorial_impl
+            .borrow()
+            .convert_func2func_creation_args4call(&Range::create(3, 21, 3, 36));
+        factorial_impl.borrow_mut().add_func_call(&call_args);
+        let call_args = is_odd_impl
+            .borrow()
+            .convert_func2func_creation_args4call(&Range::create(7, 25, 7, 37));
+        is_even_impl.borrow_mut().add_func_call(&call_args);
+        let call_args = is_even_impl
+            .borrow()
+            .convert_func2func_creation_args4call(&Range::create(2, 25, 2, 38));
+        is_odd_impl.borrow_mut().add_func_call(&call_args);
+        let call_args = factorial_impl
+            .borrow()
+            .convert_func2func_creation_args4call(&Range::create(6, 5, 6, 17));
+        main_impl.borrow_mut().add_func_call(&call_args);
+        let call_args = is_even_impl
+            .borrow()
+            .convert_func2func_creation_args4call(&Range::create(7, 5, 7, 15));
+        main_impl.borrow_mut().add_func_call(&call_args);
+        database_sqlite.link_func_calls();
+
+        let cycles = get_call_cycles(&database_sqlite, VirtualCallTargets::AllOverrides);
+        assert_eq!(cycles.len(), 2);
+
+        assert_eq!(
+            get_qualified_names(&cycles[0].funcs),
+            vec!["factorial 'int (int)'"]
+        );
+        assert_eq!(cycles[0].calls.len(), 1);
+        assert_eq!(
+            cycles[0].calls[0].call.borrow().get_range(),
+            &Range::create(3, 21, 3, 36)
+        );
+
+        assert_eq!(
+            get_qualified_names(&cycles[1].funcs),
+            vec!["is_even 'bool (int)'", "is_odd 'bool (int)'"]
+        );
+        assert_eq!(cycles[1].funcs[1].file, "odd.cpp");
+        assert_eq!(cycles[1].calls.len(), 2);
+        assert_eq!(cycles[1].calls[0].caller.file, "even.cpp");
+        assert_eq!(
+            cycles[1].calls[0].call.borrow().get_range(),
+            &Range::create(7, 25, 7, 37)
+        );
+        assert_eq!(cycles[1].calls[1].caller.file, "odd.cpp");
+        assert_eq!(
+            cycles[1].calls[1].call.borrow().get_range(),
+            &Range::create(2, 25, 2, 38)
+        );
+    }
+
+    #[test]
+    fn test_get_call_cycles_without_recursion() {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+        let cpp_file = database_sqlite.get_or_add_cpp_file("file.cpp");
+
+        let add_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs::new(
+            "add",
+            "add 'int (int, int)'",
+            None,
+            "int (int, int)",
+            Range::create(1, 1, 3, 2),
+        ));
+        let main_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs::new(
+            "main",
+            "main 'int (int, char **)'",
+            None,
+            "int (int, char **)",
+            Range::create(5, 1, 8, 2),
+        ));
+
+        let call_args = add_impl
+            .borrow()
+            .convert_func2func_creation_args4call(&Range::create(6, 12, 6, 20));
+        main_impl.borrow_mut().add_func_call(&call_args);
+        database_sqlite.link_func_calls();
+
+        assert!(get_call_cycles(&database_sqlite, VirtualCallTargets::AllOverrides).is_empty());
+    }
+}
@@ -0,0 +1,300 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::call_graph::{
+    data_structure::{
+        func_structure::{FuncMentionType, FuncStructure},
+        FuncBasics, FuncImplBasics,
+    },
+    database::database_sqlite::DatabaseSqlite,
+};
+
+use super::{function_occurrence::FunctionOccurrence, virtual_call_targets::VirtualCallTargets};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallTreeNode {
+    pub call: Rc<RefCell<FuncStructure>>,
+    pub callee: Option<FunctionOccurrence>,
+    pub is_recursive: bool,
+    pub callees: Vec<CallTreeNode>,
+}
+
+impl CallTreeNode {
+    pub fn is_virtual_call(&self) -> bool {
+        self.call.borrow().get_func_type() == Some(FuncMentionType::VirtualFuncCall)
+    }
+
+    pub fn is_callee_decl(&self) -> bool {
+        match &self.callee {
+            Some(callee) => is_func_decl(&callee.func),
+            None => false,
+        }
+    }
+}
+
+// Walks all function and virtual function calls starting at the given function implementation.
+// A depth of `None` walks the whole downstream tree, recursive calls are never expanded twice.
+// Calls are resolved via the links created by `DatabaseSqlite::link_func_calls`.
+pub fn get_call_tree(
+    db: &DatabaseSqlite,
+    func_impl: &FunctionOccurrence,
+    depth: Option<usize>,
+    virtual_call_targets: VirtualCallTargets,
+) -> Vec<CallTreeNode> {
+    let mut current_path = vec![get_func_key(&func_impl.func)];
+    get_call_tree_inner(
+        db,
+        func_impl,
+        depth,
+        virtual_call_targets,
+        &mut current_path,
+    )
+}
+
+fn get_call_tree_inner(
+    db: &DatabaseSqlite,
+    func_impl: &FunctionOccurrence,
+    depth: Option<usize>,
+    virtual_call_targets: VirtualCallTargets,
+    current_path: &mut Vec<(Option<FuncMentionType>, u64)>,
+) -> Vec<CallTreeNode> {
+    if depth == Some(0) {
+        return Vec::new();
+    }
+    let next_depth = depth.map(|depth| depth - 1);
+
+    let mut calls = func_impl.func.borrow_mut().get_func_calls().clone();
+    calls.append(&mut func_impl.func.borrow_mut().get_virtual_func_calls().clone());
+
+    let mut nodes = Vec::new();
+    for call in calls {
+        let callees = if call.borrow().get_func_type() == Some(FuncMentionType::VirtualFuncCall) {
+            db.get_virtual_func_call_targets(&call.borrow(), virtual_call_targets)
+        } else {
+            db.get_func_impls_or_one_decl(&call.borrow())
+        };
+
+        if callees.is_empty() {
+            nodes.push(CallTreeNode {
+                call: call.clone(),
+                callee: None,
+                is_recursive: false,
+                callees: Vec::new(),
+            });
+            continue;
+        }
+
+        for callee in callees {
+            let callee_key = get_func_key(&callee.func);
+            let is_recursive = current_path.contains(&callee_key);
+
+            let callees = if is_recursive || is_func_decl(&callee.func) {
+                Vec::new()
+            } else {
+                current_path.push(callee_key);
+                let callees = get_call_tree_inner(
+                    db,
+                    &callee,
+                    next_depth,
+                    virtual_call_targets,
+                    current_path,
+                );
+                current_path.pop();
+                callees
+            };
+
+            nodes.push(CallTreeNode {
+                call: call.clone(),
+                callee: Some(callee),
+                is_recursive,
+                callees,
+            });
+        }
+    }
+
+    nodes
+}
+
+fn is_func_decl(func: &Rc<RefCell<FuncStructure>>) -> bool {
+    matches!(
+        func.borrow().get_func_type(),
+        Some(FuncMentionType::FuncDecl) | Some(FuncMentionType::VirtualFuncDecl)
+    )
+}
+
+fn get_func_key(func: &Rc<RefCell<FuncStructure>>) -> (Option<FuncMentionType>, u64) {
+    let func = func.borrow();
+    (func.get_func_type(), func.get_id())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        call_graph::{
+            data_structure::{
+                helper::func_creation_args::FuncCreationArgs, FuncImplBasics, MainDeclPosition,
+                VirtualFuncBasics,
+            },
+            tests::test_database::{add_func_call, add_func_decl, add_func_impl},
+        },
+        location::range::Range,
+    };
+
+    use super::*;
+
+    fn create_call_tree_test_database() -> (DatabaseSqlite, FunctionOccurrence) {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+        let cpp_file = database_sqlite.get_or_add_cpp_file("file.cpp");
+
+        let mult_impl = add_func_impl(
+            &cpp_file,
+            "mult",
+            "int (int, int)",
+            Range::create(1, 1, 4, 2),
+        );
+        let add_impl = add_func_impl(
+            &cpp_file,
+            "add",
+            "int (int, int)",
+            Range::create(6, 1, 9, 2),
+        );
+        let printf_decl = add_func_decl(
+            &cpp_file,
+            "printf",
+            "int (const char *, ...)",
+            Range::create(10, 1, 10, 30),
+        );
+        let main_impl = add_func_impl(
+            &cpp_file,
+            "main",
+            "int (int, char **)",
+            Range::create(11, 1, 15, 2),
+        );
+
+        add_func_call(&mult_impl, &mult_impl, Range::create(3, 12, 3, 25));
+        add_func_call(&add_impl, &mult_impl, Range::create(8, 12, 8, 22));
+        add_func_call(&main_impl, &add_impl, Range::create(13, 12, 13, 21));
+        add_func_call(&main_impl, &printf_decl, Range::create(14, 5, 14, 20));
+        database_sqlite.link_func_calls();
+
+        let main_occurrence = database_sqlite.get_func_impls(&*main_impl.borrow())[0].clone();
+        (database_sqlite, main_occurrence)
+    }
+
+    #[test]
+    fn test_get_call_tree() {
+        let (database_sqlite, main_occurrence) = create_call_tree_test_database();
+
+        let call_tree = get_call_tree(
+            &database_sqlite,
+            &main_occurrence,
+            None,
+            VirtualCallTargets::Static,
+        );
+        assert_eq!(call_tree.len(), 2);
+
+        assert_eq!(call_tree[0].call.borrow().get_name(), "add");
+        assert_eq!(
+            call_tree[0].call.borrow().get_range(),
+            &Range::create(13, 12, 13, 21)
+        );
+        assert_eq!(call_tree[0].callee.as_ref().unwrap().file, "file.cpp");
+        assert!(!call_tree[0].is_callee_decl());
+        assert!(!call_tree[0].is_recursive);
+        assert!(!call_tree[0].is_virtual_call());
+        assert_eq!(call_tree[0].callees.len(), 1);
+
+        let mult_node = &call_tree[0].callees[0];
+        assert_eq!(mult_node.call.borrow().get_name(), "mult");
+        assert!(!mult_node.is_recursive);
+        assert_eq!(mult_node.callees.len(), 1);
+        assert_eq!(mult_node.callees[0].call.borrow().get_name(), "mult");
+        assert!(mult_node.callees[0].is_recursive);
+        assert_eq!(mult_node.callees[0].callees.len(), 0);
+
+        assert_eq!(call_tree[1].call.borrow().get_name(), "printf");
+        assert!(call_tree[1].is_callee_decl());
+        assert_eq!(
+            call_tree[1]
+                .callee
+                .as_ref()
+                .unwrap()
+                .func
+                .borrow()
+                .get_range(),
+            &Range::create(10, 1, 10, 30)
+        );
+        assert_eq!(call_tree[1].callees.len(), 0);
+    }
+
+    #[test]
+    fn test_get_call_tree_with_depth() {
+        let (database_sqlite, main_occurrence) = create_call_tree_test_database();
+
+        let call_tree = get_call_tree(
+            &database_sqlite,
+            &main_occurrence,
+            Some(0),
+            VirtualCallTargets::Static,
+        );
+        assert_eq!(call_tree.len(), 0);
+
+        let call_tree = get_call_tree(
+            &database_sqlite,
+            &main_occurrence,
+            Some(1),
+            VirtualCallTargets::Static,
+        );
+        assert_eq!(call_tree.len(), 2);
+        assert_eq!(call_tree[0].callees.len(), 0);
+
+        let call_tree = get_call_tree(
+            &database_sqlite,
+            &main_occurrence,
+            Some(2),
+            VirtualCallTargets::Static,
+        );
+        assert_eq!(call_tree[0].callees.len(), 1);
+        assert_eq!(call_tree[0].callees[0].callees.len(), 0);
+    }
+
+    #[test]
+    fn test_get_call_tree_virtual_call() {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+        let cpp_file = database_sqlite.get_or_add_cpp_file("file.cpp");
+        let cpp_class = cpp_file.borrow_mut().get_or_add_class("Foo");
+
+        let virtual_impl = cpp_class
+            .borrow_mut()
+            .add_virtual_func_impl(FuncCreationArgs::new(
+                "bar",
+                "Foo::bar 'void ()'",
+                Some("Foo::bar 'void ()'".to_string()),
+                "void ()",
+                Range::create(3, 5, 3, 20),
+            ));
+        let main_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs::new(
+            "main",
+            "main 'int ()'",
+            None,
+            "int ()",
+            Range::create(6, 1, 10, 2),
+        ));
+        let call_args = virtual_impl
+            .borrow()
+            .convert_virtual_func2virtual_func_creation_args4call(&Range::create(8, 5, 8, 14));
+        main_impl.borrow_mut().add_virtual_func_call(&call_args);
+        database_sqlite.link_func_calls();
+
+        let main_occurrence = database_sqlite.get_func_impls(&*main_impl.borrow())[0].clone();
+        let call_tree = get_call_tree(
+            &database_sqlite,
+            &main_occurrence,
+            None,
+            VirtualCallTargets::Static,
+        );
+
+        assert_eq!(call_tree.len(), 1);
+        assert!(call_tree[0].is_virtual_call());
+        assert_eq!(call_tree[0].callee.as_ref().unwrap().func, virtual_impl);
+    }
+}
@@ -0,0 +1,357 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::call_graph::{
+    data_structure::{
+        func_structure::FuncMentionType, FuncBasics, FuncImplBasics, MainDeclPosition,
+    },
+    database::database_sqlite::DatabaseSqlite,
+};
+
+use super::{
+    function_occurrence::FunctionOccurrence, gtest_tests::get_gtest_tests,
+    virtual_call_targets::VirtualCallTargets,
+};
+
+pub fn get_main_func_impls(db: &DatabaseSqlite) -> Vec<FunctionOccurrence> {
+    db.get_all_func_impls()
+        .into_iter()
+        .filter(|func_impl| {
+            let func = func_impl.func.borrow();
+            func.get_func_type() == Some(FuncMentionType::FuncImpl) && func.get_name() == "main"
+        })
+        .collect()
+}
+
+pub fn get_gtest_test_func_impls(db: &DatabaseSqlite) -> Vec<FunctionOccurrence> {
+    get_gtest_tests(db)
+        .into_iter()
+        .map(|test| test.test_body)
+        .collect()
+}
+
+// Functions declared or implemented in a header are treated as exported, as they can be used
+// by code which isn't part of the database.
+pub fn get_exported_func_impls(db: &DatabaseSqlite) -> Vec<FunctionOccurrence> {
+    let mut exported_names = HashSet::new();
+    for hpp_file in db.get_hpp_files() {
+        collect_qualified_names(&mut *hpp_file.borrow_mut(), &mut exported_names);
+    }
+
+    db.get_all_func_impls()
+        .into_iter()
+        .filter(|func_impl| exported_names.contains(func_impl.func.borrow().get_qualified_name()))
+        .collect()
+}
+
+fn collect_qualified_names(
+    main_decl_position: &mut dyn MainDeclPosition,
+    qualified_names: &mut HashSet<String>,
+) {
+    let mut funcs = main_decl_position.get_func_decls().clone();
+    funcs.append(&mut main_decl_position.get_func_impls().clone());
+    funcs.append(&mut main_decl_position.get_virtual_func_impls().clone());
+    for func in funcs {
+        qualified_names.insert(func.borrow().get_qualified_name().to_string());
+    }
+
+    for cpp_class in main_decl_position.get_classes().clone() {
+        for virtual_func_decl in cpp_class.borrow_mut().get_virtual_func_decls().iter() {
+            qualified_names.insert(virtual_func_decl.borrow().get_qualified_name().to_string());
+        }
+        collect_qualified_names(&mut *cpp_class.borrow_mut(), qualified_names);
+    }
+}
+
+// Returns all function implementations which can't be reached from the roots through calls,
+// virtual call targets or references. Calls are resolved via the links created by
+// `DatabaseSqlite::link_func_calls`, unresolved calls of templates via the calls they were bound
+// to. Template patterns are never called themselves, so they aren't reported.
+pub fn get_dead_func_impls(
+    db: &DatabaseSqlite,
+    roots: Vec<FunctionOccurrence>,
+    virtual_call_targets: VirtualCallTargets,
+) -> Vec<FunctionOccurrence> {
+    let mut reached_funcs: HashSet<(Option<FuncMentionType>, u64)> = HashSet::new();
+    let mut open_funcs: VecDeque<FunctionOccurrence> = VecDeque::from(roots);
+
+    while let Some(func_impl) = open_funcs.pop_front() {
+        let func_key = get_func_key(&func_impl);
+        if reached_funcs.contains(&func_key) {
+            continue;
+        }
+        reached_funcs.insert(func_key);
+
+        for callee in get_reachable_funcs(db, &func_impl, virtual_call_targets) {
+            if !reached_funcs.contains(&get_func_key(&callee)) {
+                open_funcs.push_back(callee);
+            }
+        }
+    }
+
+    db.get_all_func_impls()
+        .into_iter()
+        .filter(|func_impl| {
+            !reached_funcs.contains(&get_func_key(func_impl))
+                && !db.is_template_pattern(&func_impl.func.borrow())
+        })
+        .collect()
+}
+
+fn get_reachable_funcs(
+    db: &DatabaseSqlite,
+    func_impl: &FunctionOccurrence,
+    virtual_call_targets: VirtualCallTargets,
+) -> Vec<FunctionOccurrence> {
+    let mut reachable_funcs = Vec::new();
+
+    let mut func_calls = func_impl.func.borrow_mut().get_func_calls().clone();
+    let unresolved_func_calls = func_impl
+        .func
+        .borrow_mut()
+        .get_unresolved_func_calls()
+        .clone();
+    for unresolved_func_call in unresolved_func_calls {
+        func_calls.append(&mut db.get_bound_func_calls(&unresolved_func_call.borrow()));
+    }
+    for func_call in func_calls {
+        reachable_funcs.append(&mut db.get_func_impls_or_one_decl(&func_call.borrow()));
+    }
+    let virtual_func_calls = func_impl.func.borrow_mut().get_virtual_func_calls().clone();
+    for virtual_func_call in virtual_func_calls {
+        reachable_funcs.append(
+            &mut db
+                .get_virtual_func_call_targets(&virtual_func_call.borrow(), virtual_call_targets),
+        );
+    }
+    // A function whose address is taken may be called from anywhere.
+    let func_refs = func_impl.func.borrow_mut().get_func_refs().clone();
+    for func_ref in func_refs {
+        reachable_funcs.append(&mut db.get_func_impls(&*func_ref.borrow()));
+    }
+
+    reachable_funcs
+}
+
+fn get_func_key(func: &FunctionOccurrence) -> (Option<FuncMentionType>, u64) {
+    let func = func.func.borrow();
+    (func.get_func_type(), func.get_id())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        call_graph::{
+            data_structure::{
+                func_structure::FuncStructure, helper::func_creation_args::FuncCreationArgs,
+            },
+            tests::test_database::{add_func_call, add_func_decl, add_func_impl},
+        },
+        location::range::Range,
+    };
+
+    use super::*;
+
+    fn create_dead_code_test_database() -> DatabaseSqlite {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+        let hpp_file = database_sqlite.get_or_add_hpp_file("math.h");
+        let cpp_file = database_sqlite.get_or_add_cpp_file("main.cpp");
+
+        add_func_decl(
+            &hpp_file,
+            "sub",
+            "int (int, int)",
+            Range::create(1, 1, 1, 20),
+        );
+        let mult_impl = add_func_impl(
+            &cpp_file,
+            "mult",
+            "int (int, int)",
+            Range::create(1, 1, 4, 2),
+        );
+        let add_impl = add_func_impl(
+            &cpp_file,
+            "add",
+            "int (int, int)",
+            Range::create(6, 1, 9, 2),
+        );
+        add_func_impl(
+            &cpp_file,
+            "sub",
+            "int (int, int)",
+            Range::create(10, 1, 12, 2),
+        );
+        add_func_impl(&cpp_file, "unused", "void ()", Range::create(14, 1, 15, 2));
+        let callback_impl = add_func_impl(
+            &cpp_file,
+            "callback",
+            "void (int)",
+            Range::create(17, 1, 18, 2),
+        );
+        let main_impl = add_func_impl(
+            &cpp_file,
+            "main",
+            "int (int, char **)",
+            Range::create(20, 1, 24, 2),
+        );
+
+        add_func_call(&add_impl, &mult_impl, Range::create(8, 12, 8, 22));
+        add_func_call(&main_impl, &add_impl, Range::create(21, 12, 21, 21));
+        let ref_args = callback_impl
+            .borrow()
+            .convert_func2func_creation_args4call(&Range::create(22, 15, 22, 23));
+        main_impl.borrow_mut().add_func_ref(&ref_args);
+        database_sqlite.link_func_calls();
+
+        database_sqlite
+    }
+
+    fn get_qualified_names(funcs: &[FunctionOccurrence]) -> Vec<String> {
+        funcs
+            .iter()
+            .map(|func| func.func.borrow().get_qualified_name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_get_dead_func_impls_from_main() {
+        let database_sqlite = create_dead_code_test_database();
+
+        let roots = get_main_func_impls(&database_sqlite);
+        assert_eq!(
+            get_qualified_names(&roots),
+            vec!["main 'int (int, char **)'"]
+        );
+
+        let dead_funcs =
+            get_dead_func_impls(&database_sqlite, roots, VirtualCallTargets::AllOverrides);
+        assert_eq!(
+            get_qualified_names(&dead_funcs),
+            vec!["sub 'int (int, int)'", "unused 'void ()'"]
+        );
+        assert_eq!(dead_funcs[1].file, "main.cpp");
+        assert_eq!(
+            dead_funcs[1].func.borrow().get_range(),
+            &Range::create(14, 1, 15, 2)
+        );
+    }
+
+    #[test]
+    fn test_get_dead_func_impls_with_exported_funcs() {
+        let database_sqlite = create_dead_code_test_database();
+
+        let mut roots = get_main_func_impls(&database_sqlite);
+        let mut exported_funcs = get_exported_func_impls(&database_sqlite);
+        assert_eq!(
+            get_qualified_names(&exported_funcs),
+            vec!["sub 'int (int, int)'"]
+        );
+        roots.append(&mut exported_funcs);
+
+        let dead_funcs =
+            get_dead_func_impls(&database_sqlite, roots, VirtualCallTargets::AllOverrides);
+        assert_eq!(get_qualified_names(&dead_funcs), vec!["unused 'void ()'"]);
+    }
+
+    fn create_dead_code_template_test_database() -> DatabaseSqlite {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+        let db_connection = database_sqlite.get_db_connection().unwrap();
+        let cpp_file = database_sqlite.get_or_add_cpp_file("main.cpp");
+
+        let draw_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs::new(
+            "draw",
+            "draw 'int (Circle &)'",
+            None,
+            "int (Circle &)",
+            Range::create(1, 1, 4, 2),
+        ));
+        let pattern_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs::new(
+            "render",
+            "render<T> 'int (T &)'",
+            None,
+            "int (T &)",
+            Range::create(6, 1, 9, 2),
+        ));
+        let instantiation_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs::new(
+            "render",
+            "render<Circle> 'int (Circle &)'",
+            None,
+            "int (Circle &)",
+            Range::create(6, 1, 9, 2),
+        ));
+        cpp_file.borrow_mut().add_func_impl(FuncCreationArgs::new(
+            "main",
+            "main 'int (int, char **)'",
+            None,
+            "int (int, char **)",
+            Range::create(11, 1, 15, 2),
+        ));
+        FuncStructure::mark_template_pattern(&db_connection, &pattern_impl.borrow());
+
+        let unresolved_func_call =
+            pattern_impl
+                .borrow_mut()
+                .add_unresolved_func_call(&FuncCreationArgs::new(
+                    "draw",
+                    "draw",
+                    None,
+                    "",
+                    Range::create(8, 12, 8, 22),
+                ));
+        let call_args = draw_impl
+            .borrow()
+            .convert_func2func_creation_args4call(&Range::create(8, 12, 8, 22));
+        let func_call = instantiation_impl.borrow_mut().add_func_call(&call_args);
+        FuncStructure::bind_unresolved_func_call(
+            &db_connection,
+            &unresolved_func_call.borrow(),
+            &func_call.borrow(),
+        );
+        database_sqlite.link_func_calls();
+
+        database_sqlite
+    }
+
+    #[test]
+    fn test_get_dead_func_impls_with_template_pattern() {
+        let database_sqlite = create_dead_code_template_test_database();
+
+        // The pattern reaches the callees of its instantiations through the bound calls.
+        let roots: Vec<FunctionOccurrence> = database_sqlite
+            .get_all_func_impls()
+            .into_iter()
+            .filter(|func_impl| database_sqlite.is_template_pattern(&func_impl.func.borrow()))
+            .collect();
+        let dead_funcs =
+            get_dead_func_impls(&database_sqlite, roots, VirtualCallTargets::AllOverrides);
+        assert_eq!(
+            get_qualified_names(&dead_funcs),
+            vec![
+                "render<Circle> 'int (Circle &)'",
+                "main 'int (int, char **)'"
+            ]
+        );
+
+        // The pattern itself is never reported.
+        let dead_funcs = get_dead_func_impls(
+            &database_sqlite,
+            get_main_func_impls(&database_sqlite),
+            VirtualCallTargets::AllOverrides,
+        );
+        assert_eq!(
+            get_qualified_names(&dead_funcs),
+            vec!["draw 'int (Circle &)'", "render<Circle> 'int (Circle &)'"]
+        );
+    }
+
+    #[test]
+    fn test_get_dead_func_impls_without_roots() {
+        let database_sqlite = create_dead_code_test_database();
+
+        let dead_funcs = get_dead_func_impls(
+            &database_sqlite,
+            Vec::new(),
+            VirtualCallTargets::AllOverrides,
+        );
+        assert_eq!(dead_funcs.len(), 6);
+    }
+}
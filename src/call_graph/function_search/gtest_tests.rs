@@ -0,0 +1,375 @@
+use crate::call_graph::{
+    data_structure::{
+        func_structure::{FuncMentionType, FuncStructure},
+        FuncBasics, VirtualFuncBasics,
+    },
+    database::database_sqlite::DatabaseSqlite,
+};
+
+use super::{
+    call_tree::{get_call_tree, CallTreeNode},
+    function_occurrence::FunctionOccurrence,
+    impact_analysis::contains_func,
+    virtual_call_targets::VirtualCallTargets,
+};
+
+const GTEST_TEST_BODY_QUALIFIED_NAME: &str = "testing::Test::TestBody 'void ()'";
+const GTEST_TEST_BODY_SUFFIX: &str = "::TestBody 'void ()'";
+
+// A test defined via the gtest macros `TEST`, `TEST_F` or `TEST_P`. The macros generate a class
+// `<Suite>_<Name>_Test` overriding `testing::Test::TestBody` with the body of the test.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GtestTest {
+    // Named like in gtest filters, e.g. "Suite.Name".
+    pub name: String,
+    pub test_body: FunctionOccurrence,
+}
+
+// Returns the test name for the generated `TestBody` implementation of a gtest test.
+pub fn get_gtest_test_name(func: &FuncStructure) -> Option<String> {
+    if func.get_func_type() != Some(FuncMentionType::VirtualFuncImpl)
+        || func.get_base_qualified_name() != GTEST_TEST_BODY_QUALIFIED_NAME
+    {
+        return None;
+    }
+
+    let class_name = func
+        .get_qualified_name()
+        .strip_suffix(GTEST_TEST_BODY_SUFFIX)?;
+    let class_name = match class_name.rsplit_once("::") {
+        Some((_, class_name)) => class_name,
+        None => class_name,
+    };
+    // gtest doesn't allow underscores in the suite name, so the first one separates both names.
+    let (suite_name, test_name) = class_name.strip_suffix("_Test")?.split_once('_')?;
+    if suite_name.is_empty() || test_name.is_empty() {
+        return None;
+    }
+
+    Some(format!("{}.{}", suite_name, test_name))
+}
+
+pub fn get_gtest_tests(db: &DatabaseSqlite) -> Vec<GtestTest> {
+    let mut tests = Vec::new();
+    for func_impl in db.get_all_func_impls() {
+        let name = get_gtest_test_name(&func_impl.func.borrow());
+        if let Some(name) = name {
+            tests.push(GtestTest {
+                name,
+                test_body: func_impl,
+            });
+        }
+    }
+
+    tests
+}
+
+pub fn get_gtest_test(db: &DatabaseSqlite, name: &str) -> Option<GtestTest> {
+    get_gtest_tests(db)
+        .into_iter()
+        .find(|test| test.name == name)
+}
+
+// Returns all tests calling one of the given functions directly or indirectly. A test reaches a
+// function if it is covered by the test, so virtual calls are resolved like in
+// `get_funcs_covered_by`.
+pub fn get_tests_reaching(
+    db: &DatabaseSqlite,
+    funcs: Vec<FunctionOccurrence>,
+    virtual_call_targets: VirtualCallTargets,
+) -> Vec<GtestTest> {
+    get_gtest_tests(db)
+        .into_iter()
+        .filter(|test| {
+            contains_func(&funcs, &test.test_body)
+                || get_funcs_covered_by(db, test, virtual_call_targets)
+                    .iter()
+                    .any(|covered_func| contains_func(&funcs, covered_func))
+        })
+        .collect()
+}
+
+// Returns all functions called directly or indirectly by the test. Every function is only
+// returned once.
+pub fn get_funcs_covered_by(
+    db: &DatabaseSqlite,
+    test: &GtestTest,
+    virtual_call_targets: VirtualCallTargets,
+) -> Vec<FunctionOccurrence> {
+    let mut funcs = Vec::new();
+    collect_callees(
+        &get_call_tree(db, &test.test_body, None, virtual_call_targets),
+        &mut funcs,
+    );
+
+    funcs
+}
+
+fn collect_callees(nodes: &[CallTreeNode], funcs: &mut Vec<FunctionOccurrence>) {
+    for node in nodes {
+        if let Some(callee) = &node.callee {
+            if !funcs.contains(callee) {
+                funcs.push(callee.clone());
+            }
+        }
+        collect_callees(&node.callees, funcs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::{
+        call_graph::{
+            data_structure::{
+                file_structure::FileStructure, helper::func_creation_args::FuncCreationArgs,
+                FuncImplBasics, MainDeclPosition,
+            },
+            tests::test_database::{add_func_call, add_func_impl},
+        },
+        location::range::Range,
+    };
+
+    use super::*;
+
+    fn create_test_body(
+        cpp_file: &Rc<RefCell<FileStructure>>,
+        class_name: &str,
+        range: Range,
+    ) -> Rc<RefCell<FuncStructure>> {
+        cpp_file
+            .borrow_mut()
+            .add_virtual_func_impl(FuncCreationArgs::new(
+                "TestBody",
+                &format!("{}::TestBody 'void ()'", class_name),
+                Some(GTEST_TEST_BODY_QUALIFIED_NAME.to_string()),
+                "void ()",
+                range,
+            ))
+    }
+
+    fn create_gtest_test_database() -> DatabaseSqlite {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+        let cpp_file = database_sqlite.get_or_add_cpp_file("file_test.cpp");
+
+        let mult_impl = add_func_impl(
+            &cpp_file,
+            "mult",
+            "int (int, int)",
+            Range::create(1, 1, 4, 2),
+        );
+        let add_impl = add_func_impl(
+            &cpp_file,
+            "add",
+            "int (int, int)",
+            Range::create(6, 1, 9, 2),
+        );
+        let sub_impl = add_func_impl(
+            &cpp_file,
+            "sub",
+            "int (int, int)",
+            Range::create(10, 1, 12, 2),
+        );
+        let add_test = create_test_body(&cpp_file, "Math_Add_Test", Range::create(14, 1, 16, 2));
+        let sub_test = create_test_body(
+            &cpp_file,
+            "MathFixture_Sub_Test",
+            Range::create(18, 1, 20, 2),
+        );
+
+        add_func_call(&add_impl, &mult_impl, Range::create(8, 12, 8, 22));
+        add_func_call(&add_test, &add_impl, Range::create(15, 5, 15, 14));
+        add_func_call(&sub_test, &sub_impl, Range::create(19, 5, 19, 14));
+        database_sqlite.link_func_calls();
+
+        database_sqlite
+    }
+
+    fn get_test_names(tests: &[GtestTest]) -> Vec<&str> {
+        tests.iter().map(|test| test.name.as_str()).collect()
+    }
+
+    fn get_qualified_names(funcs: &[FunctionOccurrence]) -> Vec<String> {
+        funcs
+            .iter()
+            .map(|func| func.func.borrow().get_qualified_name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_get_gtest_test_name() {
+        let create_func = |qualified_name: &str, base_qualified_name: &str, func_type| {
+            FuncStructure::new(
+                1,
+                None,
+                "TestBody".to_string(),
+                qualified_name.to_string(),
+                Some(base_qualified_name.to_string()),
+                "void ()".to_string(),
+                None,
+                Range::create(1, 1, 3, 2),
+                Some(func_type),
+            )
+        };
+
+        let func = create_func(
+            "SimpleGtest_Test1_Test::TestBody 'void ()'",
+            GTEST_TEST_BODY_QUALIFIED_NAME,
+            FuncMentionType::VirtualFuncImpl,
+        );
+        assert_eq!(
+            get_gtest_test_name(&func),
+            Some("SimpleGtest.Test1".to_string())
+        );
+
+        let func = create_func(
+            "ns::Suite_Name_With_Underscores_Test::TestBody 'void ()'",
+            GTEST_TEST_BODY_QUALIFIED_NAME,
+            FuncMentionType::VirtualFuncImpl,
+        );
+        assert_eq!(
+            get_gtest_test_name(&func),
+            Some("Suite.Name_With_Underscores".to_string())
+        );
+
+        let func = create_func(
+            "SimpleGtest_Test1_Test::TestBody 'void ()'",
+            GTEST_TEST_BODY_QUALIFIED_NAME,
+            FuncMentionType::VirtualFuncDecl,
+        );
+        assert_eq!(get_gtest_test_name(&func), None);
+
+        let func = create_func(
+            "Shape_Area_Test::TestBody 'void ()'",
+            "Shape::TestBody 'void ()'",
+            FuncMentionType::VirtualFuncImpl,
+        );
+        assert_eq!(get_gtest_test_name(&func), None);
+
+        let func = create_func(
+            GTEST_TEST_BODY_QUALIFIED_NAME,
+            GTEST_TEST_BODY_QUALIFIED_NAME,
+            FuncMentionType::VirtualFuncImpl,
+        );
+        assert_eq!(get_gtest_test_name(&func), None);
+    }
+
+    #[test]
+    fn test_get_gtest_tests() {
+        let database_sqlite = create_gtest_test_database();
+
+        let tests = get_gtest_tests(&database_sqlite);
+        assert_eq!(get_test_names(&tests), vec!["Math.Add", "MathFixture.Sub"]);
+        assert_eq!(tests[0].test_body.file, "file_test.cpp");
+
+        assert!(get_gtest_test(&database_sqlite, "MathFixture.Sub").is_some());
+        assert!(get_gtest_test(&database_sqlite, "Math.Sub").is_none());
+    }
+
+    #[test]
+    fn test_get_tests_reaching() {
+        let database_sqlite = create_gtest_test_database();
+
+        let mult_impl = database_sqlite
+            .get_all_func_impls()
+            .into_iter()
+            .filter(|func| func.func.borrow().get_name() == "mult")
+            .collect();
+        assert_eq!(
+            get_test_names(&get_tests_reaching(
+                &database_sqlite,
+                mult_impl,
+                VirtualCallTargets::AllOverrides
+            )),
+            vec!["Math.Add"]
+        );
+
+        let all_func_impls = database_sqlite.get_all_func_impls();
+        assert_eq!(
+            get_test_names(&get_tests_reaching(
+                &database_sqlite,
+                all_func_impls,
+                VirtualCallTargets::AllOverrides
+            )),
+            vec!["Math.Add", "MathFixture.Sub"]
+        );
+    }
+
+    #[test]
+    fn test_get_tests_reaching_through_virtual_call() {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+        let cpp_file = database_sqlite.get_or_add_cpp_file("shape_test.cpp");
+
+        let shape_class = cpp_file.borrow_mut().get_or_add_class("Shape");
+        let shape_area_impl =
+            shape_class
+                .borrow_mut()
+                .add_virtual_func_impl(FuncCreationArgs::new(
+                    "area",
+                    "Shape::area 'int ()'",
+                    Some("Shape::area 'int ()'".to_string()),
+                    "int ()",
+                    Range::create(3, 5, 3, 30),
+                ));
+        let circle_class = cpp_file.borrow_mut().get_or_add_class("Circle");
+        let circle_area_impl =
+            circle_class
+                .borrow_mut()
+                .add_virtual_func_impl(FuncCreationArgs::new(
+                    "area",
+                    "Circle::area 'int ()'",
+                    Some("Shape::area 'int ()'".to_string()),
+                    "int ()",
+                    Range::create(8, 5, 8, 30),
+                ));
+        let area_test =
+            create_test_body(&cpp_file, "Shapes_Area_Test", Range::create(11, 1, 14, 2));
+        let call_args = shape_area_impl
+            .borrow()
+            .convert_virtual_func2virtual_func_creation_args4call(&Range::create(13, 5, 13, 17));
+        area_test.borrow_mut().add_virtual_func_call(&call_args);
+        database_sqlite.link_func_calls();
+
+        let circle_area = database_sqlite.get_func_impls(&*circle_area_impl.borrow());
+        assert!(get_tests_reaching(
+            &database_sqlite,
+            circle_area.clone(),
+            VirtualCallTargets::Static
+        )
+        .is_empty());
+        assert_eq!(
+            get_test_names(&get_tests_reaching(
+                &database_sqlite,
+                circle_area,
+                VirtualCallTargets::AllOverrides
+            )),
+            vec!["Shapes.Area"]
+        );
+    }
+
+    #[test]
+    fn test_get_funcs_covered_by() {
+        let database_sqlite = create_gtest_test_database();
+
+        let test = get_gtest_test(&database_sqlite, "Math.Add").unwrap();
+        assert_eq!(
+            get_qualified_names(&get_funcs_covered_by(
+                &database_sqlite,
+                &test,
+                VirtualCallTargets::AllOverrides
+            )),
+            vec!["add 'int (int, int)'", "mult 'int (int, int)'"]
+        );
+
+        let test = get_gtest_test(&database_sqlite, "MathFixture.Sub").unwrap();
+        assert_eq!(
+            get_qualified_names(&get_funcs_covered_by(
+                &database_sqlite,
+                &test,
+                VirtualCallTargets::AllOverrides
+            )),
+            vec!["sub 'int (int, int)'"]
+        );
+    }
+}
@@ -0,0 +1,436 @@
+use std::collections::VecDeque;
+
+use crate::call_graph::{
+    data_structure::{
+        func_structure::{FuncMentionType, FuncStructure},
+        FuncBasics, VirtualFuncBasics,
+    },
+    database::database_sqlite::DatabaseSqlite,
+};
+
+use super::function_occurrence::FunctionOccurrence;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangedLines {
+    pub file: String,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+impl ChangedLines {
+    pub fn new(file: &str, start_line: usize, end_line: usize) -> Self {
+        ChangedLines {
+            file: file.to_string(),
+            start_line,
+            end_line,
+        }
+    }
+}
+
+// Accepts "file:line" or "file:start_line-end_line".
+pub fn parse_changed_lines(input: &str) -> Option<ChangedLines> {
+    let (file, lines) = input.rsplit_once(':')?;
+    if file.is_empty() {
+        return None;
+    }
+
+    let (start_line, end_line) = match lines.split_once('-') {
+        Some((start_line, end_line)) => (start_line.parse().ok()?, end_line.parse().ok()?),
+        None => {
+            let line = lines.parse().ok()?;
+            (line, line)
+        }
+    };
+    if start_line > end_line {
+        return None;
+    }
+
+    Some(ChangedLines::new(file, start_line, end_line))
+}
+
+// Collects the changed lines of the new file versions in a unified diff. Removed lines are
+// mapped to the line following them, so the surrounding function is still found.
+pub fn parse_unified_diff(diff: &str) -> Vec<ChangedLines> {
+    let mut changed_lines: Vec<ChangedLines> = Vec::new();
+    let mut current_file: Option<String> = None;
+    let mut new_line = 0;
+    let mut remaining_old_lines = 0;
+    let mut remaining_new_lines = 0;
+
+    for line in diff.lines() {
+        if remaining_old_lines == 0 && remaining_new_lines == 0 {
+            if let Some(file) = line.strip_prefix("+++ ") {
+                let file = file.split('\t').next().unwrap_or_default();
+                current_file = match file {
+                    "/dev/null" => None,
+                    _ => Some(file.strip_prefix("b/").unwrap_or(file).to_string()),
+                };
+            } else if let Some(hunk) = line.strip_prefix("@@ ") {
+                let (old_range, new_range) = match parse_hunk_header(hunk) {
+                    Some(ranges) => ranges,
+                    None => continue,
+                };
+                new_line = new_range.0;
+                remaining_old_lines = old_range.1;
+                remaining_new_lines = new_range.1;
+            }
+            continue;
+        }
+
+        let changed_line = match line.chars().next() {
+            Some('+') => {
+                remaining_new_lines -= 1;
+                new_line += 1;
+                Some(new_line - 1)
+            }
+            Some('-') => {
+                remaining_old_lines -= 1;
+                Some(new_line)
+            }
+            Some('\\') => None,
+            _ => {
+                remaining_old_lines = remaining_old_lines.saturating_sub(1);
+                remaining_new_lines = remaining_new_lines.saturating_sub(1);
+                new_line += 1;
+                None
+            }
+        };
+
+        if let (Some(changed_line), Some(file)) = (changed_line, &current_file) {
+            add_changed_line(&mut changed_lines, file, changed_line);
+        }
+    }
+
+    changed_lines
+}
+
+// "-1,5 +1,6 @@ fn foo()" results in ((1, 5), (1, 6)), a missing count defaults to one line.
+fn parse_hunk_header(hunk: &str) -> Option<((usize, usize), (usize, usize))> {
+    let mut parts = hunk.split(' ');
+    let old_range = parse_hunk_range(parts.next()?.strip_prefix('-')?)?;
+    let new_range = parse_hunk_range(parts.next()?.strip_prefix('+')?)?;
+    Some((old_range, new_range))
+}
+
+fn parse_hunk_range(range: &str) -> Option<(usize, usize)> {
+    match range.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
+
+fn add_changed_line(changed_lines: &mut Vec<ChangedLines>, file: &str, line: usize) {
+    if let Some(last) = changed_lines.last_mut() {
+        if last.file == file && line >= last.start_line && line <= last.end_line + 1 {
+            last.end_line = last.end_line.max(line);
+            return;
+        }
+    }
+    changed_lines.push(ChangedLines::new(file, line, line));
+}
+
+// Diffs use paths relative to the repository, while the database stores the paths as seen
+// by the compiler, e.g. "./src/file.cpp" or "/home/user/project/src/file.cpp".
+fn is_same_file(db_file: &str, changed_file: &str) -> bool {
+    let db_file = db_file.strip_prefix("./").unwrap_or(db_file);
+    let changed_file = changed_file.strip_prefix("./").unwrap_or(changed_file);
+
+    db_file == changed_file || db_file.ends_with(&format!("/{}", changed_file))
+}
+
+// Returns all function impls overlapping the changed lines, followed by all functions calling
+// them directly or indirectly. Every function is only returned once.
+pub fn get_impacted_funcs(
+    db: &DatabaseSqlite,
+    changed_lines: &[ChangedLines],
+) -> Vec<FunctionOccurrence> {
+    let mut changed_funcs = Vec::new();
+    for func_impl in db.get_all_func_impls() {
+        let range = func_impl.func.borrow().get_range().clone();
+        let is_changed = changed_lines.iter().any(|changed_lines| {
+            is_same_file(&func_impl.file, &changed_lines.file)
+                && range.start.line <= changed_lines.end_line
+                && range.end.line >= changed_lines.start_line
+        });
+        if is_changed {
+            changed_funcs.push(func_impl);
+        }
+    }
+
+    get_transitive_callers(db, changed_funcs)
+}
+
+// Returns the given functions followed by all functions calling them directly or indirectly.
+// Every function is only returned once.
+pub fn get_transitive_callers(
+    db: &DatabaseSqlite,
+    funcs: Vec<FunctionOccurrence>,
+) -> Vec<FunctionOccurrence> {
+    let mut callers: Vec<FunctionOccurrence> = Vec::new();
+    let mut open_funcs: VecDeque<FunctionOccurrence> = VecDeque::from(funcs);
+
+    while let Some(func) = open_funcs.pop_front() {
+        if contains_func(&callers, &func) {
+            continue;
+        }
+
+        for caller in get_func_callers_including_base(db, &func.func.borrow()) {
+            if !contains_func(&callers, &caller) {
+                open_funcs.push_back(caller);
+            }
+        }
+        callers.push(func);
+    }
+
+    callers
+}
+
+// An override is also reached through calls of the function it overrides.
+pub fn get_func_callers_including_base(
+    db: &DatabaseSqlite,
+    func: &FuncStructure,
+) -> Vec<FunctionOccurrence> {
+    let mut callers = db.get_func_callers(func);
+
+    if func.get_func_type() == Some(FuncMentionType::VirtualFuncImpl)
+        && func.get_base_qualified_name() != func.get_qualified_name()
+    {
+        let base_func = FuncStructure::new(
+            0,
+            None,
+            func.get_name().to_string(),
+            func.get_base_qualified_name().to_string(),
+            None,
+            func.get_qual_type().to_string(),
+            None,
+            func.get_range().clone(),
+            None,
+        );
+        callers.append(&mut db.get_func_callers(&base_func));
+    }
+
+    callers
+}
+
+pub fn contains_func(funcs: &[FunctionOccurrence], func: &FunctionOccurrence) -> bool {
+    let func = func.func.borrow();
+    funcs.iter().any(|other| {
+        let other = other.func.borrow();
+        other.get_func_type() == func.get_func_type() && other.get_id() == func.get_id()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        call_graph::{
+            data_structure::{
+                helper::func_creation_args::FuncCreationArgs, FuncImplBasics, MainDeclPosition,
+            },
+            tests::test_database::{add_func_call, add_func_impl},
+        },
+        location::range::Range,
+    };
+
+    use super::*;
+
+    fn create_impact_test_database() -> DatabaseSqlite {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+        let cpp_file = database_sqlite.get_or_add_cpp_file("./src/file.cpp");
+
+        let mult_impl = add_func_impl(
+            &cpp_file,
+            "mult",
+            "int (int, int)",
+            Range::create(1, 1, 4, 2),
+        );
+        let add_impl = add_func_impl(
+            &cpp_file,
+            "add",
+            "int (int, int)",
+            Range::create(6, 1, 9, 2),
+        );
+        let sub_impl = add_func_impl(
+            &cpp_file,
+            "sub",
+            "int (int, int)",
+            Range::create(10, 1, 12, 2),
+        );
+        let main_impl = add_func_impl(
+            &cpp_file,
+            "main",
+            "int (int, char **)",
+            Range::create(14, 1, 18, 2),
+        );
+
+        add_func_call(&add_impl, &mult_impl, Range::create(8, 12, 8, 22));
+        add_func_call(&main_impl, &add_impl, Range::create(16, 12, 16, 21));
+        add_func_call(&main_impl, &sub_impl, Range::create(17, 12, 17, 21));
+        // Recursive calls must not be reported twice.
+        add_func_call(&add_impl, &add_impl, Range::create(7, 12, 7, 21));
+
+        database_sqlite
+    }
+
+    fn get_qualified_names(funcs: &[FunctionOccurrence]) -> Vec<String> {
+        funcs
+            .iter()
+            .map(|func| func.func.borrow().get_qualified_name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_changed_lines() {
+        assert_eq!(
+            parse_changed_lines("src/file.cpp:10-20"),
+            Some(ChangedLines::new("src/file.cpp", 10, 20))
+        );
+        assert_eq!(
+            parse_changed_lines("src/file.cpp:7"),
+            Some(ChangedLines::new("src/file.cpp", 7, 7))
+        );
+        assert_eq!(parse_changed_lines("src/file.cpp:20-10"), None);
+        assert_eq!(parse_changed_lines("src/file.cpp:a-b"), None);
+        assert_eq!(parse_changed_lines("src/file.cpp"), None);
+        assert_eq!(parse_changed_lines(":10"), None);
+    }
+
+    #[test]
+    fn test_parse_unified_diff() {
+        let diff = "\
+diff --git a/src/file.cpp b/src/file.cpp
+index 1234567..89abcde 100644
+--- a/src/file.cpp
++++ b/src/file.cpp
+@@ -2,4 +2,5 @@ int mult(int a, int b)
+ {
+-    return a * b;
++    int result = a * b;
++    return result;
+ }
+ 
+@@ -20 +21 @@ int main()
+-    return 0;
++    return 1;
+diff --git a/old.cpp b/old.cpp
+deleted file mode 100644
+--- a/old.cpp
++++ /dev/null
+@@ -1,2 +0,0 @@
+-int old()
+-{}
+diff --git a/src/file.h b/src/file.h
+--- a/src/file.h
++++ b/src/file.h
+@@ -3,2 +3,1 @@
+ int mult(int a, int b);
+-int add(int a, int b);
+\\ No newline at end of file
+";
+
+        assert_eq!(
+            parse_unified_diff(diff),
+            vec![
+                ChangedLines::new("src/file.cpp", 3, 4),
+                ChangedLines::new("src/file.cpp", 21, 21),
+                ChangedLines::new("src/file.h", 4, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_same_file() {
+        assert!(is_same_file("./src/file.cpp", "src/file.cpp"));
+        assert!(is_same_file(
+            "/home/user/project/src/file.cpp",
+            "src/file.cpp"
+        ));
+        assert!(is_same_file("src/file.cpp", "./src/file.cpp"));
+        assert!(!is_same_file("./src/other_file.cpp", "file.cpp"));
+        assert!(!is_same_file("./src/file.cpp", "other/file.cpp"));
+    }
+
+    #[test]
+    fn test_get_impacted_funcs() {
+        let database_sqlite = create_impact_test_database();
+
+        let impacted_funcs =
+            get_impacted_funcs(&database_sqlite, &[ChangedLines::new("src/file.cpp", 2, 3)]);
+        assert_eq!(
+            get_qualified_names(&impacted_funcs),
+            vec![
+                "mult 'int (int, int)'",
+                "add 'int (int, int)'",
+                "main 'int (int, char **)'",
+            ]
+        );
+        assert!(impacted_funcs
+            .iter()
+            .all(|func| func.file == "./src/file.cpp"));
+
+        let impacted_funcs = get_impacted_funcs(
+            &database_sqlite,
+            &[ChangedLines::new("src/file.cpp", 11, 11)],
+        );
+        assert_eq!(
+            get_qualified_names(&impacted_funcs),
+            vec!["sub 'int (int, int)'", "main 'int (int, char **)'"]
+        );
+
+        let impacted_funcs =
+            get_impacted_funcs(&database_sqlite, &[ChangedLines::new("src/file.cpp", 5, 5)]);
+        assert!(impacted_funcs.is_empty());
+
+        let impacted_funcs = get_impacted_funcs(
+            &database_sqlite,
+            &[ChangedLines::new("src/other_file.cpp", 1, 20)],
+        );
+        assert!(impacted_funcs.is_empty());
+    }
+
+    #[test]
+    fn test_get_impacted_funcs_virtual_override() {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+        let cpp_file = database_sqlite.get_or_add_cpp_file("file.cpp");
+
+        let base_class = cpp_file.borrow_mut().add_class("Base");
+        let base_impl = base_class
+            .borrow_mut()
+            .add_virtual_func_impl(FuncCreationArgs::new(
+                "area",
+                "Base::area 'int ()'",
+                Some("Base::area 'int ()'".to_string()),
+                "int ()",
+                Range::create(3, 5, 3, 40),
+            ));
+        let derived_class = cpp_file.borrow_mut().add_class("Derived");
+        derived_class
+            .borrow_mut()
+            .add_virtual_func_impl(FuncCreationArgs::new(
+                "area",
+                "Derived::area 'int ()'",
+                Some("Base::area 'int ()'".to_string()),
+                "int ()",
+                Range::create(8, 5, 8, 40),
+            ));
+        let print_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs::new(
+            "print",
+            "print 'void (Base &)'",
+            None,
+            "void (Base &)",
+            Range::create(11, 1, 14, 2),
+        ));
+
+        let call_args = base_impl
+            .borrow()
+            .convert_virtual_func2virtual_func_creation_args4call(&Range::create(13, 5, 13, 15));
+        print_impl.borrow_mut().add_virtual_func_call(&call_args);
+
+        let impacted_funcs =
+            get_impacted_funcs(&database_sqlite, &[ChangedLines::new("file.cpp", 8, 8)]);
+        assert_eq!(
+            get_qualified_names(&impacted_funcs),
+            vec!["Derived::area 'int ()'", "print 'void (Base &)'"]
+        );
+    }
+}
@@ -1 +1,8 @@
+pub mod call_cycles;
+pub mod call_tree;
+pub mod dead_code;
 pub mod function_occurrence;
+pub mod gtest_tests;
+pub mod impact_analysis;
+pub mod name_match;
+pub mod virtual_call_targets;
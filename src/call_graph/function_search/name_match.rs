@@ -0,0 +1,58 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameMatch {
+    // The whole qualified name, with or without the type.
+    Exact,
+    // Qualified names starting with the given text.
+    Prefix,
+    // A pattern in the syntax of SQLite GLOB, e.g. "ns::*" or "get?alue*".
+    Glob,
+}
+
+// Converts the name into a GLOB pattern, so that all lookups by name can use the same query.
+pub fn get_name_glob_pattern(name: &str, name_match: NameMatch) -> String {
+    match name_match {
+        NameMatch::Exact => escape_glob(name),
+        NameMatch::Prefix => format!("{}*", escape_glob(name)),
+        NameMatch::Glob => name.to_string(),
+    }
+}
+
+// Qualified names can contain GLOB special characters, e.g. "operator*" or "int *[]".
+fn escape_glob(text: &str) -> String {
+    let mut escaped = String::new();
+    for character in text.chars() {
+        match character {
+            '*' | '?' | '[' => {
+                escaped.push('[');
+                escaped.push(character);
+                escaped.push(']');
+            }
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_name_glob_pattern() {
+        assert_eq!(
+            get_name_glob_pattern("ns::add", NameMatch::Exact),
+            "ns::add"
+        );
+        assert_eq!(
+            get_name_glob_pattern("Vec::operator[]", NameMatch::Exact),
+            "Vec::operator[[]]"
+        );
+        assert_eq!(get_name_glob_pattern("ns::", NameMatch::Prefix), "ns::*");
+        assert_eq!(
+            get_name_glob_pattern("Ptr::operator*", NameMatch::Prefix),
+            "Ptr::operator[*]*"
+        );
+        assert_eq!(get_name_glob_pattern("*::get?", NameMatch::Glob), "*::get?");
+    }
+}
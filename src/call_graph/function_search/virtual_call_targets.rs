@@ -0,0 +1,63 @@
+use crate::call_graph::data_structure::FuncBasics;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VirtualCallTargets {
+    // Only the function named by the call itself.
+    Static,
+    // All implementations overriding the same base function.
+    AllOverrides,
+    // Implementations of the static receiver type and its sub classes.
+    ReceiverSubtree,
+}
+
+// The qualified name of a method is built as "Class::name 'type'", so the class is whatever is
+// left after removing the method name and type.
+pub fn get_receiver_class_name(func: &dyn FuncBasics) -> Option<&str> {
+    let method_suffix = format!("::{} '{}'", func.get_name(), func.get_qual_type());
+
+    func.get_qualified_name()
+        .strip_suffix(method_suffix.as_str())
+        .filter(|class_name| !class_name.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        call_graph::data_structure::func_structure::FuncStructure, location::range::Range,
+    };
+
+    use super::*;
+
+    fn create_func(name: &str, qualified_name: &str, qual_type: &str) -> FuncStructure {
+        FuncStructure::new(
+            1,
+            None,
+            name.to_string(),
+            qualified_name.to_string(),
+            None,
+            qual_type.to_string(),
+            None,
+            Range::create(1, 1, 1, 10),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_get_receiver_class_name() {
+        let func = create_func("add", "TestClass::add 'int (int, int)'", "int (int, int)");
+        assert_eq!(get_receiver_class_name(&func), Some("TestClass"));
+
+        let func = create_func(
+            "add",
+            "ns::Outer::Inner::add 'int (int, int)'",
+            "int (int, int)",
+        );
+        assert_eq!(get_receiver_class_name(&func), Some("ns::Outer::Inner"));
+
+        let func = create_func("add", "add 'int (int, int)'", "int (int, int)");
+        assert_eq!(get_receiver_class_name(&func), None);
+
+        let func = create_func("add", "TestClass::add 'int (int)'", "int (int, int)");
+        assert_eq!(get_receiver_class_name(&func), None);
+    }
+}
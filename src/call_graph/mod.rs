@@ -1,5 +1,6 @@
 pub mod ast_walker;
 pub mod data_structure;
 pub mod database;
+pub mod export;
 pub mod function_search;
-mod tests;
+pub(crate) mod tests;
@@ -28,6 +28,7 @@ mod tests {
                 base_qualified_name: Some("func_decl".to_string()),
                 qualified_type: "int".to_string(),
                 range: Range::new(Position::new(1, 2), Position::new(1, 10)),
+                usr: None,
             });
 
         let func_impl = cpp_class
@@ -38,6 +39,7 @@ mod tests {
                 base_qualified_name: Some("func".to_string()),
                 qualified_type: "int".to_string(),
                 range: Range::new(Position::new(3, 2), Position::new(3, 10)),
+                usr: None,
             });
 
         let func_call_args = &func_decl
@@ -75,6 +77,7 @@ mod tests {
                 base_qualified_name: Some("func_decl".to_string()),
                 qualified_type: "int".to_string(),
                 range: Range::new(Position::new(1, 2), Position::new(1, 10)),
+                usr: None,
             });
 
         let func_impl = cpp_class
@@ -85,6 +88,7 @@ mod tests {
                 base_qualified_name: Some("func".to_string()),
                 qualified_type: "int".to_string(),
                 range: Range::new(Position::new(3, 2), Position::new(3, 10)),
+                usr: None,
             });
 
         let func_call_args = &func_decl
@@ -122,6 +126,7 @@ mod tests {
                 base_qualified_name: Some("func_decl".to_string()),
                 qualified_type: "int".to_string(),
                 range: Range::new(Position::new(1, 2), Position::new(1, 10)),
+                usr: None,
             });
 
         let func_impl = cpp_class
@@ -132,6 +137,7 @@ mod tests {
                 base_qualified_name: Some("func".to_string()),
                 qualified_type: "int".to_string(),
                 range: Range::new(Position::new(3, 2), Position::new(3, 10)),
+                usr: None,
             });
 
         let func_call_args = &func_decl
@@ -167,6 +173,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int".to_string(),
             range: Range::new(Position::new(1, 2), Position::new(1, 10)),
+            usr: None,
         });
 
         let func_impl = cpp_class
@@ -177,6 +184,7 @@ mod tests {
                 base_qualified_name: Some("func".to_string()),
                 qualified_type: "int".to_string(),
                 range: Range::new(Position::new(3, 2), Position::new(3, 10)),
+                usr: None,
             });
 
         let func_call_args = &func_decl
@@ -219,6 +227,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int".to_string(),
             range: Range::new(Position::new(2, 2), Position::new(2, 10)),
+            usr: None,
         });
 
         let mut matches: Vec<Rc<RefCell<FuncStructure>>> = Vec::new();
@@ -242,6 +251,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int".to_string(),
             range: Range::new(Position::new(2, 2), Position::new(2, 10)),
+            usr: None,
         });
 
         let mut matches: Vec<Rc<RefCell<FuncStructure>>> = Vec::new();
@@ -1,2 +1,4 @@
 mod matching_funcs;
+#[cfg(test)]
+pub(crate) mod test_database;
 mod type_equals_tests;
@@ -0,0 +1,64 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    call_graph::data_structure::{
+        func_structure::FuncStructure, helper::func_creation_args::FuncCreationArgs, FuncBasics,
+        FuncImplBasics, MainDeclPosition,
+    },
+    location::range::Range,
+};
+
+// Builds the creation args like the AST walker does, the name is the last part of the
+// qualified name and the type is appended to the qualified name.
+fn create_func_creation_args(
+    qualified_name: &str,
+    qualified_type: &str,
+    range: Range,
+) -> FuncCreationArgs {
+    FuncCreationArgs::new(
+        qualified_name.rsplit("::").next().unwrap(),
+        &format!("{} '{}'", qualified_name, qualified_type),
+        None,
+        qualified_type,
+        range,
+    )
+}
+
+pub fn add_func_decl<T: MainDeclPosition>(
+    main_decl_position: &Rc<RefCell<T>>,
+    qualified_name: &str,
+    qualified_type: &str,
+    range: Range,
+) -> Rc<RefCell<FuncStructure>> {
+    main_decl_position
+        .borrow_mut()
+        .add_func_decl(create_func_creation_args(
+            qualified_name,
+            qualified_type,
+            range,
+        ))
+}
+
+pub fn add_func_impl<T: MainDeclPosition>(
+    main_decl_position: &Rc<RefCell<T>>,
+    qualified_name: &str,
+    qualified_type: &str,
+    range: Range,
+) -> Rc<RefCell<FuncStructure>> {
+    main_decl_position
+        .borrow_mut()
+        .add_func_impl(create_func_creation_args(
+            qualified_name,
+            qualified_type,
+            range,
+        ))
+}
+
+pub fn add_func_call(
+    caller: &Rc<RefCell<FuncStructure>>,
+    callee: &Rc<RefCell<FuncStructure>>,
+    range: Range,
+) -> Rc<RefCell<FuncStructure>> {
+    let call_args = callee.borrow().convert_func2func_creation_args4call(&range);
+    caller.borrow_mut().add_func_call(&call_args)
+}
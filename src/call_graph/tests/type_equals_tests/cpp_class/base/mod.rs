@@ -22,6 +22,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -65,6 +66,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
 
         let sqlite_content = database_sqlite.get_db_content();
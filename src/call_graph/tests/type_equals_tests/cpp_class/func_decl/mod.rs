@@ -22,6 +22,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -49,6 +50,7 @@ mod tests {
                 base_qualified_name: None,
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         cpp_class
             .borrow_mut()
@@ -58,6 +60,7 @@ mod tests {
                 base_qualified_name: None,
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -83,6 +86,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         cpp_class.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "sub".to_string(),
@@ -90,6 +94,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(12, 5, 12, 8),
+            usr: None,
         });
         cpp_class.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "multiply".to_string(),
@@ -97,6 +102,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(13, 5, 13, 13),
+            usr: None,
         });
         cpp_class.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "divide".to_string(),
@@ -104,6 +110,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(14, 5, 14, 11),
+            usr: None,
         });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -129,6 +136,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         cpp_class.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "sub".to_string(),
@@ -136,6 +144,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(12, 5, 12, 8),
+            usr: None,
         });
         cpp_class.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "multiply".to_string(),
@@ -143,6 +152,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(13, 5, 13, 13),
+            usr: None,
         });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -168,6 +178,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -193,6 +204,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 6, 11, 8),
+            usr: None,
         });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -218,6 +230,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
 
         database_sqlite.remove_cpp_file_and_depending_content(cpp_file.borrow().get_name());
@@ -24,6 +24,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -51,6 +52,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         cpp_class
             .borrow_mut()
@@ -60,6 +62,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -88,6 +91,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         cpp_class
             .borrow_mut()
@@ -97,6 +101,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3subEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(12, 5, 12, 8),
+                usr: None,
             });
         cpp_class
             .borrow_mut()
@@ -106,6 +111,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo8multiplyEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(13, 5, 13, 13),
+                usr: None,
             });
         cpp_class
             .borrow_mut()
@@ -115,6 +121,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo6divideEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(14, 5, 14, 11),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -143,6 +150,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         cpp_class
             .borrow_mut()
@@ -152,6 +160,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3subEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(12, 5, 12, 8),
+                usr: None,
             });
         cpp_class
             .borrow_mut()
@@ -161,6 +170,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo8multiplyEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(13, 5, 13, 13),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -188,6 +198,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -215,6 +226,7 @@ mod tests {
                 base_qualified_name: Some("multi".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -242,6 +254,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 6, 11, 8),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -269,6 +282,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
 
         database_sqlite.remove_cpp_file_and_depending_content(cpp_file.borrow().get_name());
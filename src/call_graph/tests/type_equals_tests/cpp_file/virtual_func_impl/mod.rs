@@ -23,6 +23,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -49,6 +50,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         cpp_file
             .borrow_mut()
@@ -58,6 +60,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -85,6 +88,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         cpp_file
             .borrow_mut()
@@ -94,6 +98,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3subEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(12, 5, 12, 8),
+                usr: None,
             });
         cpp_file
             .borrow_mut()
@@ -103,6 +108,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo8multiplyEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(13, 5, 13, 13),
+                usr: None,
             });
         cpp_file
             .borrow_mut()
@@ -112,6 +118,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo6divideEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(14, 5, 14, 11),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -139,6 +146,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         cpp_file
             .borrow_mut()
@@ -148,6 +156,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3subEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(12, 5, 12, 8),
+                usr: None,
             });
         cpp_file
             .borrow_mut()
@@ -157,6 +166,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo8multiplyEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(13, 5, 13, 13),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -183,6 +193,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -209,6 +220,7 @@ mod tests {
                 base_qualified_name: Some("multi".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -235,6 +247,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 6, 11, 8),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -261,6 +274,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
 
         database_sqlite.remove_cpp_file_and_depending_content(cpp_file.borrow().get_name());
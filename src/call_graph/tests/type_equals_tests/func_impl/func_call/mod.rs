@@ -24,6 +24,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         let func_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs {
             name: "main".to_string(),
@@ -31,6 +32,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, char **)".to_string(),
             range: Range::create(5, 4, 5, 9),
+            usr: None,
         });
         func_impl.borrow_mut().add_func_call(
             &func_decl
@@ -60,6 +62,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         let func_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs {
             name: "main".to_string(),
@@ -67,6 +70,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, char **)".to_string(),
             range: Range::create(5, 4, 5, 9),
+            usr: None,
         });
         func_impl.borrow_mut().get_or_add_func_call(
             &func_decl
@@ -101,6 +105,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         let func_decl_sub = cpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "sub".to_string(),
@@ -108,6 +113,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(12, 5, 12, 8),
+            usr: None,
         });
         let func_decl_multiply = cpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "multiply".to_string(),
@@ -115,6 +121,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(13, 5, 13, 13),
+            usr: None,
         });
         let func_decl_divide = cpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "divide".to_string(),
@@ -122,6 +129,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(14, 5, 14, 11),
+            usr: None,
         });
         let func_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs {
             name: "main".to_string(),
@@ -129,6 +137,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, char **)".to_string(),
             range: Range::create(5, 4, 5, 9),
+            usr: None,
         });
         func_impl.borrow_mut().add_func_call(
             &func_decl_add
@@ -173,6 +182,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         let func_decl_sub = cpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "sub".to_string(),
@@ -180,6 +190,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(12, 5, 12, 8),
+            usr: None,
         });
         let func_decl_multiply = cpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "multiply".to_string(),
@@ -187,6 +198,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(13, 5, 13, 13),
+            usr: None,
         });
         cpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "divide".to_string(),
@@ -194,6 +206,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(14, 5, 14, 11),
+            usr: None,
         });
         let func_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs {
             name: "main".to_string(),
@@ -201,6 +214,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, char **)".to_string(),
             range: Range::create(5, 4, 5, 9),
+            usr: None,
         });
         func_impl.borrow_mut().add_func_call(
             &func_decl_add
@@ -240,6 +254,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         let func_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs {
             name: "main".to_string(),
@@ -247,6 +262,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, char **)".to_string(),
             range: Range::create(5, 4, 5, 9),
+            usr: None,
         });
         func_impl.borrow_mut().add_func_call(
             &func_decl
@@ -276,6 +292,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         let func_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs {
             name: "main".to_string(),
@@ -283,6 +300,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, char **)".to_string(),
             range: Range::create(5, 4, 5, 9),
+            usr: None,
         });
         func_impl.borrow_mut().add_func_call(
             &func_decl
@@ -312,6 +330,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         let func_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs {
             name: "main".to_string(),
@@ -319,6 +338,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, char **)".to_string(),
             range: Range::create(5, 4, 5, 9),
+            usr: None,
         });
         func_impl.borrow_mut().add_func_call(
             &func_decl
@@ -27,6 +27,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         let func_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs {
             name: "foo".to_string(),
@@ -34,6 +35,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, char **)".to_string(),
             range: Range::create(5, 4, 5, 9),
+            usr: None,
         });
         func_impl.borrow_mut().add_virtual_func_call(
             &func_decl
@@ -68,6 +70,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         let func_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs {
             name: "foo".to_string(),
@@ -75,6 +78,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, char **)".to_string(),
             range: Range::create(5, 4, 5, 9),
+            usr: None,
         });
         func_impl.borrow_mut().get_or_add_virtual_func_call(
             &func_decl
@@ -117,6 +121,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         let func_decl_sub = cpp_class
             .borrow_mut()
@@ -126,6 +131,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3subEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(12, 5, 12, 8),
+                usr: None,
             });
         let func_decl_multiply = cpp_class
             .borrow_mut()
@@ -135,6 +141,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3multiplyEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(13, 5, 13, 13),
+                usr: None,
             });
         let func_decl_divide = cpp_class
             .borrow_mut()
@@ -144,6 +151,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3divideEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(14, 5, 14, 11),
+                usr: None,
             });
         let func_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs {
             name: "foo".to_string(),
@@ -151,6 +159,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, char **)".to_string(),
             range: Range::create(5, 4, 5, 9),
+            usr: None,
         });
         func_impl.borrow_mut().add_virtual_func_call(
             &func_decl_add
@@ -207,6 +216,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         let func_decl_sub = cpp_class
             .borrow_mut()
@@ -216,6 +226,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3subEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(12, 5, 12, 8),
+                usr: None,
             });
         let func_decl_multiply = cpp_class
             .borrow_mut()
@@ -225,6 +236,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3multiplyEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(13, 5, 13, 13),
+                usr: None,
             });
         cpp_class
             .borrow_mut()
@@ -234,6 +246,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3divideEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(14, 5, 14, 11),
+                usr: None,
             });
         let func_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs {
             name: "foo".to_string(),
@@ -241,6 +254,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, char **)".to_string(),
             range: Range::create(5, 4, 5, 9),
+            usr: None,
         });
         func_impl.borrow_mut().add_virtual_func_call(
             &func_decl_add
@@ -289,6 +303,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         let func_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs {
             name: "foo".to_string(),
@@ -296,6 +311,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, char **)".to_string(),
             range: Range::create(5, 4, 5, 9),
+            usr: None,
         });
         func_impl.borrow_mut().add_virtual_func_call(
             &func_decl
@@ -330,6 +346,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         let func_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs {
             name: "foo".to_string(),
@@ -337,6 +354,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, char **)".to_string(),
             range: Range::create(5, 4, 5, 9),
+            usr: None,
         });
         func_impl.borrow_mut().add_virtual_func_call(
             &func_decl
@@ -371,6 +389,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         let func_impl = cpp_file.borrow_mut().add_func_impl(FuncCreationArgs {
             name: "foo".to_string(),
@@ -378,6 +397,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, char **)".to_string(),
             range: Range::create(5, 4, 5, 9),
+            usr: None,
         });
         func_impl.borrow_mut().add_virtual_func_call(
             &func_decl
@@ -21,6 +21,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -47,6 +48,7 @@ mod tests {
                 base_qualified_name: None,
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         hpp_file
             .borrow_mut()
@@ -56,6 +58,7 @@ mod tests {
                 base_qualified_name: None,
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -80,6 +83,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         hpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "sub".to_string(),
@@ -87,6 +91,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(12, 5, 12, 8),
+            usr: None,
         });
         hpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "multiply".to_string(),
@@ -94,6 +99,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(13, 5, 13, 13),
+            usr: None,
         });
         hpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "divide".to_string(),
@@ -101,6 +107,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(14, 5, 14, 11),
+            usr: None,
         });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -125,6 +132,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         hpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "sub".to_string(),
@@ -132,6 +140,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(12, 5, 12, 8),
+            usr: None,
         });
         hpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "multiply".to_string(),
@@ -139,6 +148,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(13, 5, 13, 13),
+            usr: None,
         });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -163,6 +173,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -187,6 +198,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 6, 11, 8),
+            usr: None,
         });
 
         let sqlite_content = database_sqlite.get_db_content();
@@ -211,6 +223,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
 
         database_sqlite.remove_hpp_file_and_depending_content(hpp_file.borrow().get_name());
@@ -24,6 +24,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         let cpp_class = cpp_file.borrow_mut().add_class("BarClass");
         let func_impl = cpp_class
@@ -34,6 +35,7 @@ mod tests {
                 base_qualified_name: Some("".to_string()),
                 qualified_type: "int (int, char **)".to_string(),
                 range: Range::create(5, 4, 5, 9),
+                usr: None,
             });
         func_impl.borrow_mut().add_func_call(
             &func_decl
@@ -63,6 +65,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         let cpp_class = cpp_file.borrow_mut().add_class("BarClass");
         let func_impl = cpp_class
@@ -73,6 +76,7 @@ mod tests {
                 base_qualified_name: Some("".to_string()),
                 qualified_type: "int (int, char **)".to_string(),
                 range: Range::create(5, 4, 5, 9),
+                usr: None,
             });
         func_impl.borrow_mut().get_or_add_func_call(
             &func_decl
@@ -107,6 +111,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         let func_decl_sub = cpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "sub".to_string(),
@@ -114,6 +119,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(12, 5, 12, 8),
+            usr: None,
         });
         let func_decl_multiply = cpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "multiply".to_string(),
@@ -121,6 +127,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(13, 5, 13, 13),
+            usr: None,
         });
         let func_decl_divide = cpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "divide".to_string(),
@@ -128,6 +135,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(14, 5, 14, 11),
+            usr: None,
         });
         let cpp_class = cpp_file.borrow_mut().add_class("BarClass");
         let func_impl = cpp_class
@@ -138,6 +146,7 @@ mod tests {
                 base_qualified_name: Some("".to_string()),
                 qualified_type: "int (int, char **)".to_string(),
                 range: Range::create(5, 4, 5, 9),
+                usr: None,
             });
         func_impl.borrow_mut().add_func_call(
             &func_decl_add
@@ -182,6 +191,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         let func_decl_sub = cpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "sub".to_string(),
@@ -189,6 +199,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(12, 5, 12, 8),
+            usr: None,
         });
         let func_decl_multiply = cpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "multiply".to_string(),
@@ -196,6 +207,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(13, 5, 13, 13),
+            usr: None,
         });
         cpp_file.borrow_mut().add_func_decl(FuncCreationArgs {
             name: "divide".to_string(),
@@ -203,6 +215,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(14, 5, 14, 11),
+            usr: None,
         });
         let cpp_class = cpp_file.borrow_mut().add_class("BarClass");
         let func_impl = cpp_class
@@ -213,6 +226,7 @@ mod tests {
                 base_qualified_name: Some("".to_string()),
                 qualified_type: "int (int, char **)".to_string(),
                 range: Range::create(5, 4, 5, 9),
+                usr: None,
             });
         func_impl.borrow_mut().add_func_call(
             &func_decl_add
@@ -252,6 +266,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         let cpp_class = cpp_file.borrow_mut().add_class("BarClass");
         let func_impl = cpp_class
@@ -262,6 +277,7 @@ mod tests {
                 base_qualified_name: Some("".to_string()),
                 qualified_type: "int (int, char **)".to_string(),
                 range: Range::create(5, 4, 5, 9),
+                usr: None,
             });
         func_impl.borrow_mut().add_func_call(
             &func_decl
@@ -291,6 +307,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         let cpp_class = cpp_file.borrow_mut().add_class("BarClass");
         let func_impl = cpp_class
@@ -301,6 +318,7 @@ mod tests {
                 base_qualified_name: Some("".to_string()),
                 qualified_type: "int (int, char **)".to_string(),
                 range: Range::create(5, 4, 5, 9),
+                usr: None,
             });
         func_impl.borrow_mut().add_func_call(
             &func_decl
@@ -330,6 +348,7 @@ mod tests {
             base_qualified_name: None,
             qualified_type: "int (int, int)".to_string(),
             range: Range::create(11, 5, 11, 8),
+            usr: None,
         });
         let cpp_class = cpp_file.borrow_mut().add_class("BarClass");
         let func_impl = cpp_class
@@ -340,6 +359,7 @@ mod tests {
                 base_qualified_name: Some("".to_string()),
                 qualified_type: "int (int, char **)".to_string(),
                 range: Range::create(5, 4, 5, 9),
+                usr: None,
             });
         func_impl.borrow_mut().add_func_call(
             &func_decl
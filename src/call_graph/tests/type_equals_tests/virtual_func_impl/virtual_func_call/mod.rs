@@ -27,6 +27,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         let func_impl = cpp_class
             .borrow_mut()
@@ -36,6 +37,7 @@ mod tests {
                 base_qualified_name: Some("".to_string()),
                 qualified_type: "int (int, char **)".to_string(),
                 range: Range::create(5, 4, 5, 9),
+                usr: None,
             });
         func_impl.borrow_mut().add_virtual_func_call(
             &func_decl
@@ -70,6 +72,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         let func_impl = cpp_class
             .borrow_mut()
@@ -79,6 +82,7 @@ mod tests {
                 base_qualified_name: Some("".to_string()),
                 qualified_type: "int (int, char **)".to_string(),
                 range: Range::create(5, 4, 5, 9),
+                usr: None,
             });
         func_impl.borrow_mut().get_or_add_virtual_func_call(
             &func_decl
@@ -121,6 +125,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         let func_decl_sub = cpp_class
             .borrow_mut()
@@ -130,6 +135,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3subEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(12, 5, 12, 8),
+                usr: None,
             });
         let func_decl_multiply = cpp_class
             .borrow_mut()
@@ -139,6 +145,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3multiplyEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(13, 5, 13, 13),
+                usr: None,
             });
         let func_decl_divide = cpp_class
             .borrow_mut()
@@ -148,6 +155,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3divideEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(14, 5, 14, 11),
+                usr: None,
             });
         let func_impl = cpp_class
             .borrow_mut()
@@ -157,6 +165,7 @@ mod tests {
                 base_qualified_name: Some("".to_string()),
                 qualified_type: "int (int, char **)".to_string(),
                 range: Range::create(5, 4, 5, 9),
+                usr: None,
             });
         func_impl.borrow_mut().add_virtual_func_call(
             &func_decl_add
@@ -213,6 +222,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         let func_decl_sub = cpp_class
             .borrow_mut()
@@ -222,6 +232,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3subEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(12, 5, 12, 8),
+                usr: None,
             });
         let func_decl_multiply = cpp_class
             .borrow_mut()
@@ -231,6 +242,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3multiplyEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(13, 5, 13, 13),
+                usr: None,
             });
         cpp_class
             .borrow_mut()
@@ -240,6 +252,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3divideEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(14, 5, 14, 11),
+                usr: None,
             });
         let func_impl = cpp_class
             .borrow_mut()
@@ -249,6 +262,7 @@ mod tests {
                 base_qualified_name: Some("".to_string()),
                 qualified_type: "int (int, char **)".to_string(),
                 range: Range::create(5, 4, 5, 9),
+                usr: None,
             });
         func_impl.borrow_mut().add_virtual_func_call(
             &func_decl_add
@@ -297,6 +311,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         let func_impl = cpp_class
             .borrow_mut()
@@ -306,6 +321,7 @@ mod tests {
                 base_qualified_name: Some("".to_string()),
                 qualified_type: "int (int, char **)".to_string(),
                 range: Range::create(5, 4, 5, 9),
+                usr: None,
             });
         func_impl.borrow_mut().add_virtual_func_call(
             &func_decl
@@ -340,6 +356,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         let func_impl = cpp_class
             .borrow_mut()
@@ -349,6 +366,7 @@ mod tests {
                 base_qualified_name: Some("".to_string()),
                 qualified_type: "int (int, char **)".to_string(),
                 range: Range::create(5, 4, 5, 9),
+                usr: None,
             });
         func_impl.borrow_mut().add_virtual_func_call(
             &func_decl
@@ -383,6 +401,7 @@ mod tests {
                 base_qualified_name: Some("__ZN3foo3addEii".to_string()),
                 qualified_type: "int (int, int)".to_string(),
                 range: Range::create(11, 5, 11, 8),
+                usr: None,
             });
         let func_impl = cpp_class
             .borrow_mut()
@@ -392,6 +411,7 @@ mod tests {
                 base_qualified_name: Some("".to_string()),
                 qualified_type: "int (int, char **)".to_string(),
                 range: Range::create(5, 4, 5, 9),
+                usr: None,
             });
         func_impl.borrow_mut().add_virtual_func_call(
             &func_decl
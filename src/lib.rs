@@ -1,36 +1,57 @@
-use std::{cell::RefCell, path::PathBuf, rc::Rc, time::Instant};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    path::PathBuf,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
 
 use ast_reader::{
+    clang_ast_element::ClangAstElement,
+    clang_ast_format::ClangAstFormat,
+    clang_ast_json_parser::ClangAstJsonParserImpl,
     clang_ast_parser::{ClangAstParser, ClangAstParserImpl},
-    compile_commands_reader::read_compile_commands_json_file,
+    compile_commands_reader::{read_compile_commands_json_file, CompileCommand},
 };
 use call_graph::{
     ast_walker::clang_ast_walker::walk_ast_2_func_call_db,
-    database::database_sqlite::DatabaseSqlite,
-};
-use process::{
-    clang_compile2ast_call::clang_compile2ast_call, terminal_process::TerminalProcess, Process,
+    database::{database_sqlite::DatabaseSqlite, database_update::get_database_update},
 };
+use process::{clang_compile2ast_call::compile_command2ast_process, Process};
 
 pub mod ast_reader;
 pub mod call_graph;
 #[macro_use]
 pub mod macros;
 pub mod location;
+pub mod lsp;
 pub mod process;
 
 pub fn run_ast_parser(
     compile_commands_json: &PathBuf,
     db: Option<Rc<RefCell<DatabaseSqlite>>>,
     ignored_namespaces: &Vec<String>,
+    jobs: usize,
+    ast_format: ClangAstFormat,
+    implicit_destructor_calls: bool,
+    bind_unresolved_calls: bool,
 ) {
     let start_time_all = Instant::now();
 
-    let entries = if read_compile_commands_json_file(compile_commands_json).is_some() {
-        read_compile_commands_json_file(compile_commands_json).unwrap()
-    } else {
-        println!("Error reading compile_commands.json file");
-        return;
+    let entries = match read_compile_commands_json_file(compile_commands_json) {
+        Ok(entries) => entries,
+        Err(failures) => {
+            for failure in failures {
+                println!("{}", failure);
+            }
+            println!("Error reading compile_commands.json file");
+            return;
+        }
     };
 
     println!(
@@ -39,65 +60,267 @@ pub fn run_ast_parser(
         compile_commands_json.display()
     );
 
-    for entry in entries {
-        let timer = Instant::now();
-        let mut sub_timer = Instant::now();
+    analyze_compile_commands(
+        &entries,
+        db.as_ref(),
+        ignored_namespaces,
+        jobs,
+        ast_format,
+        implicit_destructor_calls,
+        bind_unresolved_calls,
+    );
 
-        let mut terminal_process =
-            Box::new(TerminalProcess::new(clang_compile2ast_call(&entry.command)));
+    if let Some(db_ref) = db.as_ref() {
+        link_func_calls(db_ref);
+    }
 
-        if !terminal_process.process() {
-            println!("Error code returned while processing file {}", entry.file);
+    let elapsed_all = start_time_all.elapsed();
+    println!("Elapsed time: {:?}", elapsed_all);
+}
+
+pub fn run_ast_parser_update(
+    compile_commands_json: &PathBuf,
+    db: Rc<RefCell<DatabaseSqlite>>,
+    ignored_namespaces: &Vec<String>,
+    jobs: usize,
+    ast_format: ClangAstFormat,
+    implicit_destructor_calls: bool,
+    bind_unresolved_calls: bool,
+) {
+    let start_time_all = Instant::now();
+
+    let entries = match read_compile_commands_json_file(compile_commands_json) {
+        Ok(entries) => entries,
+        Err(failures) => {
+            for failure in failures {
+                println!("{}", failure);
+            }
+            println!("Error reading compile_commands.json file");
+            return;
         }
+    };
+
+    let database_update = get_database_update(&db.borrow(), &entries);
+    println!(
+        "Found {} entries in file {}, {} outdated, {} removed and {} changed headers",
+        entries.len(),
+        compile_commands_json.display(),
+        database_update.outdated_cpp_files.len(),
+        database_update.removed_cpp_files.len(),
+        database_update.changed_hpp_files.len()
+    );
 
-        let elapsed_compiler = sub_timer.elapsed();
+    for removed_cpp_file in database_update.removed_cpp_files.iter() {
+        db.borrow()
+            .remove_cpp_file_and_depending_content(removed_cpp_file);
+    }
+    for changed_hpp_file in database_update.changed_hpp_files.iter() {
+        db.borrow()
+            .remove_hpp_file_and_depending_content(changed_hpp_file);
+    }
+
+    let outdated_entries: Vec<CompileCommand> = entries
+        .into_iter()
+        .filter(|entry| database_update.outdated_cpp_files.contains(&entry.file))
+        .collect();
+    for entry in outdated_entries.iter() {
+        db.borrow()
+            .remove_cpp_file_and_depending_content(&entry.file);
+    }
+
+    analyze_compile_commands(
+        &outdated_entries,
+        Some(&db),
+        ignored_namespaces,
+        jobs,
+        ast_format,
+        implicit_destructor_calls,
+        bind_unresolved_calls,
+    );
 
-        if !terminal_process.has_next_line() {
-            println!("Error processing file: {}", entry.file);
-            continue;
+    link_func_calls(&db);
+
+    let elapsed_all = start_time_all.elapsed();
+    println!("Elapsed time: {:?}", elapsed_all);
+}
+
+struct ParsedCompileCommand {
+    file: String,
+    ast: Option<VecDeque<ClangAstElement>>,
+    timer: Instant,
+    elapsed_compiler: Duration,
+    elapsed_parser: Duration,
+}
+
+fn analyze_compile_commands(
+    entries: &[CompileCommand],
+    db: Option<&Rc<RefCell<DatabaseSqlite>>>,
+    ignored_namespaces: &Vec<String>,
+    jobs: usize,
+    ast_format: ClangAstFormat,
+    implicit_destructor_calls: bool,
+    bind_unresolved_calls: bool,
+) {
+    if jobs <= 1 {
+        for entry in entries {
+            walk_parsed_compile_command(
+                parse_compile_command(entry, ast_format),
+                db,
+                ignored_namespaces,
+                implicit_destructor_calls,
+                bind_unresolved_calls,
+            );
         }
+        return;
+    }
 
-        while !terminal_process
-            .fetch_next_line()
-            .starts_with("TranslationUnitDecl")
-            && terminal_process.has_next_line()
-        {
-            terminal_process.get_next_line();
+    // The database isn't thread safe, so only clang and the AST parser run in the worker
+    // threads, while the current thread walks all parsed ASTs into the database. A worker waits
+    // with its parsed AST until it is taken, so at most one AST per worker is held in memory.
+    let next_entry_index = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::sync_channel(0);
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let sender = sender.clone();
+            let next_entry_index = &next_entry_index;
+            scope.spawn(move || loop {
+                let entry_index = next_entry_index.fetch_add(1, Ordering::SeqCst);
+                if entry_index >= entries.len() {
+                    break;
+                }
+                if sender
+                    .send(parse_compile_command(&entries[entry_index], ast_format))
+                    .is_err()
+                {
+                    break;
+                }
+            });
         }
+        drop(sender);
 
-        if !terminal_process.has_next_line() {
-            println!("Process didn't return AST output. File: {}", entry.file);
-            continue;
+        for parsed_compile_command in receiver {
+            walk_parsed_compile_command(
+                parsed_compile_command,
+                db,
+                ignored_namespaces,
+                implicit_destructor_calls,
+                bind_unresolved_calls,
+            );
         }
+    });
+}
 
-        sub_timer = Instant::now();
+fn parse_compile_command(
+    entry: &CompileCommand,
+    ast_format: ClangAstFormat,
+) -> ParsedCompileCommand {
+    let timer = Instant::now();
+    let mut sub_timer = Instant::now();
 
-        let mut parser: ClangAstParserImpl = ClangAstParserImpl::new(terminal_process);
-        let ast = parser.parse_ast();
+    let mut parsed_compile_command = ParsedCompileCommand {
+        file: entry.file.clone(),
+        ast: None,
+        timer,
+        elapsed_compiler: Duration::ZERO,
+        elapsed_parser: Duration::ZERO,
+    };
+
+    let mut terminal_process = Box::new(compile_command2ast_process(entry, ast_format));
 
-        let elapsed_parser = sub_timer.elapsed();
+    if !terminal_process.process() {
+        println!("Error starting the compiler for file {}", entry.file);
+    }
 
-        sub_timer = Instant::now();
+    if !terminal_process.has_next_line() {
+        println!("Error processing file: {}", entry.file);
+        report_exit_status(terminal_process.is_successful(), entry);
+        return parsed_compile_command;
+    }
 
-        if let (Some(ast), Some(db_ref)) = (ast, db.as_ref()) {
-            walk_ast_2_func_call_db(&entry.file, ast, db_ref.clone(), ignored_namespaces);
+    let ast_start = match ast_format {
+        ClangAstFormat::Text => "TranslationUnitDecl",
+        ClangAstFormat::Json => "{",
+    };
+    while !terminal_process.fetch_next_line().starts_with(ast_start)
+        && terminal_process.has_next_line()
+    {
+        terminal_process.get_next_line();
+    }
+
+    // As the output is streamed, this only covers the time until the AST output starts.
+    parsed_compile_command.elapsed_compiler = sub_timer.elapsed();
+
+    if !terminal_process.has_next_line() {
+        println!("Process didn't return AST output. File: {}", entry.file);
+        report_exit_status(terminal_process.is_successful(), entry);
+        return parsed_compile_command;
+    }
+
+    sub_timer = Instant::now();
+
+    let is_successful = match ast_format {
+        ClangAstFormat::Text => {
+            let mut parser = ClangAstParserImpl::new(terminal_process);
+            parsed_compile_command.ast = parser.parse_ast();
+            parser.is_process_successful()
+        }
+        ClangAstFormat::Json => {
+            let mut parser = ClangAstJsonParserImpl::new(terminal_process);
+            parsed_compile_command.ast = parser.parse_ast();
+            parser.is_process_successful()
         }
+    };
+    report_exit_status(is_successful, entry);
+
+    parsed_compile_command.elapsed_parser = sub_timer.elapsed();
+
+    parsed_compile_command
+}
 
-        let elapsed_walking = sub_timer.elapsed();
-        let elapsed = timer.elapsed();
+fn report_exit_status(is_successful: bool, entry: &CompileCommand) {
+    if !is_successful {
+        println!("Error code returned while processing file {}", entry.file);
+    }
+}
+
+fn walk_parsed_compile_command(
+    parsed_compile_command: ParsedCompileCommand,
+    db: Option<&Rc<RefCell<DatabaseSqlite>>>,
+    ignored_namespaces: &Vec<String>,
+    implicit_destructor_calls: bool,
+    bind_unresolved_calls: bool,
+) {
+    let sub_timer = Instant::now();
 
-        println!(
-            "Handled file in {} total, {} compiler, {} parsing and {} AST walking of File: {}",
-            duration2str(elapsed),
-            duration2str(elapsed_compiler),
-            duration2str(elapsed_parser),
-            duration2str(elapsed_walking),
-            entry.file
+    if let (Some(ast), Some(db_ref)) = (parsed_compile_command.ast, db) {
+        walk_ast_2_func_call_db(
+            &parsed_compile_command.file,
+            ast,
+            db_ref.clone(),
+            ignored_namespaces,
+            implicit_destructor_calls,
+            bind_unresolved_calls,
         );
     }
 
-    let elapsed_all = start_time_all.elapsed();
-    println!("Elapsed time: {:?}", elapsed_all);
+    let elapsed_walking = sub_timer.elapsed();
+    let elapsed = parsed_compile_command.timer.elapsed();
+
+    println!(
+        "Handled file in {} total, {} compiler, {} parsing and {} AST walking of File: {}",
+        duration2str(elapsed),
+        duration2str(parsed_compile_command.elapsed_compiler),
+        duration2str(parsed_compile_command.elapsed_parser),
+        duration2str(elapsed_walking),
+        parsed_compile_command.file
+    );
+}
+
+fn link_func_calls(db: &Rc<RefCell<DatabaseSqlite>>) {
+    let timer = Instant::now();
+    db.borrow().link_func_calls();
+    println!("Linked function calls in {}", duration2str(timer.elapsed()));
 }
 
 fn duration2str(duration: std::time::Duration) -> String {
@@ -1,7 +1,7 @@
 use serde::Deserialize;
 use serde::Serialize;
 
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Position {
     pub line: usize,
     pub column: usize,
@@ -26,6 +26,20 @@ impl Position {
     }
 }
 
+pub fn split_file_position(input: &str) -> Option<(&str, Position)> {
+    // Split from the right, so that Windows drive letters stay part of the file name.
+    let mut parts = input.rsplitn(3, ':');
+    let column = parts.next()?.parse::<usize>().ok()?;
+    let line = parts.next()?.parse::<usize>().ok()?;
+    let file = parts.next()?;
+
+    if file.is_empty() {
+        return None;
+    }
+
+    Some((file, Position::new(line, column)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,4 +67,19 @@ mod tests {
         other = Position::new(2, 2);
         assert!(!position.is_position_same_or_after(&other));
     }
+
+    #[test]
+    fn test_split_file_position() {
+        assert_eq!(
+            split_file_position("main.cpp:3:5"),
+            Some(("main.cpp", Position::new(3, 5)))
+        );
+        assert_eq!(
+            split_file_position("C:\\foo\\main.cpp:3:5"),
+            Some(("C:\\foo\\main.cpp", Position::new(3, 5)))
+        );
+        assert_eq!(split_file_position("foo::bar"), None);
+        assert_eq!(split_file_position("main.cpp:3"), None);
+        assert_eq!(split_file_position(":3:5"), None);
+    }
 }
@@ -3,7 +3,7 @@ use serde::Serialize;
 
 use super::position::Position;
 
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Range {
     pub start: Position,
     pub end: Position,
@@ -0,0 +1,150 @@
+use std::{
+    io::{BufRead, Write},
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+// Reads one message framed by a "Content-Length" header. Returns `Ok(None)` at the end of the
+// input and an error for a malformed message, after which the next message can still be read.
+pub fn read_message(reader: &mut dyn BufRead) -> Result<Option<Value>, String> {
+    let mut content_length: Option<usize> = None;
+    loop {
+        let mut line = String::new();
+        match reader.read_line(&mut line) {
+            Ok(0) | Err(_) => return Ok(None),
+            Ok(_) => {}
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("Content-Length") {
+                content_length = value.trim().parse().ok();
+            }
+        }
+    }
+
+    let content_length = match content_length {
+        Some(content_length) => content_length,
+        None => return Err("Missing Content-Length header".to_string()),
+    };
+    let mut content = vec![0; content_length];
+    if reader.read_exact(&mut content).is_err() {
+        return Ok(None);
+    }
+    match serde_json::from_slice(&content) {
+        Ok(message) => Ok(Some(message)),
+        Err(error) => Err(format!("Invalid message content: {}", error)),
+    }
+}
+
+pub fn write_message(writer: &mut dyn Write, message: &Value) {
+    let content = message.to_string();
+    write!(
+        writer,
+        "Content-Length: {}\r\n\r\n{}",
+        content.len(),
+        content
+    )
+    .unwrap();
+    writer.flush().unwrap();
+}
+
+pub fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let path = uri.strip_prefix("file://")?;
+
+    let mut decoded = Vec::new();
+    let mut bytes = path.bytes();
+    while let Some(byte) = bytes.next() {
+        if byte == b'%' {
+            let hex = [bytes.next()?, bytes.next()?];
+            decoded.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+        } else {
+            decoded.push(byte);
+        }
+    }
+
+    Some(PathBuf::from(String::from_utf8(decoded).ok()?))
+}
+
+pub fn path_to_uri(path: &Path) -> String {
+    let mut uri = "file://".to_string();
+    for byte in path.to_string_lossy().bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-_.~".contains(&byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{:02X}", byte));
+        }
+    }
+
+    uri
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_read_and_write_message() {
+        let mut output = Vec::new();
+        write_message(
+            &mut output,
+            &json!({"jsonrpc": "2.0", "id": 1, "result": null}),
+        );
+        write_message(&mut output, &json!({"jsonrpc": "2.0", "method": "exit"}));
+        assert!(String::from_utf8(output.clone())
+            .unwrap()
+            .starts_with("Content-Length: 38\r\n\r\n{"));
+
+        let mut input = Cursor::new(output);
+        assert_eq!(
+            read_message(&mut input),
+            Ok(Some(json!({"jsonrpc": "2.0", "id": 1, "result": null})))
+        );
+        assert_eq!(
+            read_message(&mut input),
+            Ok(Some(json!({"jsonrpc": "2.0", "method": "exit"})))
+        );
+        assert_eq!(read_message(&mut input), Ok(None));
+    }
+
+    #[test]
+    fn test_read_message_with_content_type() {
+        let mut input = Cursor::new(
+            "Content-Type: application/vscode-jsonrpc; charset=utf-8\r\ncontent-length: 2\r\n\r\n{}"
+                .as_bytes(),
+        );
+        assert_eq!(read_message(&mut input), Ok(Some(json!({}))));
+
+        let mut input = Cursor::new("Content-Type: text\r\n\r\n{}".as_bytes());
+        assert!(read_message(&mut input).is_err());
+    }
+
+    #[test]
+    fn test_read_message_after_malformed_message() {
+        let mut input =
+            Cursor::new("Content-Length: 3\r\n\r\n{]}Content-Length: 2\r\n\r\n{}".as_bytes());
+        assert!(read_message(&mut input).is_err());
+        assert_eq!(read_message(&mut input), Ok(Some(json!({}))));
+        assert_eq!(read_message(&mut input), Ok(None));
+    }
+
+    #[test]
+    fn test_uri_to_path_and_back() {
+        assert_eq!(
+            uri_to_path("file:///home/user/my%20project/file.cpp"),
+            Some(PathBuf::from("/home/user/my project/file.cpp"))
+        );
+        assert_eq!(uri_to_path("untitled:Untitled-1"), None);
+        assert_eq!(
+            path_to_uri(Path::new("/home/user/my project/file.cpp")),
+            "file:///home/user/my%20project/file.cpp"
+        );
+    }
+}
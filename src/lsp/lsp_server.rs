@@ -0,0 +1,720 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fs,
+    io::{BufRead, Write},
+    path::PathBuf,
+    rc::Rc,
+};
+
+use serde_json::{json, Value};
+
+use crate::{
+    call_graph::{
+        data_structure::{
+            func_structure::{FuncMentionType, FuncStructure},
+            FuncBasics, FuncImplBasics, VirtualFuncBasics,
+        },
+        database::database_sqlite::DatabaseSqlite,
+        function_search::{
+            function_occurrence::FunctionOccurrence,
+            impact_analysis::get_func_callers_including_base,
+            virtual_call_targets::VirtualCallTargets,
+        },
+    },
+    location::{position::Position, range::Range},
+};
+
+use super::lsp_message::{path_to_uri, read_message, uri_to_path, write_message};
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+const SYMBOL_KIND_METHOD: u64 = 6;
+const SYMBOL_KIND_FUNCTION: u64 = 12;
+
+// A language server answering call hierarchy requests from the database. Relative file names
+// of the database are resolved against the root directory, which should be the directory the
+// database was created from.
+pub struct LspServer {
+    db: DatabaseSqlite,
+    root_dir: PathBuf,
+    is_shutdown: bool,
+    // The lines of the source files, needed to convert between byte columns and LSP characters.
+    source_lines: RefCell<HashMap<String, Option<Vec<String>>>>,
+}
+
+impl LspServer {
+    pub fn new(db: DatabaseSqlite, root_dir: PathBuf) -> Self {
+        LspServer {
+            db,
+            root_dir,
+            is_shutdown: false,
+            source_lines: RefCell::new(HashMap::new()),
+        }
+    }
+
+    // Handles messages until the client sends "exit" or closes the input.
+    pub fn run(&mut self, reader: &mut dyn BufRead, writer: &mut dyn Write) {
+        loop {
+            let message = match read_message(reader) {
+                Ok(Some(message)) => message,
+                Ok(None) => return,
+                Err(error) => {
+                    // The output is reserved for the protocol, so the error is logged to stderr.
+                    eprintln!("Skipping malformed message: {}", error);
+                    write_message(
+                        writer,
+                        &json!({
+                            "jsonrpc": "2.0",
+                            "id": null,
+                            "error": {"code": PARSE_ERROR, "message": error},
+                        }),
+                    );
+                    continue;
+                }
+            };
+            if message["method"] == "exit" {
+                return;
+            }
+            if let Some(response) = self.handle_message(&message) {
+                write_message(writer, &response);
+            }
+        }
+    }
+
+    // Returns the response for requests, notifications are answered with `None`.
+    pub fn handle_message(&mut self, message: &Value) -> Option<Value> {
+        let id = message.get("id")?.clone();
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+
+        let result = match method {
+            _ if self.is_shutdown => Err((
+                INVALID_REQUEST,
+                format!("Server is shut down, cannot handle '{}'", method),
+            )),
+            "initialize" => Ok(json!({
+                "capabilities": {
+                    "callHierarchyProvider": true,
+                },
+                "serverInfo": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+            })),
+            "shutdown" => {
+                self.is_shutdown = true;
+                Ok(Value::Null)
+            }
+            "textDocument/prepareCallHierarchy" => self.prepare_call_hierarchy(params),
+            "callHierarchy/incomingCalls" => self.get_incoming_calls(params),
+            "callHierarchy/outgoingCalls" => self.get_outgoing_calls(params),
+            _ => Err((METHOD_NOT_FOUND, format!("Unknown method '{}'", method))),
+        };
+
+        Some(match result {
+            Ok(result) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+            Err((code, message)) => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": {"code": code, "message": message},
+            }),
+        })
+    }
+
+    pub fn is_shutdown(&self) -> bool {
+        self.is_shutdown
+    }
+
+    fn prepare_call_hierarchy(&self, params: &Value) -> Result<Value, (i64, String)> {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let file = match self.get_db_file_name(uri) {
+            Some(file) => file,
+            None => return Ok(Value::Null),
+        };
+        let position = self.get_position(&file, &params["position"])?;
+
+        // A call within a function matches both, only the innermost mention is used.
+        let matching_funcs = self.db.get_matching_funcs(&file, &position);
+        let innermost_funcs = matching_funcs.iter().filter(|func| {
+            let range = func.borrow().get_range().clone();
+            !matching_funcs.iter().any(|other| {
+                let other_range = other.borrow().get_range().clone();
+                other_range != range && range.is_within_range_of(&other_range)
+            })
+        });
+
+        let mut funcs: Vec<FunctionOccurrence> = Vec::new();
+        for func in innermost_funcs {
+            for func in self.get_called_funcs(&file, func) {
+                if !funcs.contains(&func) {
+                    funcs.push(func);
+                }
+            }
+        }
+
+        Ok(Value::Array(
+            funcs
+                .iter()
+                .map(|func| self.create_call_hierarchy_item(func))
+                .collect(),
+        ))
+    }
+
+    fn get_incoming_calls(&self, params: &Value) -> Result<Value, (i64, String)> {
+        let func = get_func_from_item(&params["item"])?;
+        let mut called_names = vec![func.get_qualified_name().to_string()];
+        if func.get_func_type() == Some(FuncMentionType::VirtualFuncImpl)
+            || func.get_func_type() == Some(FuncMentionType::VirtualFuncDecl)
+        {
+            called_names.push(func.get_base_qualified_name().to_string());
+        }
+
+        let mut callers: Vec<FunctionOccurrence> = Vec::new();
+        for caller in get_func_callers_including_base(&self.db, &func) {
+            if !callers.contains(&caller) {
+                callers.push(caller);
+            }
+        }
+
+        let mut incoming_calls = Vec::new();
+        for caller in callers {
+            let mut calls = caller.func.borrow_mut().get_func_calls().clone();
+            calls.append(&mut caller.func.borrow_mut().get_virtual_func_calls().clone());
+            let from_ranges: Vec<Value> = calls
+                .iter()
+                .filter(|call| {
+                    called_names.contains(&call.borrow().get_qualified_name().to_string())
+                })
+                .map(|call| self.get_lsp_range(&caller.file, call.borrow().get_range()))
+                .collect();
+
+            incoming_calls.push(json!({
+                "from": self.create_call_hierarchy_item(&caller),
+                "fromRanges": from_ranges,
+            }));
+        }
+
+        Ok(Value::Array(incoming_calls))
+    }
+
+    fn get_outgoing_calls(&self, params: &Value) -> Result<Value, (i64, String)> {
+        let item = &params["item"];
+        let func = get_func_from_item(item)?;
+        let file = item["data"]["file"].as_str().unwrap_or_default();
+
+        // The item only carries a copy of the function, the calls are loaded from the database.
+        let func_impl = self
+            .db
+            .get_matching_funcs(file, &func.get_range().start)
+            .into_iter()
+            .find(|matching_func| {
+                let matching_func = matching_func.borrow();
+                matching_func.get_func_type() == func.get_func_type()
+                    && matching_func.get_id() == func.get_id()
+            });
+        let func_impl = match func_impl {
+            Some(func_impl) => func_impl,
+            None => return Ok(Value::Array(Vec::new())),
+        };
+
+        let mut calls = func_impl.borrow_mut().get_func_calls().clone();
+        calls.append(&mut func_impl.borrow_mut().get_virtual_func_calls().clone());
+
+        let mut callees: Vec<(FunctionOccurrence, Vec<Value>)> = Vec::new();
+        for call in calls {
+            let call_range = self.get_lsp_range(file, call.borrow().get_range());
+            for callee in self.get_call_targets(&call) {
+                match callees.iter_mut().find(|(other, _)| other == &callee) {
+                    Some((_, from_ranges)) => from_ranges.push(call_range.clone()),
+                    None => callees.push((callee, vec![call_range.clone()])),
+                }
+            }
+        }
+
+        Ok(Value::Array(
+            callees
+                .iter()
+                .map(|(callee, from_ranges)| {
+                    json!({
+                        "to": self.create_call_hierarchy_item(callee),
+                        "fromRanges": from_ranges,
+                    })
+                })
+                .collect(),
+        ))
+    }
+
+    // Maps a function mention to the functions it names, e.g. a call to the called function.
+    fn get_called_funcs(
+        &self,
+        file: &str,
+        func: &Rc<RefCell<FuncStructure>>,
+    ) -> Vec<FunctionOccurrence> {
+        let func_type = func.borrow().get_func_type();
+        match func_type {
+            Some(FuncMentionType::FuncImpl) | Some(FuncMentionType::VirtualFuncImpl) => {
+                vec![FunctionOccurrence::new(file.to_string(), func.clone())]
+            }
+            Some(FuncMentionType::FuncCall) | Some(FuncMentionType::VirtualFuncCall) => {
+                self.db.get_func_impls_or_one_decl(&func.borrow())
+            }
+            _ => {
+                let func_impls = self.db.get_func_impls(&*func.borrow());
+                if func_impls.is_empty() && func_type != Some(FuncMentionType::FuncRef) {
+                    vec![FunctionOccurrence::new(file.to_string(), func.clone())]
+                } else {
+                    func_impls
+                }
+            }
+        }
+    }
+
+    fn get_call_targets(&self, call: &Rc<RefCell<FuncStructure>>) -> Vec<FunctionOccurrence> {
+        if call.borrow().get_func_type() == Some(FuncMentionType::VirtualFuncCall) {
+            self.db
+                .get_virtual_func_call_targets(&call.borrow(), VirtualCallTargets::AllOverrides)
+        } else {
+            self.db.get_func_impls_or_one_decl(&call.borrow())
+        }
+    }
+
+    fn get_db_file_name(&self, uri: &str) -> Option<String> {
+        let path = uri_to_path(uri)?;
+
+        let mut candidates = vec![path.to_string_lossy().to_string()];
+        if let Ok(relative_path) = path.strip_prefix(&self.root_dir) {
+            let relative_path = relative_path.to_string_lossy().to_string();
+            candidates.push(format!("./{}", relative_path));
+            candidates.push(relative_path);
+        }
+
+        candidates
+            .into_iter()
+            .find(|file| self.db.has_cpp_file(file) || self.db.has_hpp_file(file))
+    }
+
+    fn get_path(&self, file: &str) -> PathBuf {
+        let file = file.strip_prefix("./").unwrap_or(file);
+        self.root_dir.join(file)
+    }
+
+    fn get_uri(&self, file: &str) -> String {
+        path_to_uri(&self.get_path(file))
+    }
+
+    fn get_source_line(&self, file: &str, line: usize) -> Option<String> {
+        let mut source_lines = self.source_lines.borrow_mut();
+        let lines = source_lines.entry(file.to_string()).or_insert_with(|| {
+            fs::read_to_string(self.get_path(file))
+                .ok()
+                .map(|content| content.lines().map(|line| line.to_string()).collect())
+        });
+        lines.as_ref()?.get(line.checked_sub(1)?).cloned()
+    }
+
+    // LSP positions are zero based, while the database stores the positions of the clang AST.
+    fn get_position(&self, file: &str, position: &Value) -> Result<Position, (i64, String)> {
+        match (position["line"].as_u64(), position["character"].as_u64()) {
+            (Some(line), Some(character)) => {
+                let line = line as usize + 1;
+                let source_line = self.get_source_line(file, line);
+                Ok(Position::new(
+                    line,
+                    get_column(source_line.as_deref(), character as usize),
+                ))
+            }
+            _ => Err((INVALID_PARAMS, "Invalid position".to_string())),
+        }
+    }
+
+    // The end of a database range points one byte past the start of the last token, while the
+    // end of a LSP range points past the whole token.
+    fn get_lsp_range(&self, file: &str, range: &Range) -> Value {
+        let start_line = self.get_source_line(file, range.start.line);
+        let end_line = self.get_source_line(file, range.end.line);
+        json!({
+            "start": {
+                "line": range.start.line.saturating_sub(1),
+                "character": get_lsp_character(start_line.as_deref(), range.start.column),
+            },
+            "end": {
+                "line": range.end.line.saturating_sub(1),
+                "character": get_lsp_end_character(end_line.as_deref(), range.end.column),
+            },
+        })
+    }
+
+    fn create_call_hierarchy_item(&self, func: &FunctionOccurrence) -> Value {
+        let func_structure = func.func.borrow();
+        let qualified_name = func_structure.get_qualified_name();
+        // The qualified name ends with the quoted type, the class is part of the prefix.
+        let kind = match qualified_name.split(" '").next() {
+            Some(name) if name.contains("::") => SYMBOL_KIND_METHOD,
+            _ => SYMBOL_KIND_FUNCTION,
+        };
+        let range = self.get_lsp_range(&func.file, func_structure.get_range());
+        let base_qualified_name = match func_structure.get_func_type() {
+            Some(FuncMentionType::VirtualFuncDecl) | Some(FuncMentionType::VirtualFuncImpl) => {
+                Some(func_structure.get_base_qualified_name())
+            }
+            _ => None,
+        };
+
+        json!({
+            "name": func_structure.get_name(),
+            "kind": kind,
+            "detail": qualified_name,
+            "uri": self.get_uri(&func.file),
+            "range": range,
+            "selectionRange": range,
+            "data": {
+                "file": func.file,
+                "id": func_structure.get_id(),
+                "func_type": func_structure.get_func_type().map(|func_type| func_type as u8),
+                "qualified_name": qualified_name,
+                "base_qualified_name": base_qualified_name,
+                "qual_type": func_structure.get_qual_type(),
+                "usr": func_structure.get_usr(),
+                "range": func_structure.get_range(),
+            },
+        })
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// Clang counts the bytes of a line starting with one, LSP counts UTF-16 code units starting
+// with zero. Without the source line all characters are assumed to be a single byte.
+fn get_lsp_character(source_line: Option<&str>, column: usize) -> usize {
+    let offset = column.saturating_sub(1);
+    match source_line {
+        Some(source_line) => source_line
+            .char_indices()
+            .take_while(|(index, _)| *index < offset)
+            .map(|(_, c)| c.len_utf16())
+            .sum(),
+        None => offset,
+    }
+}
+
+fn get_lsp_end_character(source_line: Option<&str>, column: usize) -> usize {
+    let source_line = match source_line {
+        Some(source_line) => source_line,
+        None => return column.saturating_sub(1),
+    };
+
+    let token_offset = column.saturating_sub(2);
+    let mut token = source_line
+        .char_indices()
+        .skip_while(|(index, _)| *index < token_offset);
+    let token_end = match token.next() {
+        Some((_, c)) if is_identifier_char(c) => token
+            .find(|(_, c)| !is_identifier_char(*c))
+            .map(|(index, _)| index)
+            .unwrap_or(source_line.len()),
+        Some((index, c)) => index + c.len_utf8(),
+        None => source_line.len(),
+    };
+    get_lsp_character(Some(source_line), token_end + 1)
+}
+
+fn get_column(source_line: Option<&str>, character: usize) -> usize {
+    let source_line = match source_line {
+        Some(source_line) => source_line,
+        None => return character + 1,
+    };
+
+    let mut utf16_count = 0;
+    for (index, c) in source_line.char_indices() {
+        if utf16_count >= character {
+            return index + 1;
+        }
+        utf16_count += c.len_utf16();
+    }
+    source_line.len() + 1
+}
+
+fn get_func_from_item(item: &Value) -> Result<FuncStructure, (i64, String)> {
+    let data = &item["data"];
+    let func_type = match data["func_type"].as_u64() {
+        Some(0) => FuncMentionType::FuncDecl,
+        Some(1) => FuncMentionType::FuncImpl,
+        Some(3) => FuncMentionType::VirtualFuncDecl,
+        Some(4) => FuncMentionType::VirtualFuncImpl,
+        _ => return Err((INVALID_PARAMS, "Invalid call hierarchy item".to_string())),
+    };
+    let (id, qualified_name, qual_type) = match (
+        data["id"].as_u64(),
+        data["qualified_name"].as_str(),
+        data["qual_type"].as_str(),
+    ) {
+        (Some(id), Some(qualified_name), Some(qual_type)) => (id, qualified_name, qual_type),
+        _ => return Err((INVALID_PARAMS, "Invalid call hierarchy item".to_string())),
+    };
+    let range: Range = match serde_json::from_value(data["range"].clone()) {
+        Ok(range) => range,
+        Err(_) => return Err((INVALID_PARAMS, "Invalid call hierarchy item".to_string())),
+    };
+
+    Ok(FuncStructure::new(
+        id,
+        None,
+        item["name"].as_str().unwrap_or_default().to_string(),
+        qualified_name.to_string(),
+        data["base_qualified_name"]
+            .as_str()
+            .map(|name| name.to_string()),
+        qual_type.to_string(),
+        data["usr"].as_str().map(|usr| usr.to_string()),
+        range,
+        Some(func_type),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::call_graph::tests::test_database::{add_func_call, add_func_impl};
+
+    use super::*;
+
+    fn create_lsp_test_database() -> DatabaseSqlite {
+        let database_sqlite = DatabaseSqlite::create_in_memory_database();
+        let cpp_file = database_sqlite.get_or_add_cpp_file("./src/file.cpp");
+
+        let mult_impl = add_func_impl(
+            &cpp_file,
+            "mult",
+            "int (int, int)",
+            Range::create(1, 1, 4, 2),
+        );
+        let add_impl = add_func_impl(
+            &cpp_file,
+            "add",
+            "int (int, int)",
+            Range::create(6, 1, 9, 2),
+        );
+        let main_impl = add_func_impl(
+            &cpp_file,
+            "main",
+            "int (int, char **)",
+            Range::create(11, 1, 15, 2),
+        );
+
+        add_func_call(&add_impl, &mult_impl, Range::create(8, 12, 8, 22));
+        add_func_call(&main_impl, &add_impl, Range::create(13, 12, 13, 21));
+        add_func_call(&main_impl, &add_impl, Range::create(14, 12, 14, 21));
+        database_sqlite.link_func_calls();
+
+        database_sqlite
+    }
+
+    fn create_request(id: u64, method: &str, params: Value) -> Value {
+        json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params})
+    }
+
+    fn create_lsp_range(start: (u64, u64), end: (u64, u64)) -> Value {
+        json!({
+            "start": {"line": start.0, "character": start.1},
+            "end": {"line": end.0, "character": end.1},
+        })
+    }
+
+    // Runs the server with the scripted messages of a client and returns all responses.
+    fn run_lsp_session(messages: Vec<Value>) -> Vec<Value> {
+        let mut input = Vec::new();
+        for message in messages.iter() {
+            write_message(&mut input, message);
+        }
+
+        let mut output = Vec::new();
+        let mut server = LspServer::new(create_lsp_test_database(), PathBuf::from("/project"));
+        server.run(&mut Cursor::new(input), &mut output);
+
+        let mut responses = Vec::new();
+        let mut output = Cursor::new(output);
+        while let Ok(Some(response)) = read_message(&mut output) {
+            responses.push(response);
+        }
+
+        responses
+    }
+
+    #[test]
+    fn test_call_hierarchy_session() {
+        let prepare_params = json!({
+            "textDocument": {"uri": "file:///project/src/file.cpp"},
+            "position": {"line": 12, "character": 14},
+        });
+        let responses = run_lsp_session(vec![
+            create_request(1, "initialize", json!({"capabilities": {}})),
+            json!({"jsonrpc": "2.0", "method": "initialized", "params": {}}),
+            create_request(2, "textDocument/prepareCallHierarchy", prepare_params),
+        ]);
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0]["id"], 1);
+        assert_eq!(
+            responses[0]["result"]["capabilities"]["callHierarchyProvider"],
+            true
+        );
+
+        let items = responses[1]["result"].as_array().unwrap();
+        assert_eq!(items.len(), 1);
+        let add_item = items[0].clone();
+        assert_eq!(add_item["name"], "add");
+        assert_eq!(add_item["detail"], "add 'int (int, int)'");
+        assert_eq!(add_item["kind"], SYMBOL_KIND_FUNCTION);
+        assert_eq!(add_item["uri"], "file:///project/src/file.cpp");
+        assert_eq!(add_item["range"], create_lsp_range((5, 0), (8, 1)));
+
+        let responses = run_lsp_session(vec![
+            create_request(3, "callHierarchy/incomingCalls", json!({"item": add_item})),
+            create_request(4, "callHierarchy/outgoingCalls", json!({"item": add_item})),
+            create_request(5, "shutdown", Value::Null),
+            json!({"jsonrpc": "2.0", "method": "exit"}),
+            create_request(6, "shutdown", Value::Null),
+        ]);
+        assert_eq!(responses.len(), 3);
+
+        let incoming_calls = responses[0]["result"].as_array().unwrap();
+        assert_eq!(incoming_calls.len(), 1);
+        assert_eq!(incoming_calls[0]["from"]["name"], "main");
+        assert_eq!(
+            incoming_calls[0]["fromRanges"],
+            json!([
+                create_lsp_range((12, 11), (12, 20)),
+                create_lsp_range((13, 11), (13, 20)),
+            ])
+        );
+
+        let outgoing_calls = responses[1]["result"].as_array().unwrap();
+        assert_eq!(outgoing_calls.len(), 1);
+        assert_eq!(outgoing_calls[0]["to"]["name"], "mult");
+        assert_eq!(
+            outgoing_calls[0]["fromRanges"],
+            json!([create_lsp_range((7, 11), (7, 21))])
+        );
+
+        assert_eq!(
+            responses[2],
+            json!({"jsonrpc": "2.0", "id": 5, "result": null})
+        );
+    }
+
+    #[test]
+    fn test_prepare_call_hierarchy_on_func_impl_and_unknown_file() {
+        let mut server = LspServer::new(create_lsp_test_database(), PathBuf::from("/project"));
+
+        let response = server
+            .handle_message(&create_request(
+                1,
+                "textDocument/prepareCallHierarchy",
+                json!({
+                    "textDocument": {"uri": "file:///project/src/file.cpp"},
+                    "position": {"line": 0, "character": 4},
+                }),
+            ))
+            .unwrap();
+        let items = response["result"].as_array().unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0]["name"], "mult");
+
+        let response = server
+            .handle_message(&create_request(
+                2,
+                "textDocument/prepareCallHierarchy",
+                json!({
+                    "textDocument": {"uri": "file:///project/src/other_file.cpp"},
+                    "position": {"line": 0, "character": 4},
+                }),
+            ))
+            .unwrap();
+        assert_eq!(response["result"], Value::Null);
+    }
+
+    #[test]
+    fn test_handle_message_errors() {
+        let mut server = LspServer::new(create_lsp_test_database(), PathBuf::from("/project"));
+
+        assert_eq!(
+            server.handle_message(&json!({"jsonrpc": "2.0", "method": "textDocument/didOpen"})),
+            None
+        );
+
+        let response = server
+            .handle_message(&create_request(1, "textDocument/hover", json!({})))
+            .unwrap();
+        assert_eq!(response["error"]["code"], METHOD_NOT_FOUND);
+
+        let response = server
+            .handle_message(&create_request(
+                2,
+                "callHierarchy/incomingCalls",
+                json!({"item": {"name": "add"}}),
+            ))
+            .unwrap();
+        assert_eq!(response["error"]["code"], INVALID_PARAMS);
+
+        assert!(!server.is_shutdown());
+        server.handle_message(&create_request(3, "shutdown", Value::Null));
+        assert!(server.is_shutdown());
+
+        let response = server
+            .handle_message(&create_request(
+                4,
+                "initialize",
+                json!({"capabilities": {}}),
+            ))
+            .unwrap();
+        assert_eq!(response["id"], 4);
+        assert_eq!(response["error"]["code"], INVALID_REQUEST);
+    }
+
+    #[test]
+    fn test_run_after_malformed_message() {
+        let mut input = "Content-Length: 3\r\n\r\n{]}".as_bytes().to_vec();
+        write_message(&mut input, &create_request(1, "shutdown", Value::Null));
+
+        let mut output = Vec::new();
+        let mut server = LspServer::new(create_lsp_test_database(), PathBuf::from("/project"));
+        server.run(&mut Cursor::new(input), &mut output);
+
+        let mut output = Cursor::new(output);
+        let response = read_message(&mut output).unwrap().unwrap();
+        assert_eq!(response["id"], Value::Null);
+        assert_eq!(response["error"]["code"], PARSE_ERROR);
+        assert_eq!(
+            read_message(&mut output),
+            Ok(Some(json!({"jsonrpc": "2.0", "id": 1, "result": null})))
+        );
+        assert!(server.is_shutdown());
+    }
+
+    #[test]
+    fn test_convert_columns_and_characters() {
+        // "ä" takes two bytes but one UTF-16 code unit, "𝄞" takes four bytes and two code units.
+        let source_line = "int ä = add(𝄞, value);";
+        assert_eq!(get_lsp_character(Some(source_line), 5), 4);
+        assert_eq!(get_lsp_character(Some(source_line), 7), 5);
+        assert_eq!(get_lsp_character(Some(source_line), 13), 11);
+        assert_eq!(get_lsp_character(Some(source_line), 19), 15);
+        assert_eq!(get_lsp_character(None, 19), 18);
+
+        assert_eq!(get_column(Some(source_line), 4), 5);
+        assert_eq!(get_column(Some(source_line), 5), 7);
+        assert_eq!(get_column(Some(source_line), 15), 19);
+        assert_eq!(get_column(None, 15), 16);
+
+        // The end covers the whole last token, also when it is longer than a single character.
+        assert_eq!(get_lsp_end_character(Some(source_line), 26), 22);
+        assert_eq!(get_lsp_end_character(Some(source_line), 21), 21);
+        assert_eq!(get_lsp_end_character(None, 26), 25);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod lsp_message;
+pub mod lsp_server;
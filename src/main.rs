@@ -1,8 +1,30 @@
 use std::{cell::RefCell, path::PathBuf, rc::Rc};
 
-use clap::{Args, Parser, Subcommand};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use rust_clang_call_graph::{
-    call_graph::database::database_sqlite::DatabaseSqlite, run_ast_parser,
+    ast_reader::clang_ast_format::ClangAstFormat,
+    call_graph::{
+        data_structure::{FuncBasics, FuncImplBasics},
+        database::database_sqlite::DatabaseSqlite,
+        export::dot_graph::DotGraph,
+        function_search::{
+            call_cycles::get_call_cycles,
+            call_tree::get_call_tree,
+            call_tree::CallTreeNode,
+            dead_code::{
+                get_dead_func_impls, get_exported_func_impls, get_gtest_test_func_impls,
+                get_main_func_impls,
+            },
+            function_occurrence::FunctionOccurrence,
+            gtest_tests::{get_funcs_covered_by, get_gtest_test, get_tests_reaching},
+            impact_analysis::{
+                get_impacted_funcs, parse_changed_lines, parse_unified_diff, ChangedLines,
+            },
+            virtual_call_targets::VirtualCallTargets,
+        },
+    },
+    lsp::lsp_server::LspServer,
+    run_ast_parser, run_ast_parser_update,
 };
 
 #[derive(Parser)]
@@ -17,8 +39,32 @@ struct Cli {
 enum Commands {
     /// Create a new call graph database
     NewDatabase(NewDatabaseArgs),
+    /// Update an existing call graph database with all changed files
+    Update(UpdateArgs),
     /// Make a dry run of the AST parser
     DryRun(DryRunArgs),
+    /// List all functions calling a function
+    Callers(CallersArgs),
+    /// List all places where a function is referenced without being called, e.g. as callback
+    References(ReferencesArgs),
+    /// Show the tree of all functions called by a function
+    Callees(CalleesArgs),
+    /// Export the call graph into another format
+    Export(ExportArgs),
+    /// List all files including a file and all headers included by it
+    Includes(IncludesArgs),
+    /// List all functions affected by changed lines, including their direct and indirect callers
+    Impact(ImpactArgs),
+    /// List all gtest tests calling a function directly or indirectly
+    TestsReaching(TestsReachingArgs),
+    /// List all functions called directly or indirectly by a gtest test
+    CoveredBy(CoveredByArgs),
+    /// List all recursive and mutually recursive functions with the calls forming the cycles
+    Cycles(CyclesArgs),
+    /// List all function implementations which can't be reached from the entry points
+    DeadCode(DeadCodeArgs),
+    /// Serve the call hierarchy of the database as language server over stdio
+    ServeLsp(ServeLspArgs),
 }
 
 #[derive(Args)]
@@ -32,6 +78,43 @@ struct NewDatabaseArgs {
     /// Namespaces to ignore
     #[arg(short, long, value_name = "NAMESPACE", default_values_t = ["std".to_string(), "boost".to_string(), "mpl_".to_string()])]
     ignored_namespaces: Vec<String>,
+    /// Number of translation units compiled and parsed in parallel (one AST in memory per job)
+    #[arg(short, long, value_name = "N", default_value_t = 1)]
+    jobs: usize,
+    /// The clang AST dump format to read
+    #[arg(long, value_enum, default_value_t = AstFormatArg::Text)]
+    ast_format: AstFormatArg,
+    /// Record destructor calls of local variables at the end of their scope
+    #[arg(long)]
+    implicit_destructor_calls: bool,
+    /// Bind unresolved calls in templates to the calls of their instantiations
+    #[arg(long)]
+    bind_unresolved_calls: bool,
+}
+
+#[derive(Args)]
+struct UpdateArgs {
+    /// The SQLite database file to update
+    #[arg(short, long, value_name = "FILE")]
+    database_path: PathBuf,
+    /// The compile_commands.json file to use
+    #[arg(short, long, value_name = "FILE")]
+    compile_commands_json: PathBuf,
+    /// Namespaces to ignore
+    #[arg(short, long, value_name = "NAMESPACE", default_values_t = ["std".to_string(), "boost".to_string(), "mpl_".to_string()])]
+    ignored_namespaces: Vec<String>,
+    /// Number of translation units compiled and parsed in parallel (one AST in memory per job)
+    #[arg(short, long, value_name = "N", default_value_t = 1)]
+    jobs: usize,
+    /// The clang AST dump format to read
+    #[arg(long, value_enum, default_value_t = AstFormatArg::Text)]
+    ast_format: AstFormatArg,
+    /// Record destructor calls of local variables at the end of their scope
+    #[arg(long)]
+    implicit_destructor_calls: bool,
+    /// Bind unresolved calls in templates to the calls of their instantiations
+    #[arg(long)]
+    bind_unresolved_calls: bool,
 }
 
 #[derive(Args)]
@@ -39,6 +122,209 @@ struct DryRunArgs {
     /// The compile_commands.json file to use
     #[arg(short, long, value_name = "FILE")]
     compile_commands_json: Option<PathBuf>,
+    /// Number of translation units compiled and parsed in parallel (one AST in memory per job)
+    #[arg(short, long, value_name = "N", default_value_t = 1)]
+    jobs: usize,
+    /// The clang AST dump format to read
+    #[arg(long, value_enum, default_value_t = AstFormatArg::Text)]
+    ast_format: AstFormatArg,
+}
+
+#[derive(Args)]
+struct CallersArgs {
+    /// The SQLite database file to query
+    #[arg(short, long, value_name = "FILE")]
+    database_path: PathBuf,
+    /// Qualified name or position (file:line:column) of the function
+    #[arg(short, long, value_name = "FUNCTION")]
+    function: String,
+}
+
+#[derive(Args)]
+struct ReferencesArgs {
+    /// The SQLite database file to query
+    #[arg(short, long, value_name = "FILE")]
+    database_path: PathBuf,
+    /// Qualified name or position (file:line:column) of the function
+    #[arg(short, long, value_name = "FUNCTION")]
+    function: String,
+}
+
+#[derive(Args)]
+struct CalleesArgs {
+    /// The SQLite database file to query
+    #[arg(short, long, value_name = "FILE")]
+    database_path: PathBuf,
+    /// Qualified name or position (file:line:column) of the function
+    #[arg(short, long, value_name = "FUNCTION")]
+    function: String,
+    /// Maximum depth of the call tree, unlimited if not set
+    #[arg(long, value_name = "N")]
+    depth: Option<usize>,
+    /// Which implementations a virtual function call can reach
+    #[arg(long, value_enum, default_value_t = VirtualTargetsArg::AllOverrides)]
+    virtual_targets: VirtualTargetsArg,
+}
+
+#[derive(Args)]
+struct ExportArgs {
+    /// The SQLite database file to export
+    #[arg(short, long, value_name = "FILE")]
+    database_path: PathBuf,
+    /// The format to export to
+    #[arg(long, value_enum, default_value_t = ExportFormat::Dot)]
+    format: ExportFormat,
+    /// The file to write to, stdout if not set
+    #[arg(short, long, value_name = "FILE")]
+    output: Option<PathBuf>,
+    /// Qualified name or position (file:line:column) of the root function, all functions if not set
+    #[arg(short, long, value_name = "FUNCTION")]
+    function: Option<String>,
+    /// Maximum call depth starting at the root function, unlimited if not set
+    #[arg(long, value_name = "N")]
+    depth: Option<usize>,
+    /// Which implementations a virtual function call can reach
+    #[arg(long, value_enum, default_value_t = VirtualTargetsArg::AllOverrides)]
+    virtual_targets: VirtualTargetsArg,
+}
+
+#[derive(Args)]
+struct IncludesArgs {
+    /// The SQLite database file to query
+    #[arg(short, long, value_name = "FILE")]
+    database_path: PathBuf,
+    /// The file as stored in the database, e.g. "./include/header.h"
+    #[arg(short, long, value_name = "FILE")]
+    file: String,
+}
+
+#[derive(Args)]
+struct ImpactArgs {
+    /// The SQLite database file to query
+    #[arg(short, long, value_name = "FILE")]
+    database_path: PathBuf,
+    /// A unified diff (e.g. from "git diff") of the changes, "-" to read from stdin
+    #[arg(long, value_name = "FILE")]
+    diff: Option<PathBuf>,
+    /// Changed lines in the form "file:line" or "file:start_line-end_line"
+    #[arg(short, long, value_name = "LINES")]
+    lines: Vec<String>,
+}
+
+#[derive(Args)]
+struct TestsReachingArgs {
+    /// The SQLite database file to query
+    #[arg(short, long, value_name = "FILE")]
+    database_path: PathBuf,
+    /// Qualified name or position (file:line:column) of the function
+    #[arg(short, long, value_name = "FUNCTION")]
+    function: String,
+    /// Which implementations a virtual function call can reach
+    #[arg(long, value_enum, default_value_t = VirtualTargetsArg::AllOverrides)]
+    virtual_targets: VirtualTargetsArg,
+}
+
+#[derive(Args)]
+struct CoveredByArgs {
+    /// The SQLite database file to query
+    #[arg(short, long, value_name = "FILE")]
+    database_path: PathBuf,
+    /// Name of the test as used in gtest filters, e.g. "Suite.Name"
+    #[arg(short, long, value_name = "TEST")]
+    test: String,
+    /// Which implementations a virtual function call can reach
+    #[arg(long, value_enum, default_value_t = VirtualTargetsArg::AllOverrides)]
+    virtual_targets: VirtualTargetsArg,
+}
+
+#[derive(Args)]
+struct CyclesArgs {
+    /// The SQLite database file to query
+    #[arg(short, long, value_name = "FILE")]
+    database_path: PathBuf,
+    /// Which implementations a virtual function call can reach
+    #[arg(long, value_enum, default_value_t = VirtualTargetsArg::AllOverrides)]
+    virtual_targets: VirtualTargetsArg,
+}
+
+#[derive(Args)]
+struct DeadCodeArgs {
+    /// The SQLite database file to query
+    #[arg(short, long, value_name = "FILE")]
+    database_path: PathBuf,
+    /// The kinds of entry points to start from
+    #[arg(short, long, value_enum, value_name = "ROOT", default_values_t = [DeadCodeRootArg::Main, DeadCodeRootArg::Gtest])]
+    roots: Vec<DeadCodeRootArg>,
+    /// Qualified names or positions (file:line:column) of additional entry points
+    #[arg(short, long, value_name = "FUNCTION")]
+    function: Vec<String>,
+    /// Which implementations a virtual function call can reach
+    #[arg(long, value_enum, default_value_t = VirtualTargetsArg::AllOverrides)]
+    virtual_targets: VirtualTargetsArg,
+}
+
+#[derive(Args)]
+struct ServeLspArgs {
+    /// The SQLite database file to serve
+    #[arg(short, long, value_name = "FILE")]
+    database_path: PathBuf,
+    /// The directory relative file names of the database are resolved against, the current
+    /// directory if not set
+    #[arg(short, long, value_name = "DIR")]
+    root_dir: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DeadCodeRootArg {
+    /// All `main` functions
+    Main,
+    /// All gtest test bodies
+    Gtest,
+    /// All functions declared or implemented in a header
+    Exported,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ExportFormat {
+    /// Graphviz DOT
+    Dot,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum AstFormatArg {
+    /// The default text dump, functions are linked by qualified name and type
+    Text,
+    /// The JSON dump, functions are linked by their mangled name
+    Json,
+}
+
+impl From<AstFormatArg> for ClangAstFormat {
+    fn from(arg: AstFormatArg) -> Self {
+        match arg {
+            AstFormatArg::Text => ClangAstFormat::Text,
+            AstFormatArg::Json => ClangAstFormat::Json,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum VirtualTargetsArg {
+    /// Only the function named at the call
+    Static,
+    /// All functions overriding the same base function
+    AllOverrides,
+    /// Overriding functions of the static receiver type and its sub classes
+    ReceiverSubtree,
+}
+
+impl From<VirtualTargetsArg> for VirtualCallTargets {
+    fn from(arg: VirtualTargetsArg) -> Self {
+        match arg {
+            VirtualTargetsArg::Static => VirtualCallTargets::Static,
+            VirtualTargetsArg::AllOverrides => VirtualCallTargets::AllOverrides,
+            VirtualTargetsArg::ReceiverSubtree => VirtualCallTargets::ReceiverSubtree,
+        }
+    }
 }
 
 fn main() {
@@ -60,7 +346,15 @@ fn main() {
                     compile_commands_json.display()
                 );
 
-                run_ast_parser(compile_commands_json, None, &vec![]);
+                run_ast_parser(
+                    compile_commands_json,
+                    None,
+                    &vec![],
+                    args.jobs,
+                    args.ast_format.into(),
+                    false,
+                    false,
+                );
             }
             None => {
                 println!("No compile_commands_json file specified");
@@ -89,7 +383,552 @@ fn main() {
                 &args.compile_commands_json,
                 Some(db),
                 args.ignored_namespaces.as_ref(),
+                args.jobs,
+                args.ast_format.into(),
+                args.implicit_destructor_calls,
+                args.bind_unresolved_calls,
+            );
+        }
+        Commands::Update(args) => {
+            if !&args.compile_commands_json.exists() {
+                println!(
+                    "The file compile_commands.json file '{}' does not exist",
+                    &args.compile_commands_json.display()
+                );
+                return;
+            }
+            if !args.database_path.exists() {
+                println!(
+                    "The database file '{}' does not exist",
+                    args.database_path.display()
+                );
+                return;
+            }
+            println!(
+                "Using compile_commands_json file: {}",
+                &args.compile_commands_json.display()
+            );
+            println!("Updating database at: {}", args.database_path.display());
+
+            let db = match open_database(&args.database_path) {
+                Some(db) => Rc::new(RefCell::new(db)),
+                None => return,
+            };
+
+            run_ast_parser_update(
+                &args.compile_commands_json,
+                db,
+                args.ignored_namespaces.as_ref(),
+                args.jobs,
+                args.ast_format.into(),
+                args.implicit_destructor_calls,
+                args.bind_unresolved_calls,
+            );
+        }
+        Commands::Callers(args) => {
+            let db = match open_database(&args.database_path) {
+                Some(db) => db,
+                None => return,
+            };
+            print_func_callers(&db, &args.function);
+        }
+        Commands::References(args) => {
+            let db = match open_database(&args.database_path) {
+                Some(db) => db,
+                None => return,
+            };
+            print_func_references(&db, &args.function);
+        }
+        Commands::Callees(args) => {
+            let db = match open_database(&args.database_path) {
+                Some(db) => db,
+                None => return,
+            };
+            print_func_callees(&db, &args.function, args.depth, args.virtual_targets.into());
+        }
+        Commands::Export(args) => {
+            let db = match open_database(&args.database_path) {
+                Some(db) => db,
+                None => return,
+            };
+            let roots = match &args.function {
+                Some(function) => match get_func_impls_from_name_or_position(&db, function) {
+                    Some(func_impls) => func_impls,
+                    None => return,
+                },
+                None => Vec::new(),
+            };
+
+            let content = match args.format {
+                ExportFormat::Dot => {
+                    DotGraph::from_database(&db, &roots, args.depth, args.virtual_targets.into())
+                        .to_dot()
+                }
+            };
+
+            match &args.output {
+                Some(output) => std::fs::write(output, content).unwrap(),
+                None => print!("{}", content),
+            }
+        }
+        Commands::Includes(args) => {
+            let db = match open_database(&args.database_path) {
+                Some(db) => db,
+                None => return,
+            };
+            print_file_includes(&db, &args.file);
+        }
+        Commands::Impact(args) => {
+            let db = match open_database(&args.database_path) {
+                Some(db) => db,
+                None => return,
+            };
+
+            let mut changed_lines = Vec::new();
+            for lines in &args.lines {
+                match parse_changed_lines(lines) {
+                    Some(lines) => changed_lines.push(lines),
+                    None => {
+                        println!("Invalid changed lines '{}'", lines);
+                        return;
+                    }
+                }
+            }
+            if let Some(diff) = &args.diff {
+                let diff = if diff.as_os_str() == "-" {
+                    std::io::read_to_string(std::io::stdin())
+                } else {
+                    std::fs::read_to_string(diff)
+                };
+                match diff {
+                    Ok(diff) => changed_lines.append(&mut parse_unified_diff(&diff)),
+                    Err(error) => {
+                        println!("Failed to read the diff: {}", error);
+                        return;
+                    }
+                }
+            }
+
+            print_impacted_funcs(&db, &changed_lines);
+        }
+        Commands::TestsReaching(args) => {
+            let db = match open_database(&args.database_path) {
+                Some(db) => db,
+                None => return,
+            };
+            print_tests_reaching(&db, &args.function, args.virtual_targets.into());
+        }
+        Commands::CoveredBy(args) => {
+            let db = match open_database(&args.database_path) {
+                Some(db) => db,
+                None => return,
+            };
+            print_funcs_covered_by(&db, &args.test, args.virtual_targets.into());
+        }
+        Commands::Cycles(args) => {
+            let db = match open_database(&args.database_path) {
+                Some(db) => db,
+                None => return,
+            };
+            print_call_cycles(&db, args.virtual_targets.into());
+        }
+        Commands::DeadCode(args) => {
+            let db = match open_database(&args.database_path) {
+                Some(db) => db,
+                None => return,
+            };
+            let mut roots = Vec::new();
+            for root in args.roots.iter() {
+                roots.append(&mut match root {
+                    DeadCodeRootArg::Main => get_main_func_impls(&db),
+                    DeadCodeRootArg::Gtest => get_gtest_test_func_impls(&db),
+                    DeadCodeRootArg::Exported => get_exported_func_impls(&db),
+                });
+            }
+            for function in args.function.iter() {
+                match get_func_impls_from_name_or_position(&db, function) {
+                    Some(mut func_impls) => roots.append(&mut func_impls),
+                    None => return,
+                }
+            }
+
+            print_dead_func_impls(&db, roots, args.virtual_targets.into());
+        }
+        Commands::ServeLsp(args) => {
+            let db = match open_database(&args.database_path) {
+                Some(db) => db,
+                None => return,
+            };
+            let root_dir = match &args.root_dir {
+                Some(root_dir) => root_dir.clone(),
+                None => std::env::current_dir().unwrap(),
+            };
+            LspServer::new(db, root_dir).run(&mut std::io::stdin().lock(), &mut std::io::stdout());
+        }
+    }
+}
+
+fn open_database(database_path: &PathBuf) -> Option<DatabaseSqlite> {
+    if !database_path.exists() {
+        println!(
+            "The database file '{}' does not exist",
+            database_path.display()
+        );
+        return None;
+    }
+
+    match DatabaseSqlite::open_database(database_path, false) {
+        Ok(db) => Some(db),
+        Err(error) => {
+            println!("{}", error);
+            None
+        }
+    }
+}
+
+fn print_dead_func_impls(
+    db: &DatabaseSqlite,
+    roots: Vec<FunctionOccurrence>,
+    virtual_call_targets: VirtualCallTargets,
+) {
+    if roots.is_empty() {
+        println!("No entry points found");
+        return;
+    }
+
+    let dead_funcs = get_dead_func_impls(db, roots, virtual_call_targets);
+    println!("Unreachable functions:");
+    if dead_funcs.is_empty() {
+        println!("  none");
+    }
+    for dead_func in dead_funcs {
+        let func = dead_func.func.borrow();
+        let range = func.get_range();
+        println!(
+            "  {}:{}:{}-{}:{} {}",
+            dead_func.file,
+            range.start.line,
+            range.start.column,
+            range.end.line,
+            range.end.column,
+            func.get_qualified_name()
+        );
+    }
+}
+
+fn print_call_cycles(db: &DatabaseSqlite, virtual_call_targets: VirtualCallTargets) {
+    let cycles = get_call_cycles(db, virtual_call_targets);
+    if cycles.is_empty() {
+        println!("No call cycles found");
+        return;
+    }
+
+    for (index, cycle) in cycles.iter().enumerate() {
+        println!("Cycle {}:", index + 1);
+        for call in cycle.calls.iter() {
+            let func_call = call.call.borrow();
+            let range = func_call.get_range();
+            println!(
+                "  {}:{}:{}-{}:{} {} -> {}",
+                call.caller.file,
+                range.start.line,
+                range.start.column,
+                range.end.line,
+                range.end.column,
+                call.caller.func.borrow().get_qualified_name(),
+                call.callee.func.borrow().get_qualified_name()
             );
         }
     }
 }
+
+fn print_tests_reaching(
+    db: &DatabaseSqlite,
+    function: &str,
+    virtual_call_targets: VirtualCallTargets,
+) {
+    let func_impls = match get_func_impls_from_name_or_position(db, function) {
+        Some(func_impls) => func_impls,
+        None => return,
+    };
+
+    let tests = get_tests_reaching(db, func_impls, virtual_call_targets);
+    println!("Tests reaching {}:", function);
+    if tests.is_empty() {
+        println!("  none");
+    }
+    for test in tests {
+        let range = test.test_body.func.borrow().get_range().clone();
+        println!(
+            "  {} {}:{}:{}",
+            test.name, test.test_body.file, range.start.line, range.start.column
+        );
+    }
+}
+
+fn print_funcs_covered_by(
+    db: &DatabaseSqlite,
+    test: &str,
+    virtual_call_targets: VirtualCallTargets,
+) {
+    let test = match get_gtest_test(db, test) {
+        Some(test) => test,
+        None => {
+            println!("No test found for '{}'", test);
+            return;
+        }
+    };
+
+    let funcs = get_funcs_covered_by(db, &test, virtual_call_targets);
+    println!("Functions covered by {}:", test.name);
+    if funcs.is_empty() {
+        println!("  none");
+    }
+    for func in funcs {
+        let covered_func = func.func.borrow();
+        let range = covered_func.get_range();
+        println!(
+            "  {}:{}:{}-{}:{} {}",
+            func.file,
+            range.start.line,
+            range.start.column,
+            range.end.line,
+            range.end.column,
+            covered_func.get_qualified_name()
+        );
+    }
+}
+
+fn print_impacted_funcs(db: &DatabaseSqlite, changed_lines: &[ChangedLines]) {
+    let mut impacted_funcs = get_impacted_funcs(db, changed_lines);
+    if impacted_funcs.is_empty() {
+        println!("No functions affected");
+        return;
+    }
+
+    impacted_funcs.sort_by_key(|impacted_func| {
+        let start = impacted_func.func.borrow().get_range().start.clone();
+        (impacted_func.file.clone(), start.line, start.column)
+    });
+    let mut current_file: Option<&str> = None;
+    for impacted_func in &impacted_funcs {
+        if current_file != Some(&impacted_func.file) {
+            println!("{}:", impacted_func.file);
+            current_file = Some(&impacted_func.file);
+        }
+        let func = impacted_func.func.borrow();
+        let range = func.get_range();
+        println!(
+            "  {}:{}-{}:{} {}",
+            range.start.line,
+            range.start.column,
+            range.end.line,
+            range.end.column,
+            func.get_qualified_name()
+        );
+    }
+}
+
+fn print_file_includes(db: &DatabaseSqlite, file: &str) {
+    if db.get_cpp_file(file).is_none() && db.get_hpp_file(file).is_none() {
+        println!("No file found for '{}'", file);
+        return;
+    }
+
+    for (title, files) in [
+        ("Included by", db.get_file_includers(file)),
+        ("Includes", db.get_file_includees(file)),
+    ] {
+        println!("{} {}:", title, file);
+        if files.is_empty() {
+            println!("  none");
+        }
+        for file in files {
+            println!("  {}", file);
+        }
+    }
+}
+
+fn print_func_callers(db: &DatabaseSqlite, function: &str) {
+    let funcs = db.get_funcs_from_name_or_position(function);
+    if funcs.is_empty() {
+        println!("No function found for '{}'", function);
+        return;
+    }
+
+    // Declarations, implementations and calls of the same function share their callers.
+    let mut handled_funcs: Vec<String> = Vec::new();
+    for func in funcs {
+        let func = func.borrow();
+        if handled_funcs.contains(&func.get_qualified_name().to_string()) {
+            continue;
+        }
+        handled_funcs.push(func.get_qualified_name().to_string());
+
+        let callers = db.get_func_callers(&*func);
+        println!("Callers of {}:", func.get_qualified_name());
+        if callers.is_empty() {
+            println!("  none");
+        }
+        for caller in callers {
+            let caller_func = caller.func.borrow();
+            let range = caller_func.get_range();
+            println!(
+                "  {}:{}:{}-{}:{} {}",
+                caller.file,
+                range.start.line,
+                range.start.column,
+                range.end.line,
+                range.end.column,
+                caller_func.get_qualified_name()
+            );
+        }
+    }
+}
+
+fn print_func_references(db: &DatabaseSqlite, function: &str) {
+    let funcs = db.get_funcs_from_name_or_position(function);
+    if funcs.is_empty() {
+        println!("No function found for '{}'", function);
+        return;
+    }
+
+    let mut handled_funcs: Vec<String> = Vec::new();
+    for func in funcs {
+        let func = func.borrow();
+        if handled_funcs.contains(&func.get_qualified_name().to_string()) {
+            continue;
+        }
+        handled_funcs.push(func.get_qualified_name().to_string());
+
+        let referencers = db.get_func_referencers(&*func);
+        println!("References to {}:", func.get_qualified_name());
+        if referencers.is_empty() {
+            println!("  none");
+        }
+        for referencer in referencers {
+            let mut referencer_func = referencer.func.borrow_mut();
+            let referencer_name = referencer_func.get_qualified_name().to_string();
+            for func_ref in referencer_func.get_func_refs().iter() {
+                let func_ref = func_ref.borrow();
+                if func_ref.get_qualified_name() != func.get_qualified_name() {
+                    continue;
+                }
+                let range = func_ref.get_range();
+                println!(
+                    "  {}:{}:{}-{}:{} in {}",
+                    referencer.file,
+                    range.start.line,
+                    range.start.column,
+                    range.end.line,
+                    range.end.column,
+                    referencer_name
+                );
+            }
+        }
+    }
+}
+
+fn get_func_impls_from_name_or_position(
+    db: &DatabaseSqlite,
+    function: &str,
+) -> Option<Vec<FunctionOccurrence>> {
+    let funcs = db.get_funcs_from_name_or_position(function);
+    if funcs.is_empty() {
+        println!("No function found for '{}'", function);
+        return None;
+    }
+
+    let mut func_impls = Vec::new();
+    for func in funcs {
+        for func_impl in db.get_func_impls(&*func.borrow()) {
+            if !func_impls.contains(&func_impl) {
+                func_impls.push(func_impl);
+            }
+        }
+    }
+    if func_impls.is_empty() {
+        println!("No function implementation found for '{}'", function);
+        return None;
+    }
+
+    Some(func_impls)
+}
+
+fn print_func_callees(
+    db: &DatabaseSqlite,
+    function: &str,
+    depth: Option<usize>,
+    virtual_call_targets: VirtualCallTargets,
+) {
+    let func_impls = match get_func_impls_from_name_or_position(db, function) {
+        Some(func_impls) => func_impls,
+        None => return,
+    };
+
+    for func_impl in func_impls {
+        {
+            let func = func_impl.func.borrow();
+            let range = func.get_range();
+            println!(
+                "{} ({}:{}:{}-{}:{})",
+                func.get_qualified_name(),
+                func_impl.file,
+                range.start.line,
+                range.start.column,
+                range.end.line,
+                range.end.column
+            );
+        }
+        print_call_tree(
+            &get_call_tree(db, &func_impl, depth, virtual_call_targets),
+            1,
+        );
+    }
+}
+
+fn print_call_tree(nodes: &[CallTreeNode], indent: usize) {
+    for node in nodes {
+        let call = node.call.borrow();
+        let range = call.get_range();
+        let mut line = format!(
+            "{}{}:{}-{}:{} {}",
+            "  ".repeat(indent),
+            range.start.line,
+            range.start.column,
+            range.end.line,
+            range.end.column,
+            call.get_qualified_name()
+        );
+        if node.is_virtual_call() {
+            line.push_str(" [virtual]");
+        }
+        match &node.callee {
+            Some(callee) => {
+                let callee_func = callee.func.borrow();
+                if callee_func.get_qualified_name() != call.get_qualified_name() {
+                    line.push_str(&format!(" -> {}", callee_func.get_qualified_name()));
+                }
+                let callee_range = callee_func.get_range();
+                line.push_str(&format!(
+                    " ({}:{}:{}-{}:{})",
+                    callee.file,
+                    callee_range.start.line,
+                    callee_range.start.column,
+                    callee_range.end.line,
+                    callee_range.end.column
+                ));
+                if node.is_callee_decl() {
+                    line.push_str(" [declaration]");
+                }
+            }
+            None => line.push_str(" (unknown)"),
+        }
+        if node.is_recursive {
+            line.push_str(" (recursive)");
+        }
+        println!("{}", line);
+
+        print_call_tree(&node.callees, indent + 1);
+    }
+}
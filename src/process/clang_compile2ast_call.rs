@@ -1,15 +1,24 @@
-use super::argument_splitter::split_arguments;
+use std::collections::VecDeque;
 
-pub fn clang_compile2ast_call(compile_call: &str) -> String {
-    let mut splitted_compile_call = split_arguments(compile_call);
+use crate::ast_reader::{
+    clang_ast_format::ClangAstFormat, compile_commands_reader::CompileCommand,
+};
 
+use super::{
+    argument_splitter::split_arguments, streaming_terminal_process::StreamingTerminalProcess,
+};
+
+pub fn clang_compile_args2ast_args(
+    mut compile_args: VecDeque<String>,
+    ast_format: ClangAstFormat,
+) -> Vec<String> {
     let mut adjusted_call_vec: Vec<String> = Vec::new();
 
-    while splitted_compile_call.len() > 0 {
-        let current_arg = splitted_compile_call.pop_front().unwrap();
+    while !compile_args.is_empty() {
+        let current_arg = compile_args.pop_front().unwrap();
 
         if current_arg == "-o" {
-            match splitted_compile_call.pop_front() {
+            match compile_args.pop_front() {
                 Some(_) => {}
                 None => {
                     // If there is no output file, we don't need to continue.
@@ -22,10 +31,33 @@ pub fn clang_compile2ast_call(compile_call: &str) -> String {
     }
 
     adjusted_call_vec.push("-Xclang".to_string());
-    adjusted_call_vec.push("-ast-dump".to_string());
+    adjusted_call_vec.push(match ast_format {
+        ClangAstFormat::Text => "-ast-dump".to_string(),
+        ClangAstFormat::Json => "-ast-dump=json".to_string(),
+    });
     adjusted_call_vec.push("-fsyntax-only".to_string());
 
-    adjusted_call_vec.join(" ")
+    adjusted_call_vec
+}
+
+pub fn clang_compile2ast_call(compile_call: &str, ast_format: ClangAstFormat) -> String {
+    clang_compile_args2ast_args(split_arguments(compile_call), ast_format).join(" ")
+}
+
+pub fn compile_command2ast_process(
+    compile_command: &CompileCommand,
+    ast_format: ClangAstFormat,
+) -> StreamingTerminalProcess {
+    // The argument list is preferred as it doesn't need to be split and quoted again.
+    match (&compile_command.arguments, &compile_command.command) {
+        (Some(arguments), _) => StreamingTerminalProcess::new_with_args(
+            clang_compile_args2ast_args(arguments.iter().cloned().collect(), ast_format),
+        ),
+        (None, Some(command)) => {
+            StreamingTerminalProcess::new(clang_compile2ast_call(command, ast_format))
+        }
+        (None, None) => StreamingTerminalProcess::new_with_args(Vec::new()),
+    }
 }
 
 #[cfg(test)]
@@ -37,6 +69,46 @@ mod tests {
         let compile_call = "clang -c -o test.o test.c".to_string();
         let expected = "clang -c test.c -Xclang -ast-dump -fsyntax-only".to_string();
 
-        assert_eq!(clang_compile2ast_call(&compile_call), expected);
+        assert_eq!(
+            clang_compile2ast_call(&compile_call, ClangAstFormat::Text),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_clang_compile_args2ast_args() {
+        let compile_args = VecDeque::from(vec![
+            "clang".to_string(),
+            "-c".to_string(),
+            "-o".to_string(),
+            "test.o".to_string(),
+            "-DNAME=\"a b\"".to_string(),
+            "test.c".to_string(),
+        ]);
+        let expected = vec![
+            "clang",
+            "-c",
+            "-DNAME=\"a b\"",
+            "test.c",
+            "-Xclang",
+            "-ast-dump",
+            "-fsyntax-only",
+        ];
+
+        assert_eq!(
+            clang_compile_args2ast_args(compile_args, ClangAstFormat::Text),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_clang_compile2ast_call_json() {
+        let compile_call = "clang -c -o test.o test.c".to_string();
+        let expected = "clang -c test.c -Xclang -ast-dump=json -fsyntax-only".to_string();
+
+        assert_eq!(
+            clang_compile2ast_call(&compile_call, ClangAstFormat::Json),
+            expected
+        );
     }
 }
@@ -49,6 +49,10 @@ impl Process for DummyProcess {
         return self.success;
     }
 
+    fn is_successful(&mut self) -> bool {
+        self.success
+    }
+
     fn has_next_line(&self) -> bool {
         return !self.lines.is_empty();
     }
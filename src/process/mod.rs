@@ -1,10 +1,13 @@
 pub mod argument_splitter;
 pub mod clang_compile2ast_call;
 pub mod dummy_process;
+pub mod streaming_terminal_process;
 pub mod terminal_process;
 
 pub trait Process {
     fn process(&mut self) -> bool;
+    // Returns if the process exited successfully, waiting for it to finish if necessary.
+    fn is_successful(&mut self) -> bool;
 
     fn has_next_line(&self) -> bool;
     fn fetch_next_line(&self) -> String;
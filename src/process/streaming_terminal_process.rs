@@ -0,0 +1,216 @@
+use std::{
+    io::{BufRead, BufReader},
+    process::{Child, ChildStdout, Stdio},
+};
+
+use super::{terminal_process::create_command, Process};
+
+// Unlike the TerminalProcess the output isn't collected upfront, but read line by line while
+// the process is still running. This keeps the memory usage low for huge AST dumps.
+pub struct StreamingTerminalProcess {
+    process_args: String,
+    // If set, the program is started directly with these arguments instead of using a shell.
+    program_args: Option<Vec<String>>,
+    child: Option<Child>,
+    reader: Option<BufReader<ChildStdout>>,
+    next_line: Option<String>,
+    success: Option<bool>,
+}
+
+impl StreamingTerminalProcess {
+    pub fn new(process_args: String) -> Self {
+        StreamingTerminalProcess {
+            process_args,
+            program_args: None,
+            child: None,
+            reader: None,
+            next_line: None,
+            success: None,
+        }
+    }
+
+    pub fn new_with_args(program_args: Vec<String>) -> Self {
+        StreamingTerminalProcess {
+            process_args: program_args.join(" "),
+            program_args: Some(program_args),
+            child: None,
+            reader: None,
+            next_line: None,
+            success: None,
+        }
+    }
+
+    fn read_next_line(&mut self) {
+        self.next_line = None;
+
+        let reader = match self.reader.as_mut() {
+            Some(reader) => reader,
+            None => return,
+        };
+
+        let mut buffer = Vec::new();
+        match reader.read_until(b'\n', &mut buffer) {
+            Ok(0) | Err(_) => {
+                self.reader = None;
+            }
+            Ok(_) => {
+                while buffer.last() == Some(&b'\n') || buffer.last() == Some(&b'\r') {
+                    buffer.pop();
+                }
+                self.next_line = Some(String::from_utf8_lossy(&buffer).to_string());
+            }
+        }
+    }
+}
+
+impl Process for StreamingTerminalProcess {
+    fn process(&mut self) -> bool {
+        let mut command = match create_command(&self.process_args, &self.program_args) {
+            Some(command) => command,
+            None => return false,
+        };
+
+        // Compile errors are passed through, otherwise a failing compilation only shows as a
+        // missing AST.
+        match command
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+        {
+            Ok(mut child) => {
+                self.reader = child.stdout.take().map(BufReader::new);
+                self.child = Some(child);
+                self.read_next_line();
+                true
+            }
+            Err(_) => {
+                self.success = Some(false);
+                false
+            }
+        }
+    }
+
+    fn is_successful(&mut self) -> bool {
+        if let Some(success) = self.success {
+            return success;
+        }
+
+        // The remaining output needs to be consumed, otherwise the process might block forever.
+        while self.next_line.is_some() {
+            self.read_next_line();
+        }
+
+        let success = match self.child.take() {
+            Some(mut child) => child.wait().map(|status| status.success()).unwrap_or(false),
+            None => false,
+        };
+        self.success = Some(success);
+
+        success
+    }
+
+    fn has_next_line(&self) -> bool {
+        self.next_line.is_some()
+    }
+
+    fn fetch_next_line(&self) -> String {
+        match &self.next_line {
+            Some(line) => line.clone(),
+            None => "".to_string(),
+        }
+    }
+
+    fn get_next_line(&mut self) -> String {
+        let line = match self.next_line.take() {
+            Some(line) => line,
+            None => return "".to_string(),
+        };
+
+        self.read_next_line();
+
+        line
+    }
+}
+
+impl Drop for StreamingTerminalProcess {
+    fn drop(&mut self) {
+        if let Some(mut child) = self.child.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_echo() {
+        let mut process = StreamingTerminalProcess::new("echo Hello World!".to_string());
+
+        assert!(process.process());
+
+        assert!(process.has_next_line());
+        assert_eq!("Hello World!", process.fetch_next_line());
+        assert_eq!("Hello World!", process.get_next_line());
+        assert!(!process.has_next_line());
+
+        // Avoid unnecessary crashes.
+        assert_eq!("", process.get_next_line());
+        assert_eq!("", process.fetch_next_line());
+
+        assert!(process.is_successful());
+    }
+
+    #[test]
+    fn echo_multiple_lines() {
+        let mut process = StreamingTerminalProcess::new(
+            "echo Hello World! && echo && echo How are you?".to_string(),
+        );
+
+        assert!(process.process());
+
+        assert_eq!("Hello World!", process.get_next_line());
+        assert_eq!("", process.get_next_line());
+        assert_eq!("How are you?", process.get_next_line());
+        assert!(!process.has_next_line());
+
+        assert!(process.is_successful());
+    }
+
+    #[test]
+    fn echo_with_args() {
+        let mut process = StreamingTerminalProcess::new_with_args(vec![
+            "echo".to_string(),
+            "Hello  World!".to_string(),
+        ]);
+
+        assert!(process.process());
+
+        assert_eq!("Hello  World!", process.get_next_line());
+        assert!(!process.has_next_line());
+        assert!(process.is_successful());
+    }
+
+    #[test]
+    fn failing_process_with_output() {
+        let mut process = StreamingTerminalProcess::new("echo Hello && exit 3".to_string());
+
+        assert!(process.process());
+
+        // Waiting for the exit status drops the remaining output.
+        assert!(!process.is_successful());
+        assert!(!process.has_next_line());
+    }
+
+    #[test]
+    fn unknown_program() {
+        let mut process =
+            StreamingTerminalProcess::new_with_args(vec!["does_not_exist_program".to_string()]);
+
+        assert!(!process.process());
+        assert!(!process.has_next_line());
+        assert!(!process.is_successful());
+    }
+}
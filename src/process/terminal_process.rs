@@ -4,29 +4,57 @@ use super::Process;
 
 pub struct TerminalProcess {
     process_args: String,
+    // If set, the program is started directly with these arguments instead of using a shell.
+    program_args: Option<Vec<String>>,
     output_lines: VecDeque<String>,
+    success: bool,
+}
+
+pub(super) fn create_command(
+    process_args: &str,
+    program_args: &Option<Vec<String>>,
+) -> Option<Command> {
+    if let Some(program_args) = program_args {
+        let (program, args) = program_args.split_first()?;
+        let mut command = Command::new(program);
+        command.args(args);
+        Some(command)
+    } else if cfg!(target_os = "windows") {
+        let mut command = Command::new("cmd");
+        command.args(["/C", process_args]);
+        Some(command)
+    } else {
+        let mut command = Command::new("sh");
+        command.arg("-c").arg(process_args);
+        Some(command)
+    }
 }
 
 impl TerminalProcess {
     pub fn new(process_args: String) -> Self {
         TerminalProcess {
             process_args,
+            program_args: None,
+            output_lines: VecDeque::new(),
+            success: false,
+        }
+    }
+
+    pub fn new_with_args(program_args: Vec<String>) -> Self {
+        TerminalProcess {
+            process_args: program_args.join(" "),
+            program_args: Some(program_args),
             output_lines: VecDeque::new(),
+            success: false,
         }
     }
 }
 
 impl Process for TerminalProcess {
     fn process(&mut self) -> bool {
-        let output = if cfg!(target_os = "windows") {
-            Command::new("cmd")
-                .args(["/C", self.process_args.as_str()])
-                .output()
-        } else {
-            Command::new("sh")
-                .arg("-c")
-                .arg(self.process_args.as_str())
-                .output()
+        let output = match create_command(&self.process_args, &self.program_args) {
+            Some(mut command) => command.output(),
+            None => return false,
         };
 
         match output {
@@ -36,7 +64,8 @@ impl Process for TerminalProcess {
                     self.output_lines.push_back(line.to_string());
                 }
 
-                return output.status.success();
+                self.success = output.status.success();
+                self.success
             }
             Err(_) => {
                 return false;
@@ -44,6 +73,10 @@ impl Process for TerminalProcess {
         }
     }
 
+    fn is_successful(&mut self) -> bool {
+        self.success
+    }
+
     fn has_next_line(&self) -> bool {
         self.output_lines.len() > 0
     }
@@ -142,4 +175,16 @@ mod tests {
         assert!(!process.has_next_line());
         assert_eq!("", process.get_next_line());
     }
+
+    #[test]
+    fn echo_with_args() {
+        let mut process =
+            TerminalProcess::new_with_args(vec!["echo".to_string(), "Hello  World!".to_string()]);
+
+        assert!(process.process());
+
+        assert!(process.has_next_line());
+        assert_eq!("Hello  World!", process.get_next_line().trim_end());
+        assert!(!process.has_next_line());
+    }
 }
@@ -0,0 +1,46 @@
+use std::{cell::RefCell, path::PathBuf, rc::Rc};
+
+use rust_clang_call_graph::{
+    ast_reader::clang_ast_parser::{ClangAstParser, ClangAstParserImpl},
+    call_graph::{
+        ast_walker::clang_ast_walker::walk_ast_2_func_call_db,
+        database::database_sqlite::DatabaseSqlite,
+    },
+    process::dummy_process::DummyProcess,
+};
+
+// Walks the stored ASTs of a playground test into a new database within the test directory.
+pub fn create_playground_database(
+    test_dir_path: &str,
+    ast2load_file_names: Vec<&str>,
+    db_file_name: &str,
+    implicit_destructor_calls: bool,
+    bind_unresolved_calls: bool,
+) -> Rc<RefCell<DatabaseSqlite>> {
+    let test_dir = PathBuf::from(test_dir_path);
+    let db_path = test_dir.join(db_file_name);
+    let database_sqlite = Rc::new(RefCell::new(DatabaseSqlite::create_database(
+        &db_path, true,
+    )));
+
+    for ast2load_file_name in ast2load_file_names {
+        let ast2load_file = test_dir.join([ast2load_file_name, ".ast2load"].join(""));
+        let cpp_file = test_dir.join([ast2load_file_name, ".cpp"].join(""));
+        let dummy_process = Box::new(DummyProcess::new_from_file(
+            &ast2load_file.to_str().unwrap().to_string(),
+        ));
+
+        let mut parser = ClangAstParserImpl::new(dummy_process);
+        walk_ast_2_func_call_db(
+            cpp_file.to_str().unwrap(),
+            parser.parse_ast().unwrap(),
+            database_sqlite.clone(),
+            &vec!["std".to_string()],
+            implicit_destructor_calls,
+            bind_unresolved_calls,
+        );
+    }
+    database_sqlite.borrow().link_func_calls();
+
+    database_sqlite
+}
@@ -0,0 +1,15 @@
+int add(int val1, int val2)
+{
+    return val1 + val2;
+}
+
+int apply(int (*func)(int, int), int val1, int val2)
+{
+    return func(val1, val2);
+}
+
+int main(int argc, char *argv[])
+{
+    int (*func)(int, int) = &add;
+    return apply(add, 1, 2) + func(3, 4);
+}
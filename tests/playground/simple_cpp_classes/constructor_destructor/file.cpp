@@ -0,0 +1,35 @@
+class TestClass
+{
+  public:
+    TestClass(int value1, int value2)
+    {
+        init(value1 + value2);
+    }
+
+    ~TestClass()
+    {
+        reset();
+    }
+
+    void init(int value)
+    {
+        m_value = value;
+    }
+
+    void reset()
+    {
+        m_value = 0;
+    }
+
+  private:
+    int m_value;
+};
+
+int main(int argc, char *argv[])
+{
+    TestClass test(1, 2);
+    TestClass(3, 4);
+    TestClass *pointer = new TestClass(5, 6);
+    delete pointer;
+    return 0;
+}
@@ -0,0 +1,31 @@
+class TestClass
+{
+  public:
+    int operator+(const TestClass &other) const
+    {
+        return 1;
+    }
+
+    int operator()(int value) const
+    {
+        return value;
+    }
+
+    int operator[](int index) const
+    {
+        return index;
+    }
+
+    operator int() const
+    {
+        return 2;
+    }
+};
+
+int main(int argc, char *argv[])
+{
+    TestClass a;
+    int sum = a + a;
+    int value = a(1) + a[2];
+    return sum + value + static_cast<int>(a);
+}
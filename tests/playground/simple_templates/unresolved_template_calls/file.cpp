@@ -0,0 +1,24 @@
+class Circle
+{
+  public:
+    int area()
+    {
+        return 3;
+    }
+};
+
+int draw(Circle &circle)
+{
+    return circle.area();
+}
+
+template <class T> int render(T &shape)
+{
+    return draw(shape) + shape.area();
+}
+
+int main(int argc, char *argv[])
+{
+    Circle circle;
+    return render(circle);
+}
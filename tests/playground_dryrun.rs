@@ -7,11 +7,11 @@ mod tests {
     };
 
     use rust_clang_call_graph::{
-        ast_reader::compile_commands_reader::read_compile_commands_json_file,
-        process::{
-            clang_compile2ast_call::clang_compile2ast_call, terminal_process::TerminalProcess,
-            Process,
+        ast_reader::{
+            clang_ast_format::ClangAstFormat,
+            compile_commands_reader::read_compile_commands_json_file,
         },
+        process::{clang_compile2ast_call::compile_command2ast_process, Process},
         run_ast_parser,
     };
 
@@ -22,6 +22,24 @@ mod tests {
             &compile_commands_json,
             None,
             &vec!["std".to_string(), "boost".to_string()],
+            1,
+            ClangAstFormat::Text,
+            false,
+            false,
+        );
+    }
+
+    #[test]
+    fn dry_run_ast_parser_parallel_test() {
+        let compile_commands_json = PathBuf::from("tests/playground/compile_commands.json");
+        run_ast_parser(
+            &compile_commands_json,
+            None,
+            &vec!["std".to_string(), "boost".to_string()],
+            4,
+            ClangAstFormat::Text,
+            false,
+            false,
         );
     }
 
@@ -29,15 +47,10 @@ mod tests {
     fn process_files2ast_files() {
         let compile_commands_json = PathBuf::from("tests/playground/compile_commands.json");
 
-        let entries = if read_compile_commands_json_file(&compile_commands_json).is_some() {
-            read_compile_commands_json_file(&compile_commands_json).unwrap()
-        } else {
-            assert!(false);
-            return;
-        };
+        let entries = read_compile_commands_json_file(&compile_commands_json).unwrap();
 
         for entry in entries {
-            let mut terminal_process = TerminalProcess::new(clang_compile2ast_call(&entry.command));
+            let mut terminal_process = compile_command2ast_process(&entry, ClangAstFormat::Text);
 
             assert!(terminal_process.process());
 
@@ -49,6 +62,8 @@ mod tests {
                 writer.write(buffer.as_bytes()).unwrap();
                 writer.write("\n".as_bytes()).unwrap();
             }
+
+            assert!(terminal_process.is_successful());
         }
     }
 }
@@ -0,0 +1,200 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufReader, Write},
+        path::{Path, PathBuf},
+        process::{Command, Stdio},
+    };
+
+    use rust_clang_call_graph::lsp::lsp_message::{path_to_uri, read_message, write_message};
+    use serde_json::{json, Value};
+
+    use crate::common::create_playground_database;
+
+    fn create_test_database(test_dir_path: &str, ast2load_file_names: Vec<&str>) -> PathBuf {
+        create_playground_database(
+            test_dir_path,
+            ast2load_file_names,
+            "created_db_lsp.db",
+            false,
+            false,
+        );
+
+        PathBuf::from(test_dir_path).join("created_db_lsp.db")
+    }
+
+    fn create_request(id: u64, method: &str, params: Value) -> Value {
+        json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params})
+    }
+
+    // Drives the server binary like an editor would and returns the responses of all requests.
+    fn run_lsp_client(db_path: &Path, messages: Vec<Value>) -> Vec<Value> {
+        let mut server = Command::new(env!("CARGO_BIN_EXE_rust-clang-call-graph"))
+            .args(["serve-lsp", "--database-path", db_path.to_str().unwrap()])
+            .args(["--root-dir", env!("CARGO_MANIFEST_DIR")])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut stdin = server.stdin.take().unwrap();
+        for message in messages.iter() {
+            write_message(&mut stdin, message);
+        }
+        stdin.flush().unwrap();
+        drop(stdin);
+
+        let mut stdout = BufReader::new(server.stdout.take().unwrap());
+        let mut responses = Vec::new();
+        while let Ok(Some(response)) = read_message(&mut stdout) {
+            responses.push(response);
+        }
+        assert!(server.wait().unwrap().success());
+
+        responses
+    }
+
+    fn get_uri(file: &str) -> String {
+        path_to_uri(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(file))
+    }
+
+    #[test]
+    fn cross_tu_call_hierarchy_test() {
+        let test_dir_path = "./tests/playground/c_style_tests/decl_in_header_and_two_cpps";
+        let db_path = create_test_database(test_dir_path, vec!["impl", "main"]);
+        let header_uri = get_uri(&format!("{}/header.h", &test_dir_path[2..]));
+
+        let responses = run_lsp_client(
+            &db_path,
+            vec![
+                create_request(1, "initialize", json!({"capabilities": {}})),
+                json!({"jsonrpc": "2.0", "method": "initialized", "params": {}}),
+                create_request(
+                    2,
+                    "textDocument/prepareCallHierarchy",
+                    json!({
+                        "textDocument": {"uri": header_uri},
+                        "position": {"line": 0, "character": 4},
+                    }),
+                ),
+            ],
+        );
+        assert_eq!(responses.len(), 2);
+        assert_eq!(
+            responses[0]["result"]["capabilities"]["callHierarchyProvider"],
+            true
+        );
+
+        // The declaration in the header leads to the implementation in the other source file.
+        let items = responses[1]["result"].as_array().unwrap();
+        assert_eq!(items.len(), 1);
+        let add_item = items[0].clone();
+        assert_eq!(add_item["detail"], "add 'int (int, int)'");
+        assert_eq!(
+            add_item["uri"],
+            get_uri(&format!("{}/impl.cpp", &test_dir_path[2..]))
+        );
+
+        let responses = run_lsp_client(
+            &db_path,
+            vec![
+                create_request(3, "callHierarchy/incomingCalls", json!({"item": add_item})),
+                create_request(4, "shutdown", Value::Null),
+                json!({"jsonrpc": "2.0", "method": "exit"}),
+            ],
+        );
+        assert_eq!(responses.len(), 2);
+
+        let incoming_calls = responses[0]["result"].as_array().unwrap();
+        assert_eq!(incoming_calls.len(), 1);
+        let main_item = incoming_calls[0]["from"].clone();
+        assert_eq!(main_item["detail"], "main 'int (int, char **)'");
+        assert_eq!(
+            main_item["uri"],
+            get_uri(&format!("{}/main.cpp", &test_dir_path[2..]))
+        );
+        assert_eq!(
+            incoming_calls[0]["fromRanges"],
+            json!([{
+                "start": {"line": 4, "character": 11},
+                "end": {"line": 4, "character": 20},
+            }])
+        );
+
+        let responses = run_lsp_client(
+            &db_path,
+            vec![create_request(
+                5,
+                "callHierarchy/outgoingCalls",
+                json!({"item": main_item}),
+            )],
+        );
+        let outgoing_calls = responses[0]["result"].as_array().unwrap();
+        assert_eq!(outgoing_calls.len(), 1);
+        assert_eq!(outgoing_calls[0]["to"], add_item);
+    }
+
+    #[test]
+    fn virtual_method_call_hierarchy_test() {
+        let test_dir_path = "./tests/playground/simple_cpp_classes/virtual_method";
+        let db_path = create_test_database(test_dir_path, vec!["file"]);
+        let file_uri = get_uri(&format!("{}/file.cpp", &test_dir_path[2..]));
+
+        // The position is within "add" of the virtual call "test.add(1, 2)".
+        let responses = run_lsp_client(
+            &db_path,
+            vec![create_request(
+                1,
+                "textDocument/prepareCallHierarchy",
+                json!({
+                    "textDocument": {"uri": file_uri},
+                    "position": {"line": 13, "character": 17},
+                }),
+            )],
+        );
+        let items = responses[0]["result"].as_array().unwrap();
+        assert_eq!(items.len(), 1);
+        let add_item = items[0].clone();
+        assert_eq!(add_item["detail"], "TestClass::add 'int (int, int)'");
+        assert_eq!(add_item["uri"], file_uri);
+        // The range ends after the closing brace of the implementation.
+        assert_eq!(
+            add_item["range"],
+            json!({
+                "start": {"line": 3, "character": 4},
+                "end": {"line": 6, "character": 5},
+            })
+        );
+
+        let responses = run_lsp_client(
+            &db_path,
+            vec![
+                create_request(2, "callHierarchy/incomingCalls", json!({"item": add_item})),
+                create_request(3, "shutdown", Value::Null),
+                create_request(4, "callHierarchy/incomingCalls", json!({"item": add_item})),
+                json!({"jsonrpc": "2.0", "method": "exit"}),
+            ],
+        );
+        assert_eq!(responses.len(), 3);
+
+        let incoming_calls = responses[0]["result"].as_array().unwrap();
+        assert_eq!(incoming_calls.len(), 1);
+        assert_eq!(
+            incoming_calls[0]["from"]["detail"],
+            "main 'int (int, char **)'"
+        );
+        assert_eq!(
+            incoming_calls[0]["fromRanges"],
+            json!([{
+                "start": {"line": 13, "character": 11},
+                "end": {"line": 13, "character": 25},
+            }])
+        );
+
+        assert_eq!(responses[1]["result"], Value::Null);
+        assert_eq!(responses[2]["id"], 4);
+        assert_eq!(responses[2]["error"]["code"], -32600);
+    }
+}
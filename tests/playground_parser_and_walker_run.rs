@@ -1,57 +1,91 @@
+mod common;
+
 #[cfg(test)]
 mod tests {
     use std::{cell::RefCell, path::PathBuf, rc::Rc};
 
     use rust_clang_call_graph::{
-        ast_reader::clang_ast_parser::{ClangAstParser, ClangAstParserImpl},
         call_graph::{
-            ast_walker::clang_ast_walker::walk_ast_2_func_call_db,
+            data_structure::FuncBasics,
             database::{database_content::DatabaseContent, database_sqlite::DatabaseSqlite},
+            function_search::{
+                call_cycles::get_call_cycles,
+                gtest_tests::{get_funcs_covered_by, get_gtest_tests, get_tests_reaching},
+                virtual_call_targets::VirtualCallTargets,
+            },
         },
-        process::dummy_process::DummyProcess,
+        location::range::Range,
     };
 
-    fn run_ast_parser_test(test_dir_path: &str, ast2load_file_names: Vec<&str>) {
-        let test_dir = PathBuf::from(test_dir_path);
-        let db_path = test_dir.join("created_db.db");
-        let database_sqlite = Rc::new(RefCell::new(DatabaseSqlite::create_database(
-            &db_path, true,
-        )));
-
-        for ast2load_file_name in ast2load_file_names {
-            let ast2load_file = test_dir.join([ast2load_file_name, ".ast2load"].join(""));
-            let cpp_file = test_dir.join([ast2load_file_name, ".cpp"].join(""));
-            let dummy_process = Box::new(DummyProcess::new_from_file(
-                &ast2load_file.to_str().unwrap().to_string(),
-            ));
-
-            let mut parser = ClangAstParserImpl::new(dummy_process);
-            let ast = parser.parse_ast();
-
-            match ast {
-                Some(ast) => {
-                    walk_ast_2_func_call_db(
-                        &cpp_file.to_str().unwrap(),
-                        ast,
-                        database_sqlite.clone(),
-                        &vec!["std".to_string()],
-                    );
-                }
-                None => {
-                    assert!(false);
-                }
-            }
-        }
+    use crate::common::create_playground_database;
+
+    fn run_ast_parser_test(
+        test_dir_path: &str,
+        ast2load_file_names: Vec<&str>,
+    ) -> Rc<RefCell<DatabaseSqlite>> {
+        run_ast_parser_test_with_options(test_dir_path, ast2load_file_names, false, false, "")
+    }
+
+    fn run_ast_parser_test_with_implicit_destructor_calls(
+        test_dir_path: &str,
+        ast2load_file_names: Vec<&str>,
+    ) -> Rc<RefCell<DatabaseSqlite>> {
+        run_ast_parser_test_with_options(
+            test_dir_path,
+            ast2load_file_names,
+            true,
+            false,
+            "_implicit_destructor_calls",
+        )
+    }
+
+    fn run_ast_parser_test_with_bound_unresolved_calls(
+        test_dir_path: &str,
+        ast2load_file_names: Vec<&str>,
+    ) -> Rc<RefCell<DatabaseSqlite>> {
+        run_ast_parser_test_with_options(test_dir_path, ast2load_file_names, false, true, "")
+    }
 
+    fn run_ast_parser_test_with_options(
+        test_dir_path: &str,
+        ast2load_file_names: Vec<&str>,
+        implicit_destructor_calls: bool,
+        bind_unresolved_calls: bool,
+        file_name_suffix: &str,
+    ) -> Rc<RefCell<DatabaseSqlite>> {
+        let database_sqlite = create_playground_database(
+            test_dir_path,
+            ast2load_file_names,
+            &format!("created_db{}.db", file_name_suffix),
+            implicit_destructor_calls,
+            bind_unresolved_calls,
+        );
+
+        let test_dir = PathBuf::from(test_dir_path);
         let sqlite_content = database_sqlite.borrow().get_db_content();
-        let db_json_file = test_dir.join("created_db.json");
+        let db_json_file = test_dir.join(format!("created_db{}.json", file_name_suffix));
         sqlite_content.save_to_file(&db_json_file.to_str().unwrap());
 
-        let expected_db_content_json_file_name = test_dir.join("expected_db_content.json");
+        let expected_db_content_json_file_name =
+            test_dir.join(format!("expected_db_content{}.json", file_name_suffix));
         let expected_db_content =
             DatabaseContent::load_from_file(&expected_db_content_json_file_name.to_str().unwrap());
 
         assert_eq!(sqlite_content, expected_db_content);
+
+        database_sqlite
+    }
+
+    fn get_template_pattern_names(database_sqlite: &Rc<RefCell<DatabaseSqlite>>) -> Vec<String> {
+        let database_sqlite = database_sqlite.borrow();
+        let mut names: Vec<String> = database_sqlite
+            .get_all_func_impls()
+            .iter()
+            .filter(|func_impl| database_sqlite.is_template_pattern(&func_impl.func.borrow()))
+            .map(|func_impl| func_impl.func.borrow().get_qualified_name().to_string())
+            .collect();
+        names.sort();
+        names
     }
 
     #[cfg(test)]
@@ -60,10 +94,30 @@ mod tests {
 
         #[test]
         fn simple_gtest_test() {
-            run_ast_parser_test(
+            let database_sqlite = run_ast_parser_test(
                 "./tests/playground/complex_cases/simple_gtest",
                 vec!["file"],
             );
+            let database_sqlite = database_sqlite.borrow();
+
+            let tests = get_gtest_tests(&database_sqlite);
+            assert_eq!(tests.len(), 1);
+            assert_eq!(tests[0].name, "SimpleGtest.Test1");
+            assert_eq!(
+                tests[0].test_body.file,
+                "./tests/playground/complex_cases/simple_gtest/file.cpp"
+            );
+
+            // Every function covered by the test is reached by it.
+            let covered_funcs =
+                get_funcs_covered_by(&database_sqlite, &tests[0], VirtualCallTargets::Static);
+            assert!(!covered_funcs.is_empty());
+            let reaching_tests = get_tests_reaching(
+                &database_sqlite,
+                covered_funcs[..1].to_vec(),
+                VirtualCallTargets::Static,
+            );
+            assert_eq!(reaching_tests, tests);
         }
     }
 
@@ -71,6 +125,14 @@ mod tests {
     mod c_style_tests {
         use super::*;
 
+        #[test]
+        fn func_pointer_callback_test() {
+            run_ast_parser_test(
+                "./tests/playground/c_style_tests/func_pointer_callback",
+                vec!["file"],
+            );
+        }
+
         #[test]
         fn anonymous_namespace_test() {
             run_ast_parser_test(
@@ -81,10 +143,26 @@ mod tests {
 
         #[test]
         fn decl_in_header_and_2_cpps_test() {
-            run_ast_parser_test(
+            let database_sqlite = run_ast_parser_test(
                 "./tests/playground/c_style_tests/decl_in_header_and_two_cpps",
                 vec!["impl", "main"],
             );
+
+            let funcs = database_sqlite.borrow().get_funcs_from_name_or_position(
+                "./tests/playground/c_style_tests/decl_in_header_and_two_cpps/main.cpp:5:12",
+            );
+            assert_eq!(funcs.len(), 2);
+            assert_eq!(funcs[1].borrow().get_name(), "add");
+
+            let callees = database_sqlite
+                .borrow()
+                .get_func_impls_or_one_decl(&funcs[1].borrow());
+            assert_eq!(callees.len(), 1);
+            assert_eq!(
+                callees[0].file,
+                "./tests/playground/c_style_tests/decl_in_header_and_two_cpps/impl.cpp"
+            );
+            assert_eq!(callees[0].func.borrow().get_range().start.line, 3);
         }
 
         #[test]
@@ -139,10 +217,24 @@ mod tests {
 
         #[test]
         fn recursive_func_test() {
-            run_ast_parser_test(
+            let database_sqlite = run_ast_parser_test(
                 "./tests/playground/c_style_tests/recursive_func",
                 vec!["file"],
             );
+
+            let cycles =
+                get_call_cycles(&database_sqlite.borrow(), VirtualCallTargets::AllOverrides);
+            assert_eq!(cycles.len(), 1);
+            assert_eq!(cycles[0].funcs.len(), 1);
+            assert_eq!(
+                cycles[0].funcs[0].func.borrow().get_qualified_name(),
+                "factorial 'int (int)'"
+            );
+            assert_eq!(cycles[0].calls.len(), 1);
+            assert_eq!(
+                cycles[0].calls[0].call.borrow().get_range(),
+                &Range::create(3, 21, 3, 37)
+            );
         }
 
         #[test]
@@ -166,6 +258,30 @@ mod tests {
             );
         }
 
+        #[test]
+        fn constructor_destructor_test() {
+            run_ast_parser_test(
+                "./tests/playground/simple_cpp_classes/constructor_destructor",
+                vec!["file"],
+            );
+        }
+
+        #[test]
+        fn constructor_destructor_implicit_destructor_calls_test() {
+            run_ast_parser_test_with_implicit_destructor_calls(
+                "./tests/playground/simple_cpp_classes/constructor_destructor",
+                vec!["file"],
+            );
+        }
+
+        #[test]
+        fn operator_overloading_test() {
+            run_ast_parser_test(
+                "./tests/playground/simple_cpp_classes/operator_overloading",
+                vec!["file"],
+            );
+        }
+
         #[test]
         fn class_in_class_test() {
             run_ast_parser_test(
@@ -208,10 +324,35 @@ mod tests {
 
         #[test]
         fn inheritance_chain_test() {
-            run_ast_parser_test(
+            let database_sqlite = run_ast_parser_test(
                 "./tests/playground/simple_cpp_classes/inheritance_chain",
                 vec!["file"],
             );
+
+            let funcs = database_sqlite.borrow().get_funcs_from_name_or_position(
+                "./tests/playground/simple_cpp_classes/inheritance_chain/file.cpp:31:22",
+            );
+            assert_eq!(funcs.len(), 2);
+            let virtual_func_call = funcs[1].borrow();
+            assert_eq!(
+                virtual_func_call.get_qualified_name(),
+                "TestClass::add 'int (int, int)'"
+            );
+
+            let targets = database_sqlite
+                .borrow()
+                .get_virtual_func_call_targets(&virtual_func_call, VirtualCallTargets::Static);
+            assert_eq!(targets.len(), 1);
+            let targets = database_sqlite.borrow().get_virtual_func_call_targets(
+                &virtual_func_call,
+                VirtualCallTargets::ReceiverSubtree,
+            );
+            assert_eq!(targets.len(), 1);
+            let targets = database_sqlite.borrow().get_virtual_func_call_targets(
+                &virtual_func_call,
+                VirtualCallTargets::AllOverrides,
+            );
+            assert_eq!(targets.len(), 3);
         }
 
         #[test]
@@ -298,10 +439,15 @@ mod tests {
 
         #[test]
         fn simple_template_class_test() {
-            run_ast_parser_test(
+            let database_sqlite = run_ast_parser_test(
                 "./tests/playground/simple_templates/simple_template_class",
                 vec!["file"],
             );
+
+            assert_eq!(
+                get_template_pattern_names(&database_sqlite),
+                vec!["TemplateClass<T>::foo 'int ()'"]
+            );
         }
 
         #[test]
@@ -335,5 +481,34 @@ mod tests {
                 vec!["file"],
             );
         }
+
+        #[test]
+        fn unresolved_template_calls_test() {
+            let database_sqlite = run_ast_parser_test_with_bound_unresolved_calls(
+                "./tests/playground/simple_templates/unresolved_template_calls",
+                vec!["file"],
+            );
+
+            let funcs = database_sqlite.borrow().get_funcs_from_name_or_position(
+                "./tests/playground/simple_templates/unresolved_template_calls/file.cpp:17:26",
+            );
+            assert_eq!(funcs.len(), 4);
+            let unresolved_func_call = funcs[1].borrow();
+            assert_eq!(unresolved_func_call.get_qualified_name(), "area");
+
+            let bound_func_calls = database_sqlite
+                .borrow()
+                .get_bound_func_calls(&unresolved_func_call);
+            assert_eq!(bound_func_calls.len(), 1);
+            assert_eq!(
+                bound_func_calls[0].borrow().get_qualified_name(),
+                "Circle::area 'int ()'"
+            );
+
+            assert_eq!(
+                get_template_pattern_names(&database_sqlite),
+                vec!["render<T> 'int (T &)'"]
+            );
+        }
     }
 }
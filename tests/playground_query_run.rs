@@ -0,0 +1,268 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        path::{Path, PathBuf},
+        process::Command,
+    };
+
+    use crate::common::create_playground_database;
+
+    fn create_test_database(
+        test_dir_path: &str,
+        ast2load_file_names: Vec<&str>,
+        db_file_name: &str,
+        bind_unresolved_calls: bool,
+    ) -> PathBuf {
+        create_playground_database(
+            test_dir_path,
+            ast2load_file_names,
+            db_file_name,
+            false,
+            bind_unresolved_calls,
+        );
+
+        PathBuf::from(test_dir_path).join(db_file_name)
+    }
+
+    // Runs a query command of the binary on the database and returns what it printed.
+    fn run_query(command: &str, db_path: &Path, args: Vec<&str>) -> String {
+        let output = Command::new(env!("CARGO_BIN_EXE_rust-clang-call-graph"))
+            .args([command, "--database-path", db_path.to_str().unwrap()])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    #[test]
+    fn callers_test() {
+        let test_dir_path = "./tests/playground/c_style_tests/decl_in_header_and_two_cpps";
+        let db_path = create_test_database(
+            test_dir_path,
+            vec!["impl", "main"],
+            "created_db_callers.db",
+            false,
+        );
+        let expected_output = format!(
+            "Callers of add 'int (int, int)':\n  {}/main.cpp:3:1-6:2 main 'int (int, char **)'\n",
+            test_dir_path
+        );
+
+        let output = run_query("callers", &db_path, vec!["--function", "add"]);
+        assert_eq!(output, expected_output);
+
+        // The declaration in the header is found by its position as well.
+        let position = format!("{}/header.h:1:5", test_dir_path);
+        let output = run_query("callers", &db_path, vec!["--function", &position]);
+        assert_eq!(output, expected_output);
+    }
+
+    #[test]
+    fn callees_test() {
+        let test_dir_path = "./tests/playground/simple_cpp_classes/inheritance_chain";
+        let db_path =
+            create_test_database(test_dir_path, vec!["file"], "created_db_callees.db", false);
+        let file = format!("{}/file.cpp", test_dir_path);
+
+        let output = run_query(
+            "callees",
+            &db_path,
+            vec!["--function", "main", "--virtual-targets", "static"],
+        );
+        assert_eq!(
+            output,
+            [
+                format!("main 'int (int, char **)' ({}:28:1-32:2)", file),
+                format!(
+                    "  31:12-31:31 TestClass::add 'int (int, int)' [virtual] ({}:22:5-25:6)",
+                    file
+                ),
+                format!(
+                    "    24:16-24:48 TestParentClass::add 'int (int, int)' [virtual] ({}:13:5-16:6)",
+                    file
+                ),
+                format!(
+                    "      15:16-15:53 TestGrandParentClass::add 'int (int, int)' [virtual] ({}:4:5-7:6)",
+                    file
+                ),
+                "".to_string(),
+            ]
+            .join("\n")
+        );
+
+        let output = run_query(
+            "callees",
+            &db_path,
+            vec![
+                "--function",
+                "main",
+                "--virtual-targets",
+                "static",
+                "--depth",
+                "1",
+            ],
+        );
+        assert_eq!(
+            output,
+            [
+                format!("main 'int (int, char **)' ({}:28:1-32:2)", file),
+                format!(
+                    "  31:12-31:31 TestClass::add 'int (int, int)' [virtual] ({}:22:5-25:6)",
+                    file
+                ),
+                "".to_string(),
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn export_dot_test() {
+        let test_dir_path = "./tests/playground/simple_cpp_classes/virtual_method";
+        let db_path =
+            create_test_database(test_dir_path, vec!["file"], "created_db_export.db", false);
+
+        let output = run_query("export", &db_path, vec!["--format", "dot"]);
+        assert_eq!(
+            output,
+            [
+                "digraph call_graph {",
+                "    node [shape=box];",
+                "    subgraph cluster_0 {",
+                &format!("        label=\"{}/file.cpp\";", test_dir_path),
+                "        n0 [label=\"main 'int (int, char **)'\"];",
+                "        subgraph cluster_0_0 {",
+                "            label=\"TestClass\";",
+                "            n1 [label=\"TestClass::add 'int (int, int)'\"];",
+                "            n2 [label=\"TestClass::sub 'int (int, int)'\", style=dashed];",
+                "        }",
+                "    }",
+                "    n0 -> n1 [style=dashed];",
+                "    n0 -> n2 [style=dashed];",
+                "}",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn impact_test() {
+        let test_dir_path = "./tests/playground/c_style_tests/decl_in_header_and_two_cpps";
+        let db_path = create_test_database(
+            test_dir_path,
+            vec!["impl", "main"],
+            "created_db_impact.db",
+            false,
+        );
+
+        let changed_lines = format!("{}/impl.cpp:5", test_dir_path);
+        let output = run_query("impact", &db_path, vec!["--lines", &changed_lines]);
+        assert_eq!(
+            output,
+            format!(
+                "{0}/impl.cpp:\n  3:1-6:2 add 'int (int, int)'\n{0}/main.cpp:\n  3:1-6:2 main 'int (int, char **)'\n",
+                test_dir_path
+            )
+        );
+    }
+
+    #[test]
+    fn impact_of_diff_test() {
+        let test_dir_path = "./tests/playground/simple_cpp_classes/inheritance_chain";
+        let db_path =
+            create_test_database(test_dir_path, vec!["file"], "created_db_impact.db", false);
+
+        // The diff names the file relative to the repository, like "git diff" does.
+        let file = &format!("{}/file.cpp", test_dir_path)[2..];
+        let diff = [
+            format!("diff --git a/{0} b/{0}", file),
+            format!("--- a/{}", file),
+            format!("+++ b/{}", file),
+            "@@ -15,1 +15,1 @@".to_string(),
+            "-        return TestGrandParentClass::add(val1, val2);".to_string(),
+            "+        return TestGrandParentClass::add(val2, val1);".to_string(),
+            "".to_string(),
+        ]
+        .join("\n");
+        let diff_path = std::env::temp_dir().join("rust_clang_call_graph_impact.diff");
+        std::fs::write(&diff_path, diff).unwrap();
+
+        let output = run_query(
+            "impact",
+            &db_path,
+            vec!["--diff", diff_path.to_str().unwrap()],
+        );
+        assert_eq!(
+            output,
+            [
+                format!("{}/file.cpp:", test_dir_path),
+                "  13:5-16:6 TestParentClass::add 'int (int, int)'".to_string(),
+                "  22:5-25:6 TestClass::add 'int (int, int)'".to_string(),
+                "  28:1-32:2 main 'int (int, char **)'".to_string(),
+                "".to_string(),
+            ]
+            .join("\n")
+        );
+    }
+
+    // The functions of a code base without gtest tests are reached by none of them. The tests
+    // of the complex_cases/simple_gtest fixture are checked in playground_parser_and_walker_run.
+    #[test]
+    fn tests_reaching_without_tests_test() {
+        let test_dir_path = "./tests/playground/c_style_tests/decl_in_header_and_two_cpps";
+        let db_path = create_test_database(
+            test_dir_path,
+            vec!["impl", "main"],
+            "created_db_gtest.db",
+            false,
+        );
+
+        let output = run_query(
+            "tests-reaching",
+            &db_path,
+            vec!["--function", "add", "--virtual-targets", "static"],
+        );
+        assert_eq!(output, "Tests reaching add:\n  none\n");
+
+        let output = run_query("covered-by", &db_path, vec!["--test", "Math.Add"]);
+        assert_eq!(output, "No test found for 'Math.Add'\n");
+    }
+
+    #[test]
+    fn dead_code_test() {
+        let test_dir_path = "./tests/playground/simple_templates/unresolved_template_calls";
+        let db_path =
+            create_test_database(test_dir_path, vec!["file"], "created_db_dead_code.db", true);
+
+        let output = run_query("dead-code", &db_path, vec![]);
+        assert_eq!(output, "Unreachable functions:\n  none\n");
+
+        // The body of the uninstantiated template is never reported, only its instantiation.
+        let output = run_query(
+            "dead-code",
+            &db_path,
+            vec!["--roots", "exported", "--function", "draw"],
+        );
+        assert_eq!(
+            output,
+            [
+                "Unreachable functions:".to_string(),
+                format!(
+                    "  {}/file.cpp:15:20-18:2 render<Circle> 'int (Circle &)'",
+                    test_dir_path
+                ),
+                format!(
+                    "  {}/file.cpp:20:1-24:2 main 'int (int, char **)'",
+                    test_dir_path
+                ),
+                "".to_string(),
+            ]
+            .join("\n")
+        );
+    }
+}